@@ -0,0 +1,265 @@
+//! Algorithms which inspect or rearrange arrays (or any other sequence that
+//! can be viewed as a slice).
+
+use std::{
+    cmp::{Ord, Ordering},
+    collections::HashSet,
+    convert::AsRef
+};
+use crate::{
+    error::{AgcError, AgcErrorKind, AgcResult},
+    utils::{priority, random::XorShift}
+};
+
+/// The largest number of items `permutations` will permute. 10 items
+/// already have 3,628,800 permutations.
+pub const MAX_PERMUTATION_LENGTH: usize = 10;
+
+/// Find the local minima and maxima in a sequence. See `local_extrema_by`
+/// for more details.
+/// 
+/// # Example
+/// ```
+///     use algocol::arrays::local_extrema;
+///     let array = [1, 3, 2, 4, 1];
+///     assert_eq!(local_extrema(&array[..]), (vec![2], vec![1, 3]));
+/// ```
+pub fn local_extrema<S, T>(sequence: &S) -> (Vec<usize>, Vec<usize>)
+where
+    S: AsRef<[T]> + ?Sized,
+    T: Ord
+{
+    local_extrema_by(sequence, |a, b| a.cmp(b))
+}
+
+/// Find the local minima and maxima in a sequence. An element is a local
+/// minimum if it is strictly smaller than both of its neighbours, and a local
+/// maximum if it is strictly larger than both of its neighbours. `compare`
+/// is the function used to compare 2 elements.
+/// 
+/// The indices of the local minima and local maxima are returned in that
+/// order, as a tuple of 2 `Vec`s sorted in ascending order.
+/// 
+/// The first and last elements only have 1 neighbour each, so they are never
+/// counted as extrema. Elements which are equal to one of their neighbours
+/// are not counted either, which means that a plateau such as the `3`s in
+/// `[1, 3, 3, 1]` does not contain a local maximum.
+/// 
+/// This function scans the sequence once, so its time complexity is O(n).
+/// 
+/// # Example
+/// ```
+///     use algocol::arrays::local_extrema_by;
+///     let array = [1, 3, 2, 4, 1];
+///     let (minima, maxima) = local_extrema_by(&array[..], |a, b| a.cmp(b));
+///     assert_eq!(minima, [2]);
+///     assert_eq!(maxima, [1, 3]);
+/// ```
+pub fn local_extrema_by<F, S, T>(
+    sequence: &S,
+    compare: F
+) -> (Vec<usize>, Vec<usize>)
+where
+    S: AsRef<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_ref();
+    let mut minima = Vec::new();
+    let mut maxima = Vec::new();
+    for index in 1..sequence.len().saturating_sub(1) {
+        let before = compare(&sequence[index], &sequence[index-1]);
+        let after = compare(&sequence[index], &sequence[index+1]);
+        if priority::is_lt(before) && priority::is_lt(after) {
+            minima.push(index);
+        } else if priority::is_gt(before) && priority::is_gt(after) {
+            maxima.push(index);
+        }
+    }
+    (minima, maxima)
+}
+
+/// Pick `k` items at random from `iter` without knowing how many items it
+/// will produce beforehand, using Jeffrey Vitter's Algorithm R. Every item has
+/// the same chance of ending up in the sample. The random numbers are
+/// generated by a `XorShift` generator seeded with `seed`, so the same seed
+/// and the same stream will always give the same sample.
+/// 
+/// The first `k` items fill up the sample (the reservoir). After that, the
+/// `i`th item (counting from 0) replaces a random item in the reservoir with
+/// probability `k/(i+1)`. If the stream has fewer than `k` items, all of them
+/// are returned in the order they came in. Only O(k) memory is used no matter
+/// how long the stream is.
+/// 
+/// # Example
+/// ```
+///     use algocol::arrays::reservoir_sample;
+///     let sample = reservoir_sample(0..1000, 5, 42);
+///     assert_eq!(sample.len(), 5);
+///     assert!(sample.iter().all(|x| *x < 1000));
+///     assert_eq!(reservoir_sample(0..3, 5, 42), [0, 1, 2]);
+/// ```
+pub fn reservoir_sample<T, I>(iter: I, k: usize, seed: u64) -> Vec<T>
+where
+    I: Iterator<Item = T>
+{
+    let mut random = XorShift::new(seed);
+    let mut reservoir = Vec::with_capacity(k);
+    for (index, item) in iter.enumerate() {
+        if index < k {
+            reservoir.push(item);
+        } else {
+            let replaced = random.next_below(index + 1);
+            if replaced < k {
+                reservoir[replaced] = item;
+            }
+        }
+    }
+    reservoir
+}
+
+/// Find the length of the longest run of consecutive integers which are all
+/// in `sequence`, no matter what order they are in. Duplicate values only
+/// count once, and an empty sequence has a longest run of 0.
+/// 
+/// Sorting the sequence first would take O(n log n) time. Instead, every
+/// value is put in a `HashSet`, and a run is only counted from a value `x`
+/// if `x - 1` is not in the set, so that `x` is the start of its run. Each
+/// run is then counted once by looking up `x + 1`, `x + 2` and so on, so
+/// the whole function takes O(n) time on average.
+/// 
+/// # Example
+/// ```
+///     use algocol::arrays::longest_consecutive;
+///     // 1, 2, 3 and 4
+///     assert_eq!(longest_consecutive(&[100, 4, 200, 1, 3, 2]), 4);
+/// ```
+pub fn longest_consecutive<S>(sequence: &S) -> usize
+where
+    S: AsRef<[i64]> + ?Sized
+{
+    let values = sequence.as_ref().iter().copied().collect::<HashSet<i64>>();
+    let mut longest = 0;
+    for value in values.iter().copied() {
+        let is_start = match value.checked_sub(1) {
+            Some(previous) => !values.contains(&previous),
+            None => true
+        };
+        if !is_start {
+            continue;
+        }
+        let mut length = 1;
+        let mut current = value;
+        while let Some(next) = current.checked_add(1) {
+            if !values.contains(&next) {
+                break;
+            }
+            length += 1;
+            current = next;
+        }
+        longest = longest.max(length);
+    }
+    longest
+}
+
+/// Find every permutation of `items` using Heap's algorithm. There are `n!`
+/// permutations of `n` items, and the first one is `items` in its original
+/// order. If some of the items are equal, the same permutation may appear
+/// more than once.
+/// 
+/// Heap's algorithm gets from each permutation to the next by swapping just
+/// 2 items. The number of permutations grows very quickly, so an `AgcError`
+/// of kind `InvalidArgument` is returned if there are more than
+/// `MAX_PERMUTATION_LENGTH` items.
+/// 
+/// # Example
+/// ```
+///     use algocol::arrays::permutations;
+///     let all = permutations(&[1, 2, 3]).unwrap();
+///     assert_eq!(all, [
+///         [1, 2, 3], [2, 1, 3], [3, 1, 2], [1, 3, 2], [2, 3, 1], [3, 2, 1]
+///     ]);
+/// ```
+pub fn permutations<T: Clone>(items: &[T]) -> AgcResult<Vec<Vec<T>>> {
+    let n = items.len();
+    if n > MAX_PERMUTATION_LENGTH {
+        return Err(AgcError::new(
+            AgcErrorKind::InvalidArgument,
+            format!(
+                "cannot find every permutation of {} items (the limit is {}).",
+                n,
+                MAX_PERMUTATION_LENGTH
+            )
+        ));
+    }
+    let mut current = items.to_vec();
+    // `counters[i]` is how many times the item at index `i` has been swapped
+    // while permuting the first `i + 1` items
+    let mut counters = vec![0; n];
+    let mut all = Vec::with_capacity((1..=n).product());
+    all.push(current.clone());
+    let mut index = 1;
+    while index < n {
+        if counters[index] < index {
+            let other = if index % 2 == 0 {0} else {counters[index]};
+            current.swap(other, index);
+            all.push(current.clone());
+            counters[index] += 1;
+            index = 1;
+        } else {
+            counters[index] = 0;
+            index += 1;
+        }
+    }
+    Ok(all)
+}
+
+/// Rearrange `slice` into the next permutation in lexicographic order,
+/// according to `compare`, like C++'s `std::next_permutation`. `true` is
+/// returned if there is a next permutation. If `slice` is already the last
+/// permutation (sorted in descending order), it is reset to the first one
+/// (sorted in ascending order) and `false` is returned. Calling this
+/// repeatedly on a slice sorted in ascending order goes through every
+/// distinct permutation exactly once.
+/// 
+/// The longest suffix of `slice` in descending order cannot be made any
+/// larger by itself, so the element just before it (the pivot) has to be
+/// increased. It is swapped with the smallest element in the suffix that is
+/// larger than it, and the suffix is reversed so that it is in ascending
+/// order, making it as small as possible. This takes O(n) time.
+/// 
+/// # Example
+/// ```
+///     use algocol::arrays::next_permutation_by;
+///     let mut array = [1, 3, 2];
+///     assert!(next_permutation_by(&mut array, |a, b| a.cmp(b)));
+///     assert_eq!(array, [2, 1, 3]);
+///     let mut last = [3, 2, 1];
+///     assert!(!next_permutation_by(&mut last, |a, b| a.cmp(b)));
+///     assert_eq!(last, [1, 2, 3]);
+/// ```
+pub fn next_permutation_by<T, F>(slice: &mut [T], compare: F) -> bool
+where
+    F: Fn(&T, &T) -> Ordering
+{
+    let length = slice.len();
+    // Find where the descending suffix starts
+    let mut start = length;
+    while start > 1
+    && !priority::is_lt(compare(&slice[start-2], &slice[start-1])) {
+        start -= 1;
+    }
+    if start <= 1 {
+        slice.reverse();
+        return false;
+    }
+    let pivot = start - 2;
+    // The suffix is in descending order, so the last element larger than
+    // the pivot is the smallest one
+    let mut successor = length - 1;
+    while !priority::is_gt(compare(&slice[successor], &slice[pivot])) {
+        successor -= 1;
+    }
+    slice.swap(pivot, successor);
+    slice[start-1..].reverse();
+    true
+}
\ No newline at end of file
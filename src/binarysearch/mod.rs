@@ -1,19 +1,78 @@
 //! Binary search functions
 
+use num_traits::ToPrimitive;
 use crate::{
     error::{AgcError, AgcErrorKind, AgcResult},
+    traits::AgcNumberLike,
     utils::priority,
     sort::{is_sorted, is_sorted_by}
 };
 use std::{
-    cmp::{Ord, Ordering},
-    convert::AsRef
+    cmp::{Ord, Ordering, min},
+    convert::AsRef,
+    ops::Range
 };
 
 pub use binarysearch_unchecked as sc_binary_ui;
 pub use binarysearch_unchecked_by as sc_binary_uif;
 pub use binarysearch as sc_binary_i;
 pub use binarysearch_by as sc_binary_if;
+pub use binarysearch_by_key as sc_binary_kif;
+pub use binarysearch_detailed_by as sc_binary_dif;
+pub use binarysearch_first_by as sc_binary_fif;
+pub use binarysearch_last_by as sc_binary_lif;
+pub use equal_range_by as sc_equal_range_if;
+pub use count_occurrences_by as sc_count_if;
+pub use search_rotated_by as sc_rotated_if;
+pub use exponential_search_by as sc_exponential_if;
+pub use interpolation_search as sc_interpolation_i;
+pub use jump_search_by as sc_jump_if;
+pub use saddleback_search as sc_saddleback_i;
+
+/// The outcome of a binary search returned by `binarysearch_detailed_by`.
+/// 
+/// `index` is the position of the first element with the same priority as
+/// the item that was searched for if `found` is `true`. Otherwise, it is the
+/// position where the item should be inserted to keep the sequence sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchResult {
+    pub index: usize,
+    pub found: bool
+}
+
+impl SearchResult {
+    /// Check if an element with the same priority as the item was found.
+    pub fn is_found(&self) -> bool {
+        self.found
+    }
+
+    /// The position where the item should be inserted to keep the sequence
+    /// sorted. If the item was found, this is the position of the first
+    /// matching element, so inserting the item there puts it before all of
+    /// the elements with the same priority.
+    pub fn insertion_index(&self) -> usize {
+        self.index
+    }
+}
+
+/// Create the error returned when a sequence is not sorted in the order that
+/// the caller asked for. If the sequence is sorted in the opposite direction,
+/// the caller most likely passed in the wrong value for `ascending`, so a
+/// `WrongDirection` error is returned instead of `Unordered`.
+fn unsorted_error(sorted_in_reverse: bool, ascending: bool) -> AgcError {
+    if sorted_in_reverse {
+        AgcError::new(
+            AgcErrorKind::WrongDirection,
+            format!(
+                "sequence is sorted in {} order, try setting ascending to {}.",
+                if ascending {"descending"} else {"ascending"},
+                !ascending
+            )
+        )
+    } else {
+        AgcError::new(AgcErrorKind::Unordered, "sequence is not sorted.")
+    }
+}
 
 /// Find where an `item` should be in an ordered `sequence`. This function
 /// does not check to see if the sequence has been ordered properly or not,
@@ -138,7 +197,9 @@ where
             return length;
         }
     }
-    let mut left = 1;
+    // `item` does not come before the first element, so the search only
+    // moves `right` below `middle` when `middle` is at least 1.
+    let mut left = 0;
     let mut right = length - 1;
     // Put the this order check outside the while loop so that it runs
     // slightly faster.
@@ -147,7 +208,7 @@ where
             let middle = left + (right-left)/2;
             let ordering = compare(item, &sequence[middle]);
             if priority::is_eq(ordering) {
-                return left;
+                return middle;
             } else if priority::is_lt(ordering) {
                 right = middle-1;
             } else {
@@ -159,7 +220,7 @@ where
             let middle = left + (right-left)/2;
             let ordering = compare(item, &sequence[middle]);
             if priority::is_eq(ordering) {
-                return left;
+                return middle;
             } else if priority::is_gt(ordering) {
                 right = middle-1;
             } else {
@@ -172,7 +233,9 @@ where
 
 /// Find where an `item` should be in an ordered `sequence`. This function
 /// checks to see if the sequence has been ordered properly or not, If the
-/// sequence is unsorted, `Err` is returned.
+/// sequence is unsorted, `Err` is returned. If the sequence is sorted, but in
+/// the opposite direction to `ascending`, the error will be of kind
+/// `WrongDirection` instead of `Unordered`.
 /// 
 /// If the slice is correctly sorted, 2 possibilities may arise:
 /// 1. An element with the same priority as `item` is found,
@@ -220,12 +283,10 @@ where
 {
     let sequence = sequence.as_ref();
     if !is_sorted(sequence, ascending) {
-        return Err(
-            AgcError::new(AgcErrorKind::Unordered, "sequence is not sorted.")
-        );
+        return Err(unsorted_error(is_sorted(sequence, !ascending), ascending));
     }
     let location = binarysearch_unchecked(sequence, item, ascending);
-    if priority::eq(item, &sequence[location]) {
+    if location < sequence.len() && priority::eq(item, &sequence[location]) {
         Ok(Ok(location))
     } else {
         Ok(Err(location))
@@ -234,7 +295,9 @@ where
 
 /// Find where an `item` should be in an ordered `sequence`. This function
 /// checks to see if the sequence has been ordered properly or not, If the
-/// sequence is unsorted, `Err` is returned. This function requires a
+/// sequence is unsorted, `Err` is returned. If the sequence is sorted, but in
+/// the opposite direction to `ascending`, the error will be of kind
+/// `WrongDirection` instead of `Unordered`. This function requires a
 /// function to compare two elements together, the function should be passed
 /// as the argument for the parameter called `compare`.
 /// 
@@ -285,9 +348,10 @@ where
 {
     let sequence = sequence.as_ref();
     if !is_sorted_by(sequence, ascending, compare) {
-        return Err(
-            AgcError::new(AgcErrorKind::Unordered, "sequence is not sorted.")
-        );
+        return Err(unsorted_error(
+            is_sorted_by(sequence, !ascending, compare),
+            ascending
+        ));
     }
     let location = binarysearch_unchecked_by(
         sequence,
@@ -295,9 +359,745 @@ where
         ascending, 
         compare
     );
-    if priority::is_eq(compare(item, &sequence[location])) {
+    let found = matches!(
+        sequence.get(location),
+        Some(element) if priority::is_eq(compare(item, element))
+    );
+    if found {
         Ok(Ok(location))
     } else {
         Ok(Err(location))
     }
+}
+
+/// Find where an element whose key is `key` should be in an ordered
+/// `sequence`, where `extract` gives the key of each element. The sequence
+/// must be sorted by the keys of its elements. This works like
+/// `binarysearch_by`, returning `Ok(Ok(location))` if an element with the
+/// same key is found and `Ok(Err(location))` with the place where such an
+/// element should be inserted otherwise. If several elements have the same
+/// key, the first of them is returned, like `binarysearch_first_by`. If the
+/// sequence is not sorted by key, an error of kind `Unordered` or
+/// `WrongDirection` is returned.
+/// 
+/// Unlike `binarysearch_by`, `key` does not need to be a whole element, so
+/// there is no need to build a fake element just to search for its key.
+/// 
+/// # Example
+/// ```
+///     use algocol::binarysearch::binarysearch_by_key;
+///     let people = [("ann", 21), ("bob", 25), ("cat", 30)];
+///     let found = binarysearch_by_key(&people[..], &25, true, |p| p.1);
+///     assert_eq!(found, Ok(Ok(1)));
+///     let missing = binarysearch_by_key(&people[..], &28, true, |p| p.1);
+///     assert_eq!(missing, Ok(Err(2)));
+/// ```
+pub fn binarysearch_by_key<B, F, S, T>(
+    sequence: &S,
+    key: &B,
+    ascending: bool,
+    extract: F
+) -> AgcResult<Result<usize, usize>>
+where
+    B: Ord,
+    S: AsRef<[T]> + ?Sized,
+    F: Fn(&T) -> B + Copy
+{
+    let sequence = sequence.as_ref();
+    let compare = |a: &T, b: &T| extract(a).cmp(&extract(b));
+    if !is_sorted_by(sequence, ascending, compare) {
+        return Err(unsorted_error(
+            is_sorted_by(sequence, !ascending, compare),
+            ascending
+        ));
+    }
+    Ok(search_edge_unchecked(sequence, ascending, false, |element| {
+        key.cmp(&extract(element))
+    }))
+}
+
+/// Find where an `item` should be in an ordered `sequence`, like
+/// `binarysearch_by`, but return a `SearchResult` instead of a nested
+/// `Result`. The sequence is checked to see if it has been sorted properly,
+/// and an error of kind `Unordered` or `WrongDirection` is returned if it
+/// has not.
+/// 
+/// Unlike `binarysearch_by`, `index` is always the position of the first
+/// element that has the same priority as `item` when there are several of
+/// them.
+/// 
+/// # Example
+/// ```
+///     use algocol::binarysearch::binarysearch_detailed_by;
+///     let array = [0, 2, 4, 4, 6];
+///     let compare = |a: &i32, b: &i32| a.cmp(b);
+///     let hit = binarysearch_detailed_by(&array[..], &4, true, compare)
+///         .unwrap();
+///     assert!(hit.is_found());
+///     assert_eq!(hit.index, 2);
+///     let miss = binarysearch_detailed_by(&array[..], &5, true, compare)
+///         .unwrap();
+///     assert!(!miss.is_found());
+///     assert_eq!(miss.insertion_index(), 4);
+/// ```
+pub fn binarysearch_detailed_by<F, S, T>(
+    sequence: &S,
+    item: &T,
+    ascending: bool,
+    compare: F
+) -> AgcResult<SearchResult>
+where
+    S: AsRef<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_ref();
+    if !is_sorted_by(sequence, ascending, compare) {
+        return Err(unsorted_error(
+            is_sorted_by(sequence, !ascending, compare),
+            ascending
+        ));
+    }
+    // Pretend that `item` comes before every element with the same priority
+    // so that the search never stops early and always lands on the first
+    // matching element.
+    let index = binarysearch_unchecked_by(
+        sequence,
+        item,
+        ascending,
+        |a, b| match compare(a, b) {
+            Ordering::Equal if ascending => Ordering::Less,
+            Ordering::Equal => Ordering::Greater,
+            ordering => ordering
+        }
+    );
+    let found = matches!(
+        sequence.get(index),
+        Some(element) if priority::is_eq(compare(item, element))
+    );
+    Ok(SearchResult {index, found})
+}
+
+/// Find the lowest index of an element with the same priority as `item` in
+/// an ordered `sequence`. Like `binarysearch_by`, `Ok(Ok(index))` is returned
+/// if such an element is found and `Ok(Err(location))` is returned with the
+/// place where `item` should be inserted if there is none. The sequence is
+/// checked to see if it has been sorted properly, and an error of kind
+/// `Unordered` or `WrongDirection` is returned if it has not.
+/// 
+/// Instead of stopping at the first matching element it comes across, the
+/// search carries on into the half before it, so it always ends up at the
+/// first of several equal elements. The search itself takes O(log n) time,
+/// after an O(n) check that the sequence is sorted.
+/// 
+/// # Example
+/// ```
+///     use algocol::binarysearch::binarysearch_first_by;
+///     let array = [1, 2, 2, 2, 3];
+///     let compare = |a: &i32, b: &i32| a.cmp(b);
+///     let first = binarysearch_first_by(&array[..], &2, true, compare);
+///     assert_eq!(first, Ok(Ok(1)));
+/// ```
+pub fn binarysearch_first_by<F, S, T>(
+    sequence: &S,
+    item: &T,
+    ascending: bool,
+    compare: F
+) -> AgcResult<Result<usize, usize>>
+where
+    S: AsRef<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    binarysearch_edge_by(sequence.as_ref(), item, ascending, false, compare)
+}
+
+/// Find the highest index of an element with the same priority as `item` in
+/// an ordered `sequence`. Like `binarysearch_by`, `Ok(Ok(index))` is returned
+/// if such an element is found and `Ok(Err(location))` is returned with the
+/// place where `item` should be inserted if there is none. The sequence is
+/// checked to see if it has been sorted properly, and an error of kind
+/// `Unordered` or `WrongDirection` is returned if it has not.
+/// 
+/// Instead of stopping at the first matching element it comes across, the
+/// search carries on into the half after it, so it always ends up at the
+/// last of several equal elements. The search itself takes O(log n) time,
+/// after an O(n) check that the sequence is sorted.
+/// 
+/// # Example
+/// ```
+///     use algocol::binarysearch::binarysearch_last_by;
+///     let array = [1, 2, 2, 2, 3];
+///     let compare = |a: &i32, b: &i32| a.cmp(b);
+///     let last = binarysearch_last_by(&array[..], &2, true, compare);
+///     assert_eq!(last, Ok(Ok(3)));
+/// ```
+pub fn binarysearch_last_by<F, S, T>(
+    sequence: &S,
+    item: &T,
+    ascending: bool,
+    compare: F
+) -> AgcResult<Result<usize, usize>>
+where
+    S: AsRef<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    binarysearch_edge_by(sequence.as_ref(), item, ascending, true, compare)
+}
+
+/// Search for the first (or last if `last` is `true`) element with the same
+/// priority as `item`. See `binarysearch_first_by` and
+/// `binarysearch_last_by`.
+fn binarysearch_edge_by<F, T>(
+    sequence: &[T],
+    item: &T,
+    ascending: bool,
+    last: bool,
+    compare: F
+) -> AgcResult<Result<usize, usize>>
+where
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    if !is_sorted_by(sequence, ascending, compare) {
+        return Err(unsorted_error(
+            is_sorted_by(sequence, !ascending, compare),
+            ascending
+        ));
+    }
+    Ok(search_edge_unchecked(sequence, ascending, last, |element| {
+        compare(item, element)
+    }))
+}
+
+/// `binarysearch_edge_by` without checking that `sequence` is sorted.
+/// `probe` gives the ordering of the item being searched for compared to an
+/// element, so that the item does not have to be a `T`.
+fn search_edge_unchecked<P, T>(
+    sequence: &[T],
+    ascending: bool,
+    last: bool,
+    probe: P
+) -> Result<usize, usize>
+where
+    P: Fn(&T) -> Ordering
+{
+    // The answer is always in `[left, right)`, or is `left` once the window
+    // is empty.
+    let mut left = 0;
+    let mut right = sequence.len();
+    let mut found = None;
+    while left < right {
+        let middle = left + (right-left)/2;
+        let ordering = probe(&sequence[middle]);
+        // Whether the item belongs before `sequence[middle]`
+        let before = if ascending {
+            priority::is_lt(ordering)
+        } else {
+            priority::is_gt(ordering)
+        };
+        if priority::is_eq(ordering) {
+            found = Some(middle);
+            if last {
+                left = middle+1;
+            } else {
+                right = middle;
+            }
+        } else if before {
+            right = middle;
+        } else {
+            left = middle+1;
+        }
+    }
+    found.ok_or(left)
+}
+
+/// Find the range of indices of the elements in an ordered `sequence` which
+/// have the same priority as `item`. If there are no such elements, the
+/// range is empty and starts and ends where `item` should be inserted. The
+/// sequence is checked to see if it has been sorted properly, and an error
+/// of kind `Unordered` or `WrongDirection` is returned if it has not.
+/// 
+/// The start and end of the range are found with 2 binary searches, like
+/// `binarysearch_first_by` and `binarysearch_last_by`, so the searches take
+/// O(log n) time without going through all of the matching elements.
+/// However, checking that the sequence is sorted takes O(n) time, so that is
+/// how long the whole function takes.
+/// 
+/// # Example
+/// ```
+///     use algocol::binarysearch::equal_range_by;
+///     let array = [1, 2, 2, 2, 3];
+///     let compare = |a: &i32, b: &i32| a.cmp(b);
+///     assert_eq!(equal_range_by(&array[..], &2, true, compare), Ok(1..4));
+///     assert_eq!(equal_range_by(&array[..], &0, true, compare), Ok(0..0));
+/// ```
+pub fn equal_range_by<F, S, T>(
+    sequence: &S,
+    item: &T,
+    ascending: bool,
+    compare: F
+) -> AgcResult<Range<usize>>
+where
+    S: AsRef<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_ref();
+    if !is_sorted_by(sequence, ascending, compare) {
+        return Err(unsorted_error(
+            is_sorted_by(sequence, !ascending, compare),
+            ascending
+        ));
+    }
+    let probe = |element: &T| compare(item, element);
+    let first = search_edge_unchecked(sequence, ascending, false, probe);
+    Ok(match first {
+        Ok(start) => {
+            // There is at least one match, so the last one is found too
+            let last = search_edge_unchecked(sequence, ascending, true, probe);
+            start..last.unwrap()+1
+        },
+        Err(location) => location..location
+    })
+}
+
+/// Count the elements in an ordered `sequence` which have the same priority
+/// as `item`, which is 0 if there are none. The sequence is checked to see if
+/// it has been sorted properly, and an error of kind `Unordered` or
+/// `WrongDirection` is returned if it has not.
+/// 
+/// The matching elements are next to each other in a sorted sequence, so
+/// the first and last of them are found with 2 binary searches (see
+/// `equal_range_by`) and the count is the distance between them. The
+/// searches take O(log n) time no matter how many elements match, but
+/// checking that the sequence is sorted goes through every element first,
+/// so the whole function takes O(n) time.
+/// 
+/// # Example
+/// ```
+///     use algocol::binarysearch::count_occurrences_by;
+///     let array = [1, 2, 2, 2, 3];
+///     let compare = |a: &i32, b: &i32| a.cmp(b);
+///     assert_eq!(count_occurrences_by(&array[..], &2, true, compare), Ok(3));
+///     assert_eq!(count_occurrences_by(&array[..], &4, true, compare), Ok(0));
+/// ```
+pub fn count_occurrences_by<F, S, T>(
+    sequence: &S,
+    item: &T,
+    ascending: bool,
+    compare: F
+) -> AgcResult<usize>
+where
+    S: AsRef<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    equal_range_by(sequence, item, ascending, compare).map(|range| range.len())
+}
+
+/// Find the smallest integer in the range `[lo, hi]` for which `pred`
+/// returns `true`. `pred` must be monotone, which means that once it returns
+/// `true` for a value, it must also return `true` for every value after it.
+/// If `pred` is `false` for every value in the range or `lo > hi`, `None` is
+/// returned.
+/// 
+/// This is known as "binary search on the answer", and is used for problems
+/// where it is easier to check whether a value is good enough than to
+/// calculate the best value directly, such as finding the smallest capacity
+/// that can fit a list of items. `pred` is called O(log(hi - lo)) times.
+/// 
+/// # Example
+/// ```
+///     use algocol::binarysearch::binary_search_answer;
+///     // The smallest number whose square is at least 50
+///     assert_eq!(binary_search_answer(0, 50, |x| x*x >= 50), Some(8));
+///     assert_eq!(binary_search_answer(0, 5, |x| x*x >= 50), None);
+/// ```
+pub fn binary_search_answer<P>(lo: i64, hi: i64, pred: P) -> Option<i64>
+where
+    P: Fn(i64) -> bool
+{
+    if lo > hi || !pred(hi) {
+        return None;
+    }
+    let mut left = lo;
+    let mut right = hi;
+    // `pred(right)` is always `true`, so the answer is in `[left, right]`.
+    while left < right {
+        // Calculated with i128 so that `right - left` cannot overflow.
+        let middle = left + ((right as i128 - left as i128) / 2) as i64;
+        if pred(middle) {
+            right = middle;
+        } else {
+            left = middle + 1;
+        }
+    }
+    Some(left)
+}
+
+/// Find the location of `item` in a sorted `sequence` which has been rotated,
+/// like the ones made by `utils::slice::rotate_sorted`. A rotated sequence
+/// is made up of 2 runs which are both sorted in the order given by
+/// `ascending`, where every element in the first run comes after every
+/// element in the second run, such as `[3, 4, 0, 1, 2]`. If `item` is not in
+/// the sequence, `None` is returned. The elements of `sequence` must all be
+/// different from each other, otherwise `item` may not be found.
+/// 
+/// At each step, at least one of the 2 halves on either side of the middle
+/// element is sorted, which can be found out by comparing its ends. If
+/// `item` is within the range of the sorted half, the search continues
+/// there, otherwise it continues in the other half. This takes O(log n)
+/// time, just like a normal binary search.
+/// 
+/// # Example
+/// ```
+///     use algocol::binarysearch::search_rotated_by;
+///     let array = [5, 7, 9, 1, 3];
+///     let compare = |a: &i32, b: &i32| a.cmp(b);
+///     assert_eq!(search_rotated_by(&array[..], &3, true, compare), Some(4));
+///     assert_eq!(search_rotated_by(&array[..], &4, true, compare), None);
+/// ```
+pub fn search_rotated_by<F, S, T>(
+    sequence: &S,
+    item: &T,
+    ascending: bool,
+    compare: F
+) -> Option<usize>
+where
+    S: AsRef<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_ref();
+    let compare = |a: &T, b: &T| {
+        if ascending {compare(a, b)} else {compare(b, a)}
+    };
+    if sequence.is_empty() {
+        return None;
+    }
+    let mut left = 0;
+    let mut right = sequence.len() - 1;
+    while left <= right {
+        let middle = left + (right-left)/2;
+        let ordering = compare(item, &sequence[middle]);
+        if priority::is_eq(ordering) {
+            return Some(middle);
+        }
+        let left_sorted = priority::is_le(
+            compare(&sequence[left], &sequence[middle])
+        );
+        let go_left = if left_sorted {
+            // `item` is in the sorted left half
+            priority::is_ge(compare(item, &sequence[left]))
+                && priority::is_lt(ordering)
+        } else {
+            // `item` is not in the sorted right half
+            !(priority::is_gt(ordering)
+                && priority::is_le(compare(item, &sequence[right])))
+        };
+        if go_left {
+            if middle == 0 {
+                return None;
+            }
+            right = middle - 1;
+        } else {
+            left = middle + 1;
+        }
+    }
+    None
+}
+
+/// Find the index of the first element in an ordered `sequence` which does
+/// not come before `item`, which is where `item` should be inserted to keep
+/// the sequence sorted, in front of any elements with the same priority. If
+/// every element comes before `item`, the length of the sequence is
+/// returned. Like `binarysearch_unchecked_by`, this function does not check
+/// whether the sequence has been ordered properly.
+/// 
+/// Instead of searching the whole sequence, this function first checks the
+/// elements at indices 0, 1, 3, 7, 15, and so on until it finds one that
+/// does not come before `item`, and then does a binary search between that
+/// element and the one checked before it. This takes O(log i) time, where
+/// `i` is the index that is returned, so it is faster than a binary search
+/// when `item` belongs near the front of the sequence. This is what timsort
+/// uses when "galloping" (see `timsort::merge_galloping_by`).
+/// 
+/// # Example
+/// ```
+///     use algocol::binarysearch::exponential_search_by;
+///     let array = [1, 2, 2, 2, 5, 8, 13, 21, 34];
+///     let compare = |a: &i32, b: &i32| a.cmp(b);
+///     assert_eq!(exponential_search_by(&array[..], &2, true, compare), 1);
+///     assert_eq!(exponential_search_by(&array[..], &6, true, compare), 5);
+///     assert_eq!(exponential_search_by(&array[..], &40, true, compare), 9);
+/// ```
+pub fn exponential_search_by<F, S, T>(
+    sequence: &S,
+    item: &T,
+    ascending: bool,
+    compare: F
+) -> usize
+where
+    S: AsRef<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_ref();
+    let length = sequence.len();
+    // Check if an element must come before `item`.
+    let comes_before = |element: &T| {
+        let ordering = compare(item, element);
+        if ascending {
+            priority::is_gt(ordering)
+        } else {
+            priority::is_lt(ordering)
+        }
+    };
+    // Everything before `left` comes before `item`.
+    let mut left = 0;
+    let mut bound = 1;
+    while bound <= length && comes_before(&sequence[bound-1]) {
+        left = bound;
+        bound *= 2;
+    }
+    // `sequence[bound-1]` does not come before `item` (if it exists).
+    let mut right = min(bound-1, length);
+    while left < right {
+        let middle = left + (right-left)/2;
+        if comes_before(&sequence[middle]) {
+            left = middle+1;
+        } else {
+            right = middle;
+        }
+    }
+    left
+}
+
+/// Find where an `item` should be in an ordered `sequence` by jumping ahead
+/// in blocks and then checking each element in the block where `item`
+/// belongs. Like `binarysearch_by`, `Ok(Ok(location))` is returned if an
+/// element with the same priority as `item` is found and `Ok(Err(location))`
+/// with the place where it should be inserted otherwise. If there are
+/// several matching elements, the first one is returned. The sequence is
+/// checked to see if it has been sorted properly, and an error of kind
+/// `Unordered` or `WrongDirection` is returned if it has not.
+/// 
+/// The blocks are `sqrt(n)` elements long, so there are at most `sqrt(n)`
+/// jumps followed by at most `sqrt(n)` steps through the last block, which
+/// takes O(sqrt(n)) time. This is slower than a binary search, but it only
+/// ever moves forward through the sequence, which is useful when going
+/// backwards is expensive.
+/// 
+/// # Example
+/// ```
+///     use algocol::binarysearch::jump_search_by;
+///     let array = [1, 3, 5, 7, 9, 11, 13, 15, 17];
+///     let compare = |a: &i32, b: &i32| a.cmp(b);
+///     assert_eq!(jump_search_by(&array[..], &13, true, compare), Ok(Ok(6)));
+///     assert_eq!(jump_search_by(&array[..], &8, true, compare), Ok(Err(4)));
+/// ```
+pub fn jump_search_by<F, S, T>(
+    sequence: &S,
+    item: &T,
+    ascending: bool,
+    compare: F
+) -> AgcResult<Result<usize, usize>>
+where
+    S: AsRef<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_ref();
+    if !is_sorted_by(sequence, ascending, compare) {
+        return Err(unsorted_error(
+            is_sorted_by(sequence, !ascending, compare),
+            ascending
+        ));
+    }
+    let length = sequence.len();
+    let step = ((length as f64).sqrt() as usize).max(1);
+    // Whether `element` belongs before `item`
+    let before = |element: &T| if ascending {
+        priority::is_lt(compare(element, item))
+    } else {
+        priority::is_gt(compare(element, item))
+    };
+    // Jump to the end of each block until one ends at or after `item`
+    let mut start = 0;
+    while start < length && before(&sequence[min(start + step, length) - 1]) {
+        start += step;
+    }
+    let end = min(start + step, length);
+    let mut location = start.min(length);
+    while location < end && before(&sequence[location]) {
+        location += 1;
+    }
+    let found = matches!(
+        sequence.get(location),
+        Some(element) if priority::is_eq(compare(item, element))
+    );
+    Ok(if found {Ok(location)} else {Err(location)})
+}
+
+/// Find `item` in a `matrix` whose rows and columns are both sorted in
+/// ascending order. `Ok(Some((row, column)))` is returned with the position
+/// of the first match, which is the leftmost match in the topmost row that
+/// has one, and `Ok(None)` is returned if there is no match. An `AgcError`
+/// of kind `InvalidArgument` is returned if the rows are not all the same
+/// length. The matrix is not checked to see if it has been sorted, as that
+/// would take longer than the search itself, so the result is meaningless
+/// if it has not been.
+/// 
+/// The search starts at the top-right corner. If the element there is
+/// bigger than `item`, so is everything below it, so the search moves left
+/// to the next column. If it is smaller, so is everything to the left of it,
+/// so the search moves down to the next row. Each step rules out a row or a
+/// column, so this takes O(rows + columns) time.
+/// 
+/// # Example
+/// ```
+///     use algocol::binarysearch::saddleback_search;
+///     let matrix: [&[i32]; 3] = [&[1, 4, 7], &[2, 5, 8], &[3, 6, 9]];
+///     assert_eq!(saddleback_search(&matrix, &6), Ok(Some((2, 1))));
+///     assert_eq!(saddleback_search(&matrix, &10), Ok(None));
+/// ```
+pub fn saddleback_search<T: Ord>(
+    matrix: &[&[T]],
+    item: &T
+) -> AgcResult<Option<(usize, usize)>> {
+    let columns = match matrix.first() {
+        Some(row) => row.len(),
+        None => return Ok(None)
+    };
+    if matrix.iter().any(|row| row.len() != columns) {
+        return Err(AgcError::new(
+            AgcErrorKind::InvalidArgument,
+            "every row in the matrix must have the same length."
+        ));
+    }
+    let mut row = 0;
+    // Every column from `column` onwards has been ruled out
+    let mut column = columns;
+    while row < matrix.len() && column > 0 {
+        match matrix[row][column-1].cmp(item) {
+            Ordering::Greater => column -= 1,
+            Ordering::Less => row += 1,
+            Ordering::Equal => {
+                // Earlier rows have no match, but this one might have more
+                // to the left
+                let mut first = column-1;
+                while first > 0 && matrix[row][first-1] == *item {
+                    first -= 1;
+                }
+                return Ok(Some((row, first)));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Find where an `item` should be in an ordered `sequence` of numbers by
+/// guessing where it is from the values at either end of the part of the
+/// sequence being searched. Like `binarysearch`, `Ok(Ok(location))` is
+/// returned if `item` is found and `Ok(Err(location))` with the place where
+/// it should be inserted otherwise. The sequence is checked to see if it has
+/// been sorted properly, and an error of kind `Unordered` or
+/// `WrongDirection` is returned if it has not.
+/// 
+/// Binary search always checks the middle of the part it is searching.
+/// Interpolation search instead assumes that the values go up by roughly the
+/// same amount from one element to the next, and checks where `item` would
+/// be if they did. If the values really are spread out evenly, this takes
+/// O(log log n) probes on average, but it can take O(n) probes if they are
+/// not. When both ends have the same value, the first one is checked.
+/// 
+/// # Example
+/// ```
+///     use algocol::binarysearch::interpolation_search;
+///     let array = (0..100).map(|x| x * 10).collect::<Vec<i32>>();
+///     assert_eq!(interpolation_search(&array[..], &420, true), Ok(Ok(42)));
+///     assert_eq!(interpolation_search(&array[..], &425, true), Ok(Err(43)));
+/// ```
+pub fn interpolation_search<S, T>(
+    sequence: &S,
+    item: &T,
+    ascending: bool
+) -> AgcResult<Result<usize, usize>>
+where
+    S: AsRef<[T]> + ?Sized,
+    T: AgcNumberLike + ToPrimitive
+{
+    let (_, location) = interpolation_search_counted(
+        sequence,
+        item,
+        ascending
+    )?;
+    Ok(location)
+}
+
+/// Search for `item` like `interpolation_search`, and also return the number
+/// of elements which were probed along the way. This can be compared with
+/// the number of comparisons made by a binary search (see
+/// `utils::cmp::CountingComparator`) to see how many fewer probes
+/// interpolation search needs on evenly spread out data.
+/// 
+/// # Example
+/// ```
+///     use algocol::binarysearch::interpolation_search_counted;
+///     let array = (0..1000).collect::<Vec<i64>>();
+///     let (probes, location) = interpolation_search_counted(
+///         &array[..], &777, true
+///     ).unwrap();
+///     assert_eq!(location, Ok(777));
+///     assert_eq!(probes, 1);
+/// ```
+pub fn interpolation_search_counted<S, T>(
+    sequence: &S,
+    item: &T,
+    ascending: bool
+) -> AgcResult<(usize, Result<usize, usize>)>
+where
+    S: AsRef<[T]> + ?Sized,
+    T: AgcNumberLike + ToPrimitive
+{
+    let sequence = sequence.as_ref();
+    if !is_sorted(sequence, ascending) {
+        return Err(unsorted_error(is_sorted(sequence, !ascending), ascending));
+    }
+    // Whether `a` belongs before `b`
+    let before = |a: &T, b: &T| if ascending {a < b} else {a > b};
+    let mut probes = 0;
+    if sequence.is_empty() {
+        return Ok((probes, Err(0)));
+    }
+    let mut low = 0;
+    let mut high = sequence.len() - 1;
+    // Every element before `low` belongs before `item`, and every element
+    // after `high` belongs after it
+    while low <= high
+    && !before(item, &sequence[low])
+    && !before(&sequence[high], item) {
+        let (first, last) = (sequence[low], sequence[high]);
+        let probe = if first == last {
+            low
+        } else {
+            // Working in floating point numbers avoids overflowing `T`
+            let to_f64 = |value: T| value.to_f64().unwrap_or(0.0);
+            let fraction = (to_f64(*item) - to_f64(first))
+                / (to_f64(last) - to_f64(first));
+            let offset = (fraction * (high - low) as f64).round() as usize;
+            low + offset.min(high - low)
+        };
+        probes += 1;
+        let element = &sequence[probe];
+        if element == item {
+            return Ok((probes, Ok(probe)));
+        } else if before(item, element) {
+            if probe == 0 {
+                break;
+            }
+            high = probe - 1;
+        } else {
+            low = probe + 1;
+        }
+    }
+    let location = if low > high || before(item, &sequence[low]) {
+        low
+    } else {
+        high + 1
+    };
+    Ok((probes, Err(location)))
 }
\ No newline at end of file
@@ -4,7 +4,7 @@
 //! defined in `std`.
 
 use std::{
-    convert::{Into},
+    convert::From,
     error,
     fmt
 };
@@ -17,9 +17,15 @@ pub enum AgcErrorKind {
     OutOfBounds,
     WrongOrder,
     Unordered,
+    WrongDirection,
     AlreadyExists,
     SameNode,
     NotFound,
+    InvalidArgument,
+    InconsistentOrder,
+    Disconnected,
+    CyclicGraph,
+    NegativeCycle,
     Other
 }
 
@@ -29,15 +35,15 @@ impl fmt::Display for AgcErrorKind {
     }
 }
 
-impl Into<String> for AgcErrorKind {
-    fn into(self) -> String {
-        format!("{:?}", self)
+impl From<AgcErrorKind> for String {
+    fn from(kind: AgcErrorKind) -> String {
+        format!("{:?}", kind)
     }
 }
 
-impl<'a> Into<String> for &'a AgcErrorKind {
-    fn into(self) -> String {
-        format!("{:?}", self)
+impl From<&AgcErrorKind> for String {
+    fn from(kind: &AgcErrorKind) -> String {
+        format!("{:?}", kind)
     }
 }
 
@@ -57,6 +63,11 @@ impl AgcError {
         let description = description.as_ref().to_string();
         Self {kind, description}
     }
+
+    /// Get the class of error that has occurred.
+    pub fn kind(&self) -> AgcErrorKind {
+        self.kind
+    }
 }
 
 impl fmt::Display for AgcError {
@@ -0,0 +1,197 @@
+//! Computational geometry algorithms on points in 2D space.
+//! 
+//! Points are represented as `(x, y)` tuples of `f64`. The functions in this
+//! module return the indices of points in the slice they were given instead
+//! of the points themselves.
+
+use crate::sort::{mergesort::merge_buffered, mergesort_by};
+
+/// Sort `indices` by the coordinate of `points` picked by `coordinate`.
+fn sort_indices_by<C>(
+    indices: &mut [usize],
+    points: &[(f64, f64)],
+    coordinate: C
+)
+where
+    C: Fn(&(f64, f64)) -> f64 + Copy
+{
+    // Mergesort only fails if `merge` is given bad indices, which it never
+    // does here.
+    mergesort_by(indices, true, |a: &usize, b: &usize| {
+        coordinate(&points[*a]).total_cmp(&coordinate(&points[*b]))
+    }).unwrap();
+}
+
+fn squared_distance(a: &(f64, f64), b: &(f64, f64)) -> f64 {
+    (a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)
+}
+
+/// Find the closest pair of points among the points in `by_x`, which must be
+/// sorted by their x coordinates. The squared distance between them is
+/// returned alongside their indices. `by_y` must start out as a copy of
+/// `by_x`, and is left sorted by the y coordinates of the points.
+fn closest_pair_in(
+    points: &[(f64, f64)],
+    by_x: &[usize],
+    by_y: &mut [usize]
+) -> ((usize, usize), f64) {
+    let length = by_x.len();
+    let mut best = ((by_x[0], by_x[1]), f64::INFINITY);
+    // Small enough to check every pair
+    if length <= 3 {
+        for i in 0..length {
+            for j in i+1..length {
+                let distance = squared_distance(
+                    &points[by_x[i]],
+                    &points[by_x[j]]
+                );
+                if distance < best.1 {
+                    best = ((by_x[i], by_x[j]), distance);
+                }
+            }
+        }
+        sort_indices_by(by_y, points, |point| point.1);
+        return best;
+    }
+    let middle = length/2;
+    let middle_x = points[by_x[middle]].0;
+    let (left_by_y, right_by_y) = by_y.split_at_mut(middle);
+    let left = closest_pair_in(points, &by_x[..middle], left_by_y);
+    let right = closest_pair_in(points, &by_x[middle..], right_by_y);
+    best = if left.1 <= right.1 {left} else {right};
+    // Both halves come back sorted by y, so merging them sorts the whole of
+    // `by_y` in linear time. The indices are always in bounds.
+    merge_buffered(by_y, 0, middle - 1, length - 1, true, |a, b| {
+        points[*a].1.total_cmp(&points[*b].1)
+    }).unwrap();
+    // A pair with one point on each side can only be closer than `best` if
+    // both points are within `best` of the dividing line. Going through this
+    // strip in order of y, each point only has to be checked against the
+    // points above it which are less than `best` away vertically, and there
+    // can only be a few of them.
+    let strip = by_y
+        .iter()
+        .copied()
+        .filter(|index| (points[*index].0 - middle_x).powi(2) < best.1)
+        .collect::<Vec<usize>>();
+    for (i, lower) in strip.iter().enumerate() {
+        for upper in strip[i+1..].iter() {
+            if (points[*upper].1 - points[*lower].1).powi(2) >= best.1 {
+                break;
+            }
+            let distance = squared_distance(&points[*lower], &points[*upper]);
+            if distance < best.1 {
+                best = ((*lower, *upper), distance);
+            }
+        }
+    }
+    best
+}
+
+/// Find the 2 points in `points` which are closest to each other. The indices
+/// of the 2 points (with the smaller index first) and the distance between
+/// them are returned. If there are fewer than 2 points, `None` is returned.
+/// 
+/// This uses the divide-and-conquer algorithm by Shamos and Hoey. The points
+/// are sorted by their x coordinates and split into 2 halves by a vertical
+/// line. The closest pair in each half is found recursively, and the
+/// smaller of the 2 distances, `d`, is used to limit the search for pairs
+/// which cross the line to a strip of width `2d` around it. When the strip
+/// is sorted by y coordinate, each point in it only needs to be compared
+/// with at most 7 points after it. Rather than sorting the strip at every
+/// level, each recursive call also sorts its half by y coordinate, so the 2
+/// halves only have to be merged. The combining step therefore takes linear
+/// time, and the whole algorithm takes O(n log n) time. The points are
+/// sorted by x with this crate's mergesort and merged with `merge_buffered`.
+/// 
+/// # Example
+/// ```
+///     use algocol::geometry::closest_pair;
+///     let points = [(0.0, 0.0), (5.0, 5.0), (1.0, 1.0), (9.0, 0.0)];
+///     let ((a, b), distance) = closest_pair(&points).unwrap();
+///     assert_eq!((a, b), (0, 2));
+///     assert!((distance - 2f64.sqrt()).abs() < 1e-9);
+/// ```
+pub fn closest_pair(points: &[(f64, f64)]) -> Option<((usize, usize), f64)> {
+    if points.len() < 2 {
+        return None;
+    }
+    let mut by_x = (0..points.len()).collect::<Vec<usize>>();
+    sort_indices_by(&mut by_x, points, |point| point.0);
+    let mut by_y = by_x.clone();
+    let ((a, b), distance) = closest_pair_in(points, &by_x, &mut by_y);
+    Some(((a.min(b), a.max(b)), distance.sqrt()))
+}
+
+/// The cross product of the vectors from `origin` to `a` and from `origin` to
+/// `b`. It is positive if `origin`, `a` and `b` make a counter-clockwise
+/// turn, negative if they make a clockwise turn and 0 if they are collinear.
+fn cross(origin: &(f64, f64), a: &(f64, f64), b: &(f64, f64)) -> f64 {
+    (a.0 - origin.0) * (b.1 - origin.1) - (a.1 - origin.1) * (b.0 - origin.0)
+}
+
+/// Find the convex hull of `points`, which is the smallest convex polygon
+/// that contains all of them. The indices of the corners of the hull are
+/// returned in counter-clockwise order, starting from the point with the
+/// smallest x coordinate (and the smallest y coordinate if there is a tie).
+/// 
+/// Points which lie on an edge of the hull but are not corners are left out,
+/// and if the same point appears more than once, only its first index is
+/// used. If every point is on the same line, only the 2 ends of the line are
+/// returned, and if every point is the same, only 1 index is returned.
+/// 
+/// This uses Andrew's monotone chain algorithm. The points are sorted by
+/// their x coordinates (with this crate's mergesort), and then the lower
+/// half of the hull is built by going through them from left to right and
+/// the upper half by going from right to left. Whenever the last 2 points in
+/// a half and the next point do not make a counter-clockwise turn, the last
+/// point cannot be a corner and is removed.
+/// 
+/// # Example
+/// ```
+///     use algocol::geometry::convex_hull;
+///     let points = [
+///         (0.0, 0.0), (2.0, 2.0), (1.0, 1.0), (2.0, 0.0), (0.0, 2.0)
+///     ];
+///     // (1.0, 1.0) is inside the square
+///     assert_eq!(convex_hull(&points), [0, 3, 1, 4]);
+/// ```
+pub fn convex_hull(points: &[(f64, f64)]) -> Vec<usize> {
+    let mut sorted = (0..points.len()).collect::<Vec<usize>>();
+    // Sort by y first, so that the stable sort by x breaks ties with y.
+    sort_indices_by(&mut sorted, points, |point| point.1);
+    sort_indices_by(&mut sorted, points, |point| point.0);
+    sorted.dedup_by(|later, earlier| points[*later] == points[*earlier]);
+    if sorted.len() <= 1 {
+        return sorted;
+    }
+    let turns_left = |hull: &[usize], next: usize| {
+        let length = hull.len();
+        cross(
+            &points[hull[length-2]],
+            &points[hull[length-1]],
+            &points[next]
+        ) > 0.0
+    };
+    let mut hull: Vec<usize> = Vec::with_capacity(sorted.len() + 1);
+    for index in sorted.iter() {
+        while hull.len() >= 2 && !turns_left(&hull, *index) {
+            hull.pop();
+        }
+        hull.push(*index);
+    }
+    // The upper half starts from the rightmost point, which is already at
+    // the end of the lower half, and must not remove any points from the
+    // lower half.
+    let lower_length = hull.len();
+    for index in sorted.iter().rev().skip(1) {
+        while hull.len() > lower_length && !turns_left(&hull, *index) {
+            hull.pop();
+        }
+        hull.push(*index);
+    }
+    // The upper half ends at the leftmost point, which the lower half started
+    // from.
+    hull.pop();
+    hull
+}
\ No newline at end of file
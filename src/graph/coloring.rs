@@ -0,0 +1,85 @@
+//! Functions which colour the nodes of a graph.
+//! 
+//! Colouring a graph means giving every node a colour, so that no 2 nodes
+//! which share an edge have the same colour. Colours are numbered from 0.
+//! Finding the smallest number of colours needed is NP-hard, so the
+//! functions here give good colourings quickly instead of the best ones.
+
+use std::collections::{HashMap, HashSet};
+use crate::{
+    error::{AgcError, AgcErrorKind, AgcResult},
+    graph::maps::AdjacencyMatrix,
+    traits::{AgcHashable, AgcNumberLike}
+};
+
+impl<K, V> AdjacencyMatrix<K, V>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    /// Colour the nodes of this graph one at a time in the order given by
+    /// `ordering`, ignoring the direction of the edges. Each node gets the
+    /// smallest colour which none of its neighbours coloured before it have.
+    /// A map from each node to its colour is returned.
+    /// 
+    /// The number of colours used depends a lot on `ordering`. A bad order
+    /// can use many more colours than necessary, even on a tree, which only
+    /// ever needs 2. `smallest_last_ordering` gives an order which tends to
+    /// use few colours.
+    /// 
+    /// `ordering` must have every node in the graph exactly once. If it has a
+    /// node which is not in the graph, an `AgcError` of kind `NotFound` is
+    /// returned, and if it leaves out a node or has a node more than once,
+    /// one of kind `InvalidArgument` is returned.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     let edges = [('a', 'b'), ('b', 'c'), ('c', 'd')];
+    ///     for (left, right) in edges.iter() {
+    ///         matrix.push(Edge::new(*left, *right, 1, EdgeKind::ToRight))
+    ///             .unwrap();
+    ///     }
+    ///     // Colouring both ends of the path first forces a third colour
+    ///     let bad = matrix.greedy_coloring(&['a', 'd', 'b', 'c']).unwrap();
+    ///     assert_eq!(bad[&'c'], 2);
+    ///     let good = matrix.greedy_coloring(&['a', 'b', 'c', 'd']).unwrap();
+    ///     assert_eq!(good[&'c'], 0);
+    /// ```
+    pub fn greedy_coloring(
+        &self,
+        ordering: &[K]
+    ) -> AgcResult<HashMap<K, usize>> {
+        let adjacency = self.undirected_adjacency();
+        let mut colours: HashMap<K, usize> = HashMap::new();
+        for node in ordering.iter() {
+            let neighbours = match adjacency.get(node) {
+                Some(neighbours) => neighbours,
+                None => return Err(AgcError::new(
+                    AgcErrorKind::NotFound,
+                    "node could not be found in the graph."
+                ))
+            };
+            if colours.contains_key(node) {
+                return Err(AgcError::new(
+                    AgcErrorKind::InvalidArgument,
+                    "a node cannot be coloured more than once."
+                ));
+            }
+            let taken = neighbours
+                .iter()
+                .filter_map(|neighbour| colours.get(*neighbour))
+                .collect::<HashSet<&usize>>();
+            let colour = (0..).find(|colour| !taken.contains(colour)).unwrap();
+            colours.insert(node.clone(), colour);
+        }
+        if colours.len() != adjacency.len() {
+            return Err(AgcError::new(
+                AgcErrorKind::InvalidArgument,
+                "the ordering must include every node in the graph."
+            ));
+        }
+        Ok(colours)
+    }
+}
\ No newline at end of file
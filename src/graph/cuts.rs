@@ -0,0 +1,114 @@
+//! Functions which find cuts in a graph.
+
+use std::collections::HashMap;
+use crate::{
+    error::{AgcError, AgcErrorKind, AgcResult},
+    graph::{disjoint_set::DisjointSet, maps::AdjacencyMatrix},
+    traits::{AgcHashable, AgcNumberLike},
+    utils::random::XorShift
+};
+
+impl<K, V> AdjacencyMatrix<K, V>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    /// Estimate the minimum cut of this graph with Karger's algorithm. A cut
+    /// splits the nodes into 2 non-empty groups, and its size is the number
+    /// of edges between the groups. The direction and costs of the edges are
+    /// ignored, and edges going both ways between 2 nodes count as 1 edge.
+    /// 
+    /// Each run of the algorithm contracts a random edge, merging the 2
+    /// nodes on either end into 1 and keeping the edges between the merged
+    /// node and the rest of the graph, until only 2 nodes are left. The
+    /// edges between them form a cut. A single run finds a minimum cut with
+    /// probability at least `2/(n(n-1))`, so the algorithm is run
+    /// `iterations` times and the smallest cut found is returned. About
+    /// `n^2 ln n` iterations make it very unlikely for the result to be
+    /// wrong, but the answer can only ever be too large, never too small.
+    /// 
+    /// `contract_edge` keeps only 1 edge when 2 merged nodes shared a
+    /// neighbour, but Karger's algorithm needs every parallel edge, so the
+    /// contractions are tracked by merging groups of nodes in a
+    /// `DisjointSet` instead. The nodes are sorted before the edges are
+    /// listed, so the same `seed` always gives the same result. Each run
+    /// shuffles the edges with a generator seeded by `seed` and contracts
+    /// them in that order, skipping edges inside a group, which takes
+    /// O(E log V) time per run. A graph which is not connected has a minimum
+    /// cut of 0.
+    /// 
+    /// An `AgcError` of kind `InvalidArgument` is returned if the graph has
+    /// fewer than 2 nodes or `iterations` is 0.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<i32, i32>::new();
+    ///     // A square, which can only be split in 2 by cutting 2 edges
+    ///     for (left, right) in [(0, 1), (1, 2), (2, 3), (3, 0)].iter() {
+    ///         matrix.push(Edge::new(*left, *right, 1, EdgeKind::ToRight))
+    ///             .unwrap();
+    ///     }
+    ///     assert_eq!(matrix.karger_min_cut(20, 1), Ok(2));
+    /// ```
+    pub fn karger_min_cut(
+        &self,
+        iterations: usize,
+        seed: u64
+    ) -> AgcResult<usize>
+    where
+        K: Ord
+    {
+        let adjacency = self.undirected_adjacency();
+        if adjacency.len() < 2 || iterations == 0 {
+            return Err(AgcError::new(
+                AgcErrorKind::InvalidArgument,
+                "the graph needs at least 2 nodes and at least 1 iteration."
+            ));
+        }
+        let mut nodes = adjacency.keys().copied().collect::<Vec<&K>>();
+        nodes.sort_unstable();
+        let indices = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (*node, index))
+            .collect::<HashMap<&K, usize>>();
+        let mut edges = Vec::new();
+        for (from, adjacent) in adjacency.iter() {
+            for to in adjacent.iter() {
+                // Each edge is in `adjacency` twice, once from each end
+                if indices[from] < indices[to] {
+                    edges.push((indices[from], indices[to]));
+                }
+            }
+        }
+        edges.sort_unstable();
+        let mut random = XorShift::new(seed);
+        let mut smallest = edges.len();
+        for _ in 0..iterations {
+            for index in (1..edges.len()).rev() {
+                edges.swap(index, random.next_below(index + 1));
+            }
+            let mut groups = DisjointSet::new();
+            for index in 0..nodes.len() {
+                groups.make_set(index);
+            }
+            for (from, to) in edges.iter() {
+                if groups.set_count() == 2 {
+                    break;
+                }
+                groups.union(from, to)?;
+            }
+            // Only possible if the graph is not connected
+            if groups.set_count() > 2 {
+                return Ok(0);
+            }
+            let cut = edges
+                .iter()
+                .filter(|(from, to)| !groups.connected(from, to))
+                .count();
+            smallest = smallest.min(cut);
+        }
+        Ok(smallest)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,217 @@
+//! Functions which find cycles in a graph.
+
+use std::collections::HashMap;
+use num_traits::Zero;
+use crate::{
+    error::AgcResult,
+    graph::maps::{AdjacencyMatrix, Edge},
+    traits::{AgcHashable, AgcNumberLike}
+};
+
+/// Relax every edge in `edges` once, as in a pass of the Bellman-Ford
+/// algorithm. If going through an edge gives a cheaper way of getting to the
+/// node at its end, its cost in `costs` is lowered. If `predecessors` is
+/// given, the node at the start of the edge is recorded as its predecessor.
+/// Nodes which are not in `costs` have not been reached yet, so edges leaving
+/// them are skipped. The last node whose cost was lowered is returned, or
+/// `None` if no cost changed.
+pub(crate) fn relax_edges<'a, K, V>(
+    edges: &'a [Edge<K, V>],
+    costs: &mut HashMap<&'a K, V>,
+    mut predecessors: Option<&mut HashMap<&'a K, &'a K>>
+) -> Option<&'a K>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    let mut relaxed = None;
+    for edge in edges.iter() {
+        let total = match costs.get(&edge.left) {
+            Some(cost) => *cost + edge.cost,
+            None => continue
+        };
+        if !matches!(costs.get(&edge.right), Some(cost) if *cost <= total) {
+            costs.insert(&edge.right, total);
+            if let Some(predecessors) = predecessors.as_mut() {
+                predecessors.insert(&edge.right, &edge.left);
+            }
+            relaxed = Some(&edge.right);
+        }
+    }
+    relaxed
+}
+
+/// The state of a node during the depth-first search in
+/// `find_cycle_directed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Colour {
+    /// The node has not been visited yet.
+    White,
+    /// The node is on the path currently being followed.
+    Grey,
+    /// Every node reachable from the node has been searched.
+    Black
+}
+
+impl<K, V> AdjacencyMatrix<K, V>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    /// Check if this graph has a cycle, following the direction of the
+    /// edges. See `find_cycle_directed`.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     matrix.push(Edge::new('a', 'b', 1, EdgeKind::ToRight)).unwrap();
+    ///     matrix.push(Edge::new('b', 'c', 1, EdgeKind::ToRight)).unwrap();
+    ///     assert!(!matrix.has_cycle_directed());
+    ///     matrix.push(Edge::new('c', 'a', 1, EdgeKind::ToRight)).unwrap();
+    ///     assert!(matrix.has_cycle_directed());
+    /// ```
+    pub fn has_cycle_directed(&self) -> bool {
+        self.find_cycle_directed().is_some()
+    }
+
+    /// Find a cycle in this graph, following the direction of the edges. The
+    /// nodes on the cycle are returned in the order that the edges go, so
+    /// there is an edge from each node to the next one and from the last
+    /// node back to the first one. `None` is returned if the graph has no
+    /// cycle, which means that it can be sorted topologically. A
+    /// `Bidirectional` edge goes both ways, so it makes a cycle of 2 nodes.
+    /// 
+    /// This is a depth-first search from every node which has not been
+    /// visited yet, where each node is coloured white before it is visited,
+    /// grey while the search is following paths out of it, and black once
+    /// every path out of it has been followed. The grey nodes are exactly
+    /// the nodes on the current path, so an edge going to a grey node
+    /// closes a cycle, which is the part of the path from that node onwards.
+    /// An edge going to a black node cannot, since the search already went
+    /// everywhere that node leads without coming back. This takes O(V + E)
+    /// time.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     matrix.push(Edge::new('a', 'b', 1, EdgeKind::ToRight)).unwrap();
+    ///     matrix.push(Edge::new('b', 'c', 1, EdgeKind::ToRight)).unwrap();
+    ///     matrix.push(Edge::new('c', 'a', 1, EdgeKind::ToRight)).unwrap();
+    ///     let mut cycle = matrix.find_cycle_directed().unwrap();
+    ///     // The cycle can start from any of its nodes
+    ///     while cycle[0] != 'a' {
+    ///         cycle.rotate_left(1);
+    ///     }
+    ///     assert_eq!(cycle, vec!['a', 'b', 'c']);
+    /// ```
+    pub fn find_cycle_directed(&self) -> Option<Vec<K>> {
+        let mut colours: HashMap<&K, Colour> = HashMap::new();
+        for start in self.nodes() {
+            if colours.contains_key(start) {
+                continue;
+            }
+            colours.insert(start, Colour::Grey);
+            let mut path = vec![start];
+            let edges = self.get_adjacent(start).into_iter().flatten();
+            let mut stack = vec![edges];
+            while let Some(adjacent) = stack.last_mut() {
+                let next = match adjacent.next() {
+                    Some((next, _)) => next,
+                    None => {
+                        colours.insert(path.pop().unwrap(), Colour::Black);
+                        stack.pop();
+                        continue;
+                    }
+                };
+                match colours.get(next).copied().unwrap_or(Colour::White) {
+                    Colour::White => {
+                        colours.insert(next, Colour::Grey);
+                        path.push(next);
+                        let edges = self.get_adjacent(next);
+                        stack.push(edges.into_iter().flatten());
+                    },
+                    Colour::Grey => {
+                        let from = path.iter().position(|node| *node == next);
+                        return Some(path[from.unwrap()..]
+                            .iter()
+                            .map(|node| (*node).clone())
+                            .collect());
+                    },
+                    Colour::Black => {}
+                }
+            }
+        }
+        None
+    }
+
+    /// Find a cycle whose edges add up to a negative cost, if this graph has
+    /// one. The nodes on the cycle are returned in the order that the edges
+    /// go, so there is an edge from each node to the next one and from the
+    /// last node back to the first one. `Ok(None)` is returned if the graph
+    /// has no negative cycle.
+    /// 
+    /// This uses the Bellman-Ford algorithm, starting with a distance of 0 to
+    /// every node as if there was an extra node with an edge to every other
+    /// node. Every edge is relaxed `V` times, and the node that each
+    /// distance came from (its predecessor) is recorded. Without a negative
+    /// cycle, the distances stop changing after `V - 1` passes, so a node
+    /// which is still relaxed in the last pass can be reached from a
+    /// negative cycle. Following the predecessors `V` times from that node
+    /// is guaranteed to land on the cycle, which is then read off by
+    /// following the predecessors until they come back around. This takes
+    /// O(VE) time.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     matrix.push(Edge::new('a', 'b', 1, EdgeKind::ToRight)).unwrap();
+    ///     matrix.push(Edge::new('b', 'c', -1, EdgeKind::ToRight)).unwrap();
+    ///     matrix.push(Edge::new('c', 'a', 1, EdgeKind::ToRight)).unwrap();
+    ///     assert_eq!(matrix.find_negative_cycle(), Ok(None));
+    ///     matrix.push(Edge::new('c', 'b', -1, EdgeKind::ToRight)).unwrap();
+    ///     let cycle = matrix.find_negative_cycle().unwrap().unwrap();
+    ///     assert!(cycle == vec!['b', 'c'] || cycle == vec!['c', 'b']);
+    /// ```
+    pub fn find_negative_cycle(&self) -> AgcResult<Option<Vec<K>>>
+    where
+        V: Zero
+    {
+        let edges = self.edges().collect::<Vec<Edge<K, V>>>();
+        let nodes = self.all_nodes();
+        let mut distances = nodes
+            .iter()
+            .map(|node| (*node, V::zero()))
+            .collect::<HashMap<&K, V>>();
+        let mut predecessors: HashMap<&K, &K> = HashMap::new();
+        let mut relaxed = None;
+        for _ in 0..nodes.len() {
+            relaxed = relax_edges(
+                &edges,
+                &mut distances,
+                Some(&mut predecessors)
+            );
+            if relaxed.is_none() {
+                return Ok(None);
+            }
+        }
+        let mut node = match relaxed {
+            Some(node) => node,
+            None => return Ok(None)
+        };
+        for _ in 0..nodes.len() {
+            node = predecessors[node];
+        }
+        let mut cycle = vec![node.clone()];
+        let mut previous = predecessors[node];
+        while previous != node {
+            cycle.push(previous.clone());
+            previous = predecessors[previous];
+        }
+        // The predecessors go against the direction of the edges
+        cycle.reverse();
+        Ok(Some(cycle))
+    }
+}
\ No newline at end of file
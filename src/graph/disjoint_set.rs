@@ -0,0 +1,162 @@
+//! A structure which keeps track of items split into groups that do not
+//! overlap.
+
+use std::collections::HashMap;
+use crate::{
+    error::{AgcError, AgcErrorKind, AgcResult},
+    traits::AgcHashable
+};
+
+/// A disjoint-set (or union-find) structure, which splits items into sets
+/// where every item is in exactly 1 set. Each set starts off with 1 item in
+/// it, and 2 sets can be merged into 1 with `union`. `find` gives the item
+/// chosen to represent the set that an item is in, so 2 items are in the
+/// same set if they have the same representative.
+/// 
+/// Every set is stored as a tree, where each item points towards the root of
+/// its tree, which is the representative. `find` points every item it
+/// passes straight at the root (path compression), and `union` puts the
+/// shorter tree under the taller one (union by rank), so the trees stay
+/// flat. Together, these make both operations take nearly O(1) time on
+/// average.
+/// 
+/// # Example
+/// ```
+///     use algocol::graph::DisjointSet;
+///     let mut sets = DisjointSet::new();
+///     for item in ['a', 'b', 'c'].iter() {
+///         sets.make_set(*item);
+///     }
+///     sets.union(&'a', &'b').unwrap();
+///     assert!(sets.connected(&'a', &'b'));
+///     assert!(!sets.connected(&'a', &'c'));
+///     assert_eq!(sets.set_count(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DisjointSet<K: AgcHashable + Clone> {
+    indices: HashMap<K, usize>,
+    items: Vec<K>,
+    parents: Vec<usize>,
+    ranks: Vec<usize>,
+    set_count: usize
+}
+
+impl<K: AgcHashable + Clone> DisjointSet<K> {
+    /// Create a new `DisjointSet` with no items in it.
+    pub fn new() -> Self {
+        Self {
+            indices: HashMap::new(),
+            items: Vec::new(),
+            parents: Vec::new(),
+            ranks: Vec::new(),
+            set_count: 0
+        }
+    }
+
+    /// The number of items in every set.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether there are no items in any set.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// The number of different sets.
+    pub fn set_count(&self) -> usize {
+        self.set_count
+    }
+
+    /// Check if `item` has been added with `make_set`.
+    pub fn contains(&self, item: &K) -> bool {
+        self.indices.contains_key(item)
+    }
+
+    /// Add `item` in a new set of its own. If `item` has already been added,
+    /// nothing changes and `false` is returned.
+    pub fn make_set(&mut self, item: K) -> bool {
+        if self.contains(&item) {
+            return false;
+        }
+        let index = self.items.len();
+        self.indices.insert(item.clone(), index);
+        self.items.push(item);
+        self.parents.push(index);
+        self.ranks.push(0);
+        self.set_count += 1;
+        true
+    }
+
+    /// Find the representative of the set that `item` is in, or `None` if
+    /// `item` has not been added.
+    pub fn find(&mut self, item: &K) -> Option<&K> {
+        let index = *self.indices.get(item)?;
+        let root = self.find_root(index);
+        Some(&self.items[root])
+    }
+
+    /// Merge the sets that `a` and `b` are in. `true` is returned if they
+    /// were in different sets and `false` if they were already in the same
+    /// set. An `AgcError` of kind `NotFound` is returned if either item has
+    /// not been added.
+    pub fn union(&mut self, a: &K, b: &K) -> AgcResult<bool> {
+        let (a, b) = match (self.indices.get(a), self.indices.get(b)) {
+            (Some(a), Some(b)) => (*a, *b),
+            _ => return Err(AgcError::new(
+                AgcErrorKind::NotFound,
+                "item could not be found in the disjoint set."
+            ))
+        };
+        let (a, b) = (self.find_root(a), self.find_root(b));
+        if a == b {
+            return Ok(false);
+        }
+        // The root of the shorter tree goes under the root of the taller one
+        let (shorter, taller) = if self.ranks[a] < self.ranks[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parents[shorter] = taller;
+        if self.ranks[shorter] == self.ranks[taller] {
+            self.ranks[taller] += 1;
+        }
+        self.set_count -= 1;
+        Ok(true)
+    }
+
+    /// Check if `a` and `b` are in the same set. `false` is returned if
+    /// either item has not been added.
+    pub fn connected(&mut self, a: &K, b: &K) -> bool {
+        match (self.indices.get(a), self.indices.get(b)) {
+            (Some(a), Some(b)) => {
+                let (a, b) = (*a, *b);
+                self.find_root(a) == self.find_root(b)
+            },
+            _ => false
+        }
+    }
+
+    /// Find the index of the root of the tree that the item at `index` is
+    /// in, pointing every item on the way straight at the root.
+    fn find_root(&mut self, index: usize) -> usize {
+        let mut root = index;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        let mut node = index;
+        while self.parents[node] != root {
+            let next = self.parents[node];
+            self.parents[node] = root;
+            node = next;
+        }
+        root
+    }
+}
+
+impl<K: AgcHashable + Clone> Default for DisjointSet<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
\ No newline at end of file
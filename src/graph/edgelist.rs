@@ -0,0 +1,214 @@
+//! Saving and loading `AdjacencyMatrix`es as plain text, and working with
+//! lists of `Edge`s.
+//! 
+//! The format used here is an edge list, where each line describes one edge
+//! in the form `left right cost kind`, for example:
+//! 
+//! ```text
+//! a b 5 ToRight
+//! b c 2 Bidirectional
+//! d
+//! ```
+//! 
+//! `kind` is written the same way `EdgeKind` is displayed and can be left
+//! out, in which case the edge is assumed to be `ToRight`. A line with only
+//! one node on it registers that node without giving it any edges. Blank
+//! lines are ignored. Since the parts of each line are separated by
+//! whitespace, nodes and costs must not contain any whitespace when they are
+//! displayed.
+
+use std::{
+    collections::HashSet,
+    fmt::{Display, Write},
+    str::FromStr
+};
+use crate::{
+    error::{AgcError, AgcErrorKind, AgcResult},
+    graph::maps::{AdjacencyMatrix, Edge, EdgeKind},
+    structures::heap::{sift_down_by, sift_up_by},
+    traits::{AgcHashable, AgcNumberLike}
+};
+
+impl<K, V> AdjacencyMatrix<K, V>
+where
+    K: AgcHashable + Clone + Display,
+    V: AgcNumberLike + Display
+{
+    /// Write every edge in this `AdjacencyMatrix` as an edge list, one edge
+    /// per line. Nodes without any outgoing edges are written on a line of
+    /// their own so that they are not lost when the edge list is loaded
+    /// again. See the module-level documentation for the format used.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<i32, i32>::new();
+    ///     matrix.push(Edge::new(0, 1, 5, EdgeKind::ToRight)).unwrap();
+    ///     assert_eq!(matrix.to_edge_list_string(), "0 1 5 ToRight\n");
+    /// ```
+    pub fn to_edge_list_string(&self) -> String {
+        let mut output = String::new();
+        for from in self.nodes() {
+            let adjacent = self.get_adjacent(from).unwrap();
+            if adjacent.is_empty() {
+                writeln!(output, "{}", from).unwrap();
+            }
+            for (to, cost) in adjacent.iter() {
+                writeln!(
+                    output,
+                    "{} {} {} {}",
+                    from,
+                    to,
+                    cost,
+                    EdgeKind::ToRight
+                ).unwrap();
+            }
+        }
+        output
+    }
+}
+
+impl<K, V> AdjacencyMatrix<K, V>
+where
+    K: AgcHashable + Clone + FromStr,
+    V: AgcNumberLike + FromStr
+{
+    /// Load an `AdjacencyMatrix` from an edge list. See the module-level
+    /// documentation for the format used.
+    /// 
+    /// If a line has the wrong number of parts, or if one of its parts could
+    /// not be parsed, an `AgcError` of kind `InvalidArgument` is returned,
+    /// saying which line was malformed. Edges which start and end in the same
+    /// node return an error of kind `SameNode`.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::AdjacencyMatrix;
+    ///     let matrix = AdjacencyMatrix::<i32, i32>::from_edge_list_str(
+    ///         "0 1 5 ToRight\n1 2 3 Bidirectional"
+    ///     ).unwrap();
+    ///     assert_eq!(matrix.get_edge(&0, &1), Some(&5));
+    ///     assert_eq!(matrix.get_edge(&2, &1), Some(&3));
+    /// ```
+    pub fn from_edge_list_str(s: &str) -> AgcResult<Self> {
+        let mut matrix = Self::new();
+        for (index, line) in s.lines().enumerate() {
+            let number = index + 1;
+            let parts = line.split_whitespace().collect::<Vec<&str>>();
+            match parts.len() {
+                0 => continue,
+                1 => {
+                    matrix.register_node(&parse_part(parts[0], number)?);
+                },
+                3 | 4 => {
+                    let edge_kind = match parts.get(3) {
+                        Some(kind) => parse_part(kind, number)?,
+                        None => EdgeKind::ToRight
+                    };
+                    matrix.push(Edge::try_new(
+                        parse_part(parts[0], number)?,
+                        parse_part(parts[1], number)?,
+                        parse_part(parts[2], number)?,
+                        edge_kind
+                    )?)?;
+                },
+                count => return Err(AgcError::new(
+                    AgcErrorKind::InvalidArgument,
+                    format!(
+                        "Line {} has {} parts, expected `left right cost \
+                        kind`.",
+                        number,
+                        count
+                    )
+                ))
+            }
+        }
+        Ok(matrix)
+    }
+}
+
+fn parse_part<T: FromStr>(part: &str, number: usize) -> AgcResult<T> {
+    part.parse().map_err(|_| AgcError::new(
+        AgcErrorKind::InvalidArgument,
+        format!("Could not parse {:?} on line {}.", part, number)
+    ))
+}
+
+/// Merge several lists of edges, each sorted by cost in ascending order, into
+/// one list sorted by cost. Edges with the same cost are kept in the order of
+/// the lists they came from, so an edge from `lists[0]` comes before an edge
+/// with the same cost from `lists[1]`.
+/// 
+/// If `dedup` is `true`, only the first of any edges which join the same
+/// nodes in the same direction is kept. Since the merged list is sorted by
+/// cost, this is the cheapest of them. An edge from `a` to `b` is the same as
+/// an edge of kind `ToLeft` from `b` to `a`, and a `Bidirectional` edge
+/// between `a` and `b` is the same as one between `b` and `a`.
+/// 
+/// This is a k-way merge: the next edge from each list is kept in a min-heap,
+/// so the cheapest of them can be taken off the top in O(log k) time. For `n`
+/// edges in `k` lists, this takes O(n log k) time. If a list is not sorted,
+/// the merged list will not be sorted either.
+/// 
+/// # Example
+/// ```
+///     use algocol::graph::{
+///         Edge, EdgeKind, edgelist::merge_sorted_edge_lists
+///     };
+///     let kind = EdgeKind::ToRight;
+///     let first = vec![
+///         Edge::new('a', 'b', 1, kind), Edge::new('b', 'c', 4, kind)
+///     ];
+///     let second = vec![Edge::new('a', 'b', 2, kind)];
+///     let merged = merge_sorted_edge_lists(&[first, second], true);
+///     let costs = merged.iter().map(|edge| edge.cost).collect::<Vec<_>>();
+///     assert_eq!(costs, [1, 4]);
+/// ```
+pub fn merge_sorted_edge_lists<K, V>(
+    lists: &[Vec<Edge<K, V>>],
+    dedup: bool
+) -> Vec<Edge<K, V>>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    // Each entry is the index of a list and the index of the next edge in it
+    let compare = |a: &(usize, usize), b: &(usize, usize)| {
+        lists[a.0][a.1].cost.cmp(&lists[b.0][b.1].cost).then(a.0.cmp(&b.0))
+    };
+    let mut heap: Vec<(usize, usize)> = Vec::with_capacity(lists.len());
+    for (index, list) in lists.iter().enumerate() {
+        if !list.is_empty() {
+            heap.push((index, 0));
+            let last = heap.len() - 1;
+            sift_up_by(&mut heap[..], last, false, compare);
+        }
+    }
+    let total = lists.iter().map(|list| list.len()).sum();
+    let mut merged = Vec::with_capacity(total);
+    let mut seen: HashSet<(K, K, bool)> = HashSet::new();
+    while let Some(&(list, position)) = heap.first() {
+        let edge = &lists[list][position];
+        if position + 1 < lists[list].len() {
+            heap[0] = (list, position + 1);
+        } else {
+            heap.swap_remove(0);
+        }
+        sift_down_by(&mut heap[..], 0, false, compare);
+        if dedup {
+            let (left, right) = (edge.left.clone(), edge.right.clone());
+            let key = match edge.edge_kind {
+                EdgeKind::ToRight => (left, right, false),
+                EdgeKind::ToLeft => (right, left, false),
+                EdgeKind::Bidirectional => (left, right, true)
+            };
+            let flipped = || (key.1.clone(), key.0.clone(), true);
+            if seen.contains(&key) || (key.2 && seen.contains(&flipped())) {
+                continue;
+            }
+            seen.insert(key);
+        }
+        merged.push(edge.clone());
+    }
+    merged
+}
\ No newline at end of file
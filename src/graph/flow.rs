@@ -0,0 +1,135 @@
+//! Network flow algorithms on `AdjacencyMatrix`.
+
+use std::collections::{HashMap, VecDeque};
+use num_traits::Zero;
+use crate::{
+    error::{AgcError, AgcErrorKind, AgcResult},
+    graph::maps::AdjacencyMatrix,
+    traits::{AgcHashable, AgcNumberLike}
+};
+
+impl<K, V> AdjacencyMatrix<K, V>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike + Zero
+{
+    /// Find the maximum flow that can be sent from `source` to `sink`, using
+    /// the cost of each edge as its capacity.
+    /// 
+    /// This uses the Edmonds-Karp algorithm. The algorithm keeps track of the
+    /// residual capacity of each edge, which is how much more flow can be
+    /// sent through it. Sending flow from `a` to `b` also adds to the
+    /// residual capacity from `b` to `a`, since that flow could be cancelled
+    /// later. The algorithm repeatedly finds the shortest path (by number of
+    /// edges) from `source` to `sink` with breadth-first search where every
+    /// edge still has some residual capacity, and sends as much flow along
+    /// it as the smallest residual capacity on the path allows. Once no such
+    /// path is left, the total flow sent is the maximum flow. This takes
+    /// O(VE^2) time.
+    /// 
+    /// The residual capacities are kept in a copy of the graph, so `self` is
+    /// not changed. An `AgcError` of kind `NotFound` is returned if `source`
+    /// or `sink` are not in the graph, `SameNode` if they are the same node
+    /// and `InvalidArgument` if any capacity is negative.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     for (left, right, cost) in [
+    ///         ('s', 'a', 3), ('s', 'b', 2), ('a', 'b', 5), ('a', 't', 2),
+    ///         ('b', 't', 3)
+    ///     ].iter() {
+    ///         matrix.push(Edge::new(*left, *right, *cost, EdgeKind::ToRight))
+    ///             .unwrap();
+    ///     }
+    ///     assert_eq!(matrix.max_flow(&'s', &'t'), Ok(5));
+    /// ```
+    pub fn max_flow(&self, source: &K, sink: &K) -> AgcResult<V> {
+        if source == sink {
+            return Err(AgcError::new(
+                AgcErrorKind::SameNode,
+                "source cannot be the same as sink."
+            ));
+        }
+        let nodes = self.all_nodes();
+        for node in [source, sink].iter() {
+            if !nodes.contains(node) {
+                return Err(AgcError::new(
+                    AgcErrorKind::NotFound,
+                    "source and sink must be nodes in the graph."
+                ));
+            }
+        }
+        let mut residual: HashMap<K, HashMap<K, V>> = HashMap::new();
+        for from in self.nodes() {
+            for (to, capacity) in self.get_adjacent(from).unwrap().iter() {
+                if *capacity < V::zero() {
+                    return Err(AgcError::new(
+                        AgcErrorKind::InvalidArgument,
+                        "Capacities cannot be negative."
+                    ));
+                }
+                residual.entry(from.clone()).or_default()
+                    .insert(to.clone(), *capacity);
+                // Make sure that the reverse edge exists so that flow can be
+                // cancelled.
+                residual.entry(to.clone()).or_default()
+                    .entry(from.clone()).or_insert_with(V::zero);
+            }
+        }
+        let mut flow = V::zero();
+        while let Some(path) = augmenting_path(&residual, source, sink) {
+            let bottleneck = path.windows(2)
+                .map(|pair| residual[&pair[0]][&pair[1]])
+                .min()
+                .unwrap();
+            for pair in path.windows(2) {
+                let (from, to) = (&pair[0], &pair[1]);
+                *residual.get_mut(from).unwrap().get_mut(to).unwrap() -=
+                    bottleneck;
+                *residual.get_mut(to).unwrap().get_mut(from).unwrap() +=
+                    bottleneck;
+            }
+            flow += bottleneck;
+        }
+        Ok(flow)
+    }
+}
+
+/// Find the shortest path from `source` to `sink` in which every edge has
+/// some residual capacity left, returning the nodes along the path.
+fn augmenting_path<K, V>(
+    residual: &HashMap<K, HashMap<K, V>>,
+    source: &K,
+    sink: &K
+) -> Option<Vec<K>>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike + Zero
+{
+    let mut parents = HashMap::new();
+    let mut queue = VecDeque::new();
+    queue.push_back(source);
+    while let Some(node) = queue.pop_front() {
+        for (next, capacity) in residual.get(node)?.iter() {
+            if *capacity > V::zero()
+            && next != source
+            && !parents.contains_key(next) {
+                parents.insert(next, node);
+                if next == sink {
+                    let mut path = vec![sink.clone()];
+                    let mut current = sink;
+                    while let Some(parent) = parents.get(current) {
+                        path.push((*parent).clone());
+                        current = parent;
+                    }
+                    path.reverse();
+                    return Some(path);
+                }
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
\ No newline at end of file
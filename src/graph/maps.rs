@@ -1,7 +1,8 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     convert::AsRef,
-    fmt
+    fmt,
+    str::FromStr
 };
 use crate::{
     error::{AgcResult, AgcError, AgcErrorKind},
@@ -26,10 +27,29 @@ impl fmt::Display for EdgeKind {
     }
 }
 
+impl FromStr for EdgeKind {
+    type Err = AgcError;
+
+    /// Parse an `EdgeKind` from the same text that its `Display`
+    /// implementation produces (`ToRight`, `ToLeft` or `Bidirectional`).
+    fn from_str(s: &str) -> AgcResult<Self> {
+        use EdgeKind::*;
+        match s {
+            "ToRight" => Ok(ToRight),
+            "ToLeft" => Ok(ToLeft),
+            "Bidirectional" => Ok(Bidirectional),
+            _ => Err(AgcError::new(
+                AgcErrorKind::InvalidArgument,
+                format!("{:?} is not a kind of edge.", s)
+            ))
+        }
+    }
+}
+
 /// An edge in a graph, with a `left` node, a `right` node and a cost to
 /// traverse the 2 nodes in a certain direction specified by
 /// `edge_kind: EdgeKind
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Edge<N, C>
 where
     N: AgcHashable + Clone,
@@ -50,8 +70,8 @@ where
     /// 
     /// # Example
     /// ```
-    ///     use algocol::graph::Edge;
-    ///     Edge::new(0, 1, 5);
+    ///     use algocol::graph::{Edge, EdgeKind};
+    ///     Edge::new(0, 1, 5, EdgeKind::ToRight);
     /// ```
     /// 
     /// # Panics
@@ -59,9 +79,9 @@ where
     /// an edge that starts and end in the same node does not exist.
     /// 
     /// ```ignore
-    ///     use algocol::graph::Edge;
-    ///     Edge::new(0, 1, 5); // Does not panic
-    ///     Edge::new(0, 0, 5); // Panics!
+    ///     use algocol::graph::{Edge, EdgeKind};
+    ///     Edge::new(0, 1, 5, EdgeKind::ToRight); // Does not panic
+    ///     Edge::new(0, 0, 5, EdgeKind::ToRight); // Panics!
     /// ```
     pub fn new(left: N, right: N, cost: C, edge_kind: EdgeKind) -> Self {
         Self::try_new(left, right, cost, edge_kind).unwrap()
@@ -74,9 +94,10 @@ where
     /// 
     /// # Example
     /// ```
-    ///     use algocol::graph::Edge;
-    ///     assert!(matches!(Edge::try_new(0, 1, 5), Ok(_)));
-    ///     assert!(matches!(Edge::try_new(0, 0, 5), Err(_)));
+    ///     use algocol::graph::{Edge, EdgeKind};
+    ///     let kind = EdgeKind::ToRight;
+    ///     assert!(matches!(Edge::try_new(0, 1, 5, kind), Ok(_)));
+    ///     assert!(matches!(Edge::try_new(0, 0, 5, kind), Err(_)));
     /// ```
     pub fn try_new(
         left: N,
@@ -107,16 +128,16 @@ where
 /// ```
 ///     use algocol::graph::AdjacencyMatrix;
 ///     let mut matrix = AdjacencyMatrix::<i32, i32>::new();
-///     if let Some(a) = matrix.get_adjacent(&0) {
-///         for (b, cost) in a.iter() {
-///             println!("Cost to get from {} to {}: {}", a, b, cost);
+///     if let Some(adjacent) = matrix.get_adjacent(&0) {
+///         for (b, cost) in adjacent.iter() {
+///             println!("Cost to get from {} to {}: {}", 0, b, cost);
 ///         }
 ///     }
 /// ```
 /// 
 /// If you need to access the cost of an edge more directly, you can use
 /// `self.get_edge` or `self.get_mut_edge`.
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct AdjacencyMatrix<K, V>
 where
     K: AgcHashable + Clone,
@@ -147,6 +168,64 @@ where
         matrix
     }
 
+    /// Iterate over every node registered in this `AdjacencyMatrix`.
+    pub fn nodes(&self) -> impl Iterator<Item = &K> {
+        self.matrix.keys()
+    }
+
+    /// Get every node in this `AdjacencyMatrix`. Unlike `nodes`, this
+    /// includes nodes which have not been registered because they have only
+    /// ever been the destination of an edge.
+    pub fn all_nodes(&self) -> HashSet<&K> {
+        let mut nodes = self.matrix.keys().collect::<HashSet<&K>>();
+        for adjacent in self.matrix.values() {
+            nodes.extend(adjacent.keys());
+        }
+        nodes
+    }
+
+    /// Iterate over every edge in this `AdjacencyMatrix`, in no particular
+    /// order. Each edge is returned as an `Edge` of kind `ToRight` going from
+    /// the node it starts at to the node it ends at, so a `Bidirectional`
+    /// edge that was pushed in comes out as 2 edges, one in each direction.
+    pub fn edges(&self) -> impl Iterator<Item = Edge<K, V>> + '_ {
+        self.matrix.iter().flat_map(|(from, adjacent)| {
+            adjacent.iter().map(move |(to, cost)| {
+                Edge::new(from.clone(), to.clone(), *cost, EdgeKind::ToRight)
+            })
+        })
+    }
+
+    /// Return an `AgcError` of kind `NotFound` if any node in `nodes` is not
+    /// in this `AdjacencyMatrix`.
+    pub(crate) fn check_nodes_exist(&self, nodes: &[&K]) -> AgcResult<()> {
+        let all_nodes = self.all_nodes();
+        if nodes.iter().all(|node| all_nodes.contains(node)) {
+            Ok(())
+        } else {
+            Err(AgcError::new(
+                AgcErrorKind::NotFound,
+                "node could not be found in the graph."
+            ))
+        }
+    }
+
+    /// Map every node to the set of nodes it shares an edge with, ignoring
+    /// the direction of the edges. Every node in `all_nodes` is a key.
+    pub(crate) fn undirected_adjacency(&self) -> HashMap<&K, HashSet<&K>> {
+        let mut adjacency = self.all_nodes()
+            .into_iter()
+            .map(|node| (node, HashSet::new()))
+            .collect::<HashMap<&K, HashSet<&K>>>();
+        for (from, adjacent) in self.matrix.iter() {
+            for to in adjacent.keys() {
+                adjacency.get_mut(from).unwrap().insert(to);
+                adjacency.get_mut(to).unwrap().insert(from);
+            }
+        }
+        adjacency
+    }
+
     /// Get the `HashMap` pointing to the adjacent nodes of `node` with their
     /// cost.
     pub fn get_adjacent(&self, node: &K) -> Option<&HashMap<K, V>> {
@@ -172,7 +251,7 @@ where
 
     /// Check if a node is a key in `self.matrix`.
     pub fn registered(&self, node: &K) -> bool {
-        self.matrix.contains_key(&node)
+        self.matrix.contains_key(node)
     }
 
     /// Add a node as a key to `self.matrix` if it has not already been added
@@ -216,3 +295,139 @@ where
         }
     }
 }
+
+impl<K, V> Default for AdjacencyMatrix<K, V>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A `MultiAdjacencyMatrix` works like an `AdjacencyMatrix`, except that it
+/// keeps every edge pushed into it, even if there is already an edge going
+/// from the same node to the same node. This lets it represent multigraphs,
+/// where 2 nodes can be joined by more than 1 edge (called parallel edges).
+/// 
+/// Each node is mapped to its adjacent nodes, and each adjacent node is
+/// mapped to a `Vec` of the costs of every edge going there, in the order
+/// that they were pushed. The algorithms in this crate are written for
+/// `AdjacencyMatrix`, which only keeps the cheapest of any parallel edges.
+/// Use `to_adjacency_matrix` to get that simple graph when you want to run
+/// them on a multigraph.
+/// 
+/// # Example
+/// ```
+///     use algocol::graph::{Edge, EdgeKind, MultiAdjacencyMatrix};
+///     let mut matrix = MultiAdjacencyMatrix::<i32, i32>::new();
+///     matrix.push(Edge::new(0, 1, 5, EdgeKind::ToRight)).unwrap();
+///     matrix.push(Edge::new(0, 1, 3, EdgeKind::ToRight)).unwrap();
+///     assert_eq!(matrix.get_edges(&0, &1), Some(&[5, 3][..]));
+///     assert_eq!(matrix.to_adjacency_matrix().get_edge(&0, &1), Some(&3));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MultiAdjacencyMatrix<K, V>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    matrix: HashMap<K, HashMap<K, Vec<V>>>
+}
+
+impl<K, V> MultiAdjacencyMatrix<K, V>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    /// Create a new `MultiAdjacencyMatrix`.
+    pub fn new() -> Self {
+        Self {matrix: HashMap::new()}
+    }
+
+    /// Iterate over every node registered in this `MultiAdjacencyMatrix`.
+    pub fn nodes(&self) -> impl Iterator<Item = &K> {
+        self.matrix.keys()
+    }
+
+    /// Get the `HashMap` pointing to the adjacent nodes of `node` with the
+    /// costs of every edge going to them.
+    pub fn get_adjacent(&self, node: &K) -> Option<&HashMap<K, Vec<V>>> {
+        self.matrix.get(node)
+    }
+
+    /// Get the costs of every edge going from node `a` (from) to node `b`
+    /// (to), in the order that they were pushed.
+    pub fn get_edges(&self, from: &K, to: &K) -> Option<&[V]> {
+        self.matrix.get(from)?.get(to).map(|costs| &costs[..])
+    }
+
+    /// Check if a node is a key in `self.matrix`.
+    pub fn registered(&self, node: &K) -> bool {
+        self.matrix.contains_key(node)
+    }
+
+    /// Add a node as a key to `self.matrix` if it has not already been added
+    /// and get a mutable reference to the `HashMap` of adjacent nodes to it.
+    pub fn register_node(&mut self, node: &K) -> &mut HashMap<K, Vec<V>> {
+        self.matrix.entry(node.clone()).or_default()
+    }
+
+    fn push_raw(&mut self, from: &K, to: &K, cost: V) -> AgcResult<()> {
+        if from == to {
+            return Err(AgcError::new(
+                AgcErrorKind::SameNode,
+                "from cannot be the same as to"
+            ));
+        }
+        self.register_node(from).entry(to.clone()).or_default().push(cost);
+        Ok(())
+    }
+
+    /// Push an edge into the `MultiAdjacencyMatrix`, keeping any edges which
+    /// already join the same nodes.
+    /// 
+    /// This method returns an `Err` if `edge.left == edge.right`.
+    pub fn push(&mut self, edge: Edge<K, V>) -> AgcResult<()> {
+        use EdgeKind::*;
+        match edge.edge_kind {
+            ToRight => self.push_raw(&edge.left, &edge.right, edge.cost),
+            ToLeft => self.push_raw(&edge.right, &edge.left, edge.cost),
+            Bidirectional => {
+                self.push_raw(&edge.left, &edge.right, edge.cost)?;
+                self.push_raw(&edge.right, &edge.left, edge.cost)
+            }
+        }
+    }
+
+    /// Convert this multigraph into a simple graph, keeping only the
+    /// cheapest edge out of each group of parallel edges.
+    pub fn to_adjacency_matrix(&self) -> AdjacencyMatrix<K, V> {
+        let mut simple = AdjacencyMatrix::new();
+        for (from, adjacent) in self.matrix.iter() {
+            simple.register_node(from);
+            for (to, costs) in adjacent.iter() {
+                for cost in costs.iter() {
+                    simple.push(Edge::new(
+                        from.clone(),
+                        to.clone(),
+                        *cost,
+                        EdgeKind::ToRight
+                    )).unwrap();
+                }
+            }
+        }
+        simple
+    }
+}
+
+impl<K, V> Default for MultiAdjacencyMatrix<K, V>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1 +1,224 @@
-pub mod maps;
\ No newline at end of file
+//! Structures for representing graphs, as well as algorithms which operate
+//! on them.
+
+pub mod coloring;
+pub mod cuts;
+pub mod cycles;
+pub mod disjoint_set;
+pub mod edgelist;
+pub mod flow;
+pub mod maps;
+pub mod ordering;
+pub mod paths;
+pub mod properties;
+pub mod reachability;
+pub mod spanning;
+pub mod transform;
+pub mod traversal;
+
+use std::collections::HashMap;
+use num_traits::Zero;
+use crate::{
+    error::AgcResult,
+    traits::{AgcHashable, AgcNumberLike}
+};
+
+pub use self::{
+    disjoint_set::DisjointSet,
+    maps::{AdjacencyMatrix, Edge, EdgeKind, MultiAdjacencyMatrix},
+    paths::{CostedPath, GraphMetrics}
+};
+
+/// Find the cheapest cost of getting from `source` to every node that can be
+/// reached from it with Dijkstra's algorithm. This is the same as
+/// `AdjacencyMatrix::dijkstra`, which describes the errors returned.
+pub fn dijkstra<K, V>(
+    matrix: &AdjacencyMatrix<K, V>,
+    source: &K
+) -> AgcResult<HashMap<K, V>>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike + Zero
+{
+    matrix.dijkstra(source)
+}
+
+/// Find the cheapest path from `source` to `target` with Dijkstra's
+/// algorithm, returning its cost and the nodes on it. This is the same as
+/// `AdjacencyMatrix::dijkstra_path`.
+pub fn dijkstra_path<K, V>(
+    matrix: &AdjacencyMatrix<K, V>,
+    source: &K,
+    target: &K
+) -> AgcResult<Option<CostedPath<K, V>>>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike + Zero
+{
+    matrix.dijkstra_path(source, target)
+}
+
+/// List the nodes that can be reached from `start` in the order that a
+/// breadth-first search visits them. This is the same as
+/// `AdjacencyMatrix::bfs`.
+pub fn bfs<K, V>(
+    matrix: &AdjacencyMatrix<K, V>,
+    start: &K
+) -> AgcResult<Vec<K>>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    matrix.bfs(start)
+}
+
+/// List the nodes that can be reached from `start` in the order that a
+/// depth-first search with an explicit stack visits them. This is the same as
+/// `AdjacencyMatrix::dfs`.
+pub fn dfs<K, V>(
+    matrix: &AdjacencyMatrix<K, V>,
+    start: &K
+) -> AgcResult<Vec<K>>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    matrix.dfs(start)
+}
+
+/// List the nodes that can be reached from `start` in the order that a
+/// recursive depth-first search visits them. This is the same as
+/// `AdjacencyMatrix::dfs_recursive`.
+pub fn dfs_recursive<K, V>(
+    matrix: &AdjacencyMatrix<K, V>,
+    start: &K
+) -> AgcResult<Vec<K>>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    matrix.dfs_recursive(start)
+}
+
+/// Put the nodes of `matrix` in an order where an edge from `a` to `b` means
+/// that `a` comes before `b`. This is the same as
+/// `AdjacencyMatrix::topological_sort`, and returns an `AgcError` of kind
+/// `CyclicGraph` if there is a cycle.
+pub fn topological_sort<K, V>(
+    matrix: &AdjacencyMatrix<K, V>
+) -> AgcResult<Vec<K>>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    matrix.topological_sort()
+}
+
+/// Find the cheapest cost of getting from `source` to every node that can be
+/// reached from it with the Bellman-Ford algorithm, which allows edges with
+/// negative costs. This is the same as `AdjacencyMatrix::bellman_ford`, which
+/// describes the errors returned.
+pub fn bellman_ford<K, V>(
+    matrix: &AdjacencyMatrix<K, V>,
+    source: &K
+) -> AgcResult<HashMap<K, V>>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike + Zero
+{
+    matrix.bellman_ford(source)
+}
+
+/// Find the cheapest path from `start` to `goal` with A* search, guided by
+/// `heuristic`, returning its cost and the nodes on it. This is the same as
+/// `AdjacencyMatrix::astar`, which describes what makes a heuristic safe to
+/// use.
+pub fn astar<K, V, H>(
+    matrix: &AdjacencyMatrix<K, V>,
+    start: &K,
+    goal: &K,
+    heuristic: H
+) -> AgcResult<Option<CostedPath<K, V>>>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike + Zero,
+    H: Fn(&K) -> V
+{
+    matrix.astar(start, goal, heuristic)
+}
+
+/// Find a minimum spanning tree of `matrix` with Prim's algorithm, growing
+/// it out from `start`. This is the same as `AdjacencyMatrix::prim_mst`,
+/// which describes the errors returned.
+pub fn prim_mst<K, V>(
+    matrix: &AdjacencyMatrix<K, V>,
+    start: &K
+) -> AgcResult<Vec<Edge<K, V>>>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    matrix.prim_mst(start)
+}
+
+/// Find a minimum spanning tree of `matrix` with Kruskal's algorithm. This is
+/// the same as `AdjacencyMatrix::kruskal_mst`, which describes the errors
+/// returned.
+pub fn kruskal_mst<K, V>(
+    matrix: &AdjacencyMatrix<K, V>
+) -> AgcResult<Vec<Edge<K, V>>>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    matrix.kruskal_mst()
+}
+
+/// Split the nodes of `matrix` into its connected components, ignoring the
+/// direction of the edges. This is the same as
+/// `AdjacencyMatrix::connected_components`, and never returns an error.
+pub fn connected_components<K, V>(
+    matrix: &AdjacencyMatrix<K, V>
+) -> AgcResult<Vec<Vec<K>>>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    Ok(matrix.connected_components())
+}
+
+/// Count the connected components of `matrix`, ignoring the direction of the
+/// edges. This is the same as `AdjacencyMatrix::component_count`, and never
+/// returns an error.
+pub fn component_count<K, V>(
+    matrix: &AdjacencyMatrix<K, V>
+) -> AgcResult<usize>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    Ok(matrix.component_count())
+}
+
+/// Check if `matrix` has a cycle, following the direction of the edges. This
+/// is the same as `AdjacencyMatrix::has_cycle_directed`.
+pub fn has_cycle_directed<K, V>(matrix: &AdjacencyMatrix<K, V>) -> bool
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    matrix.has_cycle_directed()
+}
+
+/// Find a cycle in `matrix`, following the direction of the edges, returning
+/// its nodes in the order that the edges go. This is the same as
+/// `AdjacencyMatrix::find_cycle_directed`.
+pub fn find_cycle_directed<K, V>(
+    matrix: &AdjacencyMatrix<K, V>
+) -> Option<Vec<K>>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    matrix.find_cycle_directed()
+}
\ No newline at end of file
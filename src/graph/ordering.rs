@@ -0,0 +1,170 @@
+//! Functions which put the nodes of a graph in order.
+
+use std::collections::{HashMap, HashSet};
+use crate::{
+    error::{AgcError, AgcErrorKind, AgcResult},
+    graph::maps::AdjacencyMatrix,
+    traits::{AgcHashable, AgcNumberLike}
+};
+
+impl<K, V> AdjacencyMatrix<K, V>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    /// Group the nodes of this graph into levels, where an edge from `a` to
+    /// `b` means that `a` has to come before `b`, such as a task that `b`
+    /// depends on. The first level has every node with no edges going into
+    /// it, and each level after that has every node whose incoming edges all
+    /// come from earlier levels. The nodes in each level do not depend on
+    /// each other, so they could be processed at the same time. The nodes in
+    /// a level are in no particular order.
+    /// 
+    /// This is a topological sort which peels off every node with no
+    /// incoming edges at once (Kahn's algorithm, one level at a time), and
+    /// takes O(V + E) time. If some nodes are never peeled off, they must be
+    /// on or after a cycle, so an `AgcError` of kind `CyclicGraph` is
+    /// returned as there is no valid order.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<&str, i32>::new();
+    ///     let edges = [("get", "build"), ("build", "test"), ("build", "doc")];
+    ///     for (left, right) in edges.iter() {
+    ///         matrix.push(Edge::new(*left, *right, 1, EdgeKind::ToRight))
+    ///             .unwrap();
+    ///     }
+    ///     let mut levels = matrix.topological_levels().unwrap();
+    ///     levels[2].sort();
+    ///     assert_eq!(levels[0], vec!["get"]);
+    ///     assert_eq!(levels[1], vec!["build"]);
+    ///     assert_eq!(levels[2], vec!["doc", "test"]);
+    /// ```
+    pub fn topological_levels(&self) -> AgcResult<Vec<Vec<K>>> {
+        let mut in_degrees = self.all_nodes()
+            .into_iter()
+            .map(|node| (node, 0))
+            .collect::<HashMap<&K, usize>>();
+        for from in self.nodes() {
+            for to in self.get_adjacent(from).unwrap().keys() {
+                *in_degrees.get_mut(to).unwrap() += 1;
+            }
+        }
+        let mut level = in_degrees
+            .iter()
+            .filter(|(_, in_degree)| **in_degree == 0)
+            .map(|(node, _)| *node)
+            .collect::<Vec<&K>>();
+        let mut levels = Vec::new();
+        let mut placed = 0;
+        while !level.is_empty() {
+            let mut next_level = Vec::new();
+            for node in level.iter() {
+                let adjacent = self.get_adjacent(node).into_iter().flatten();
+                for (to, _) in adjacent {
+                    let in_degree = in_degrees.get_mut(to).unwrap();
+                    *in_degree -= 1;
+                    if *in_degree == 0 {
+                        next_level.push(to);
+                    }
+                }
+            }
+            placed += level.len();
+            levels.push(level.into_iter().cloned().collect());
+            level = next_level;
+        }
+        if placed != in_degrees.len() {
+            return Err(AgcError::new(
+                AgcErrorKind::CyclicGraph,
+                "a graph with a cycle cannot be sorted topologically."
+            ));
+        }
+        Ok(levels)
+    }
+
+    /// Put the nodes of this graph in an order where an edge from `a` to `b`
+    /// means that `a` comes before `b`. If there are several valid orders,
+    /// any of them may be returned. An `AgcError` of kind `CyclicGraph` is
+    /// returned if the graph has a cycle, as there is no valid order. This
+    /// reuses the `CyclicGraph` kind rather than adding a separate `Cyclic`
+    /// kind, so every algorithm here which rejects cycles reports them the
+    /// same way.
+    /// 
+    /// This is Kahn's algorithm: a node is placed once every node with an
+    /// edge going into it has been placed, starting from the nodes with no
+    /// incoming edges. It takes O(V + E) time, and is done by joining the
+    /// levels from `topological_levels` together. Edges follow the direction
+    /// they were stored in by `push`, so a `ToLeft` edge from `a` to `b` puts
+    /// `b` before `a`, and a `Bidirectional` edge is a cycle between its 2
+    /// nodes.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     matrix.push(Edge::new('a', 'b', 1, EdgeKind::ToRight)).unwrap();
+    ///     matrix.push(Edge::new('c', 'b', 1, EdgeKind::ToLeft)).unwrap();
+    ///     let order = matrix.topological_sort().unwrap();
+    ///     assert_eq!(order, vec!['a', 'b', 'c']);
+    ///     matrix.push(Edge::new('c', 'a', 1, EdgeKind::ToRight)).unwrap();
+    ///     assert!(matrix.topological_sort().is_err());
+    /// ```
+    pub fn topological_sort(&self) -> AgcResult<Vec<K>> {
+        Ok(self.topological_levels()?.into_iter().flatten().collect())
+    }
+
+    /// Put the nodes of this graph in smallest-last order, ignoring the
+    /// direction of the edges. The node with the fewest neighbours is
+    /// removed from the graph, then the node with the fewest neighbours
+    /// among the nodes that are left, and so on, until every node has been
+    /// removed. The nodes are returned in the reverse of the order they were
+    /// removed in, so the last node removed comes first. Nodes with the same
+    /// number of neighbours are removed in no particular order.
+    /// 
+    /// When `greedy_coloring` colours the nodes in this order, each node has
+    /// few neighbours which have already been coloured, which tends to need
+    /// fewer colours than an arbitrary order. If every subgraph has a node
+    /// with at most `d` neighbours (like a tree, where `d` is 1), at most
+    /// `d + 1` colours are used. This takes O(V^2 + E) time.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     let edges = [('a', 'b'), ('b', 'c'), ('c', 'd'), ('b', 'e')];
+    ///     for (left, right) in edges.iter() {
+    ///         matrix.push(Edge::new(*left, *right, 1, EdgeKind::ToRight))
+    ///             .unwrap();
+    ///     }
+    ///     let ordering = matrix.smallest_last_ordering();
+    ///     assert_eq!(ordering.len(), 5);
+    ///     // A tree only needs 2 colours
+    ///     let colours = matrix.greedy_coloring(&ordering).unwrap();
+    ///     assert!(colours.values().all(|colour| *colour < 2));
+    /// ```
+    pub fn smallest_last_ordering(&self) -> Vec<K> {
+        let adjacency = self.undirected_adjacency();
+        let mut degrees = adjacency
+            .iter()
+            .map(|(node, neighbours)| (*node, neighbours.len()))
+            .collect::<HashMap<&K, usize>>();
+        let mut removed: HashSet<&K> = HashSet::new();
+        let mut ordering = Vec::with_capacity(degrees.len());
+        while let Some((&node, _)) = degrees
+            .iter()
+            .filter(|(node, _)| !removed.contains(*node))
+            .min_by_key(|(_, degree)| **degree)
+        {
+            removed.insert(node);
+            for neighbour in adjacency[node].iter() {
+                if !removed.contains(neighbour) {
+                    *degrees.get_mut(neighbour).unwrap() -= 1;
+                }
+            }
+            ordering.push(node.clone());
+        }
+        ordering.reverse();
+        ordering
+    }
+}
\ No newline at end of file
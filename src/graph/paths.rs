@@ -0,0 +1,719 @@
+//! Functions which find or count paths between nodes.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use num_traits::Zero;
+use crate::{
+    error::{AgcError, AgcErrorKind, AgcResult},
+    graph::{cycles::relax_edges, maps::{AdjacencyMatrix, Edge}},
+    sort::mergesort::mergesort_by,
+    structures::heap::{sift_down_by, sift_up_by},
+    traits::{AgcHashable, AgcNumberLike}
+};
+
+/// The eccentricity of every node in a graph, along with the measurements
+/// that come from them. This is returned by `AdjacencyMatrix::graph_metrics`.
+#[derive(Debug, Clone)]
+pub struct GraphMetrics<K, V>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    eccentricities: HashMap<K, V>,
+    radius: V,
+    diameter: V,
+    center: Vec<K>,
+    periphery: Vec<K>
+}
+
+/// A path through a graph, made up of its total cost and the nodes on it in
+/// order.
+pub type CostedPath<K, V> = (V, Vec<K>);
+
+/// The result of a search for the cheapest paths from a node, which holds
+/// the final cost of every node the search got to, along with the node each
+/// of them was reached from and the number of nodes it expanded.
+struct CheapestPaths<'a, K, V> {
+    costs: HashMap<&'a K, V>,
+    predecessors: HashMap<&'a K, &'a K>,
+    expanded: usize
+}
+
+impl<'a, K, V> CheapestPaths<'a, K, V>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    /// Get the cost of the cheapest path to `target` and the nodes on it, by
+    /// following the predecessors back from `target`.
+    fn path_to(&self, target: &K) -> Option<CostedPath<K, V>> {
+        let cost = *self.costs.get(target)?;
+        let mut path = vec![target.clone()];
+        let mut node = target;
+        while let Some(previous) = self.predecessors.get(node) {
+            path.push((*previous).clone());
+            node = previous;
+        }
+        path.reverse();
+        Some((cost, path))
+    }
+}
+
+impl<K, V> GraphMetrics<K, V>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    /// The eccentricity of `node`, which is the cost of the shortest path
+    /// from it to the node furthest away from it, or `None` if `node` is not
+    /// in the graph.
+    pub fn eccentricity(&self, node: &K) -> Option<V> {
+        self.eccentricities.get(node).copied()
+    }
+
+    /// Map every node to its eccentricity.
+    pub fn eccentricities(&self) -> &HashMap<K, V> {
+        &self.eccentricities
+    }
+
+    /// The smallest eccentricity of any node.
+    pub fn radius(&self) -> V {
+        self.radius
+    }
+
+    /// The largest eccentricity of any node, which is the cost of the
+    /// longest shortest path in the graph.
+    pub fn diameter(&self) -> V {
+        self.diameter
+    }
+
+    /// The nodes whose eccentricity is the radius, in no particular order.
+    pub fn center(&self) -> &[K] {
+        &self.center
+    }
+
+    /// The nodes whose eccentricity is the diameter, in no particular order.
+    pub fn periphery(&self) -> &[K] {
+        &self.periphery
+    }
+}
+
+impl<K, V> AdjacencyMatrix<K, V>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    /// Count the number of different shortest paths from `from` to `to`,
+    /// where the length of a path is the number of edges in it (the costs of
+    /// the edges are ignored). If `to` cannot be reached from `from`, 0 is
+    /// returned, and if `from` and `to` are the same node, 1 is returned. An
+    /// `AgcError` of kind `NotFound` is returned if either node is not in
+    /// the graph.
+    /// 
+    /// This uses a breadth-first search from `from`, which visits the nodes
+    /// level by level in order of their distance from `from`. Every shortest
+    /// path to a node must go through a node on the level before it, so the
+    /// number of shortest paths to a node is the sum of the numbers of
+    /// shortest paths to its predecessors on the previous level. This takes
+    /// O(V + E) time. The count stops growing at `u64::MAX` instead of
+    /// overflowing.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     let edges = [('a', 'b'), ('a', 'c'), ('b', 'd'), ('c', 'd')];
+    ///     for (left, right) in edges.iter() {
+    ///         matrix.push(Edge::new(*left, *right, 1, EdgeKind::ToRight))
+    ///             .unwrap();
+    ///     }
+    ///     assert_eq!(matrix.shortest_path_count(&'a', &'d'), Ok(2));
+    ///     assert_eq!(matrix.shortest_path_count(&'d', &'a'), Ok(0));
+    /// ```
+    pub fn shortest_path_count(&self, from: &K, to: &K) -> AgcResult<u64> {
+        self.check_nodes_exist(&[from, to])?;
+        let mut distances: HashMap<&K, usize> = HashMap::new();
+        let mut counts: HashMap<&K, u64> = HashMap::new();
+        let mut queue = VecDeque::new();
+        distances.insert(from, 0);
+        counts.insert(from, 1);
+        queue.push_back(from);
+        while let Some(node) = queue.pop_front() {
+            if node == to {
+                // Every node on the level before `to` has been visited
+                // already, so its count will not change any more.
+                break;
+            }
+            let adjacent = match self.get_adjacent(node) {
+                Some(adjacent) => adjacent,
+                None => continue
+            };
+            let distance = distances[node];
+            let count = counts[node];
+            for next in adjacent.keys() {
+                let next_distance = *distances.entry(next).or_insert_with(|| {
+                    queue.push_back(next);
+                    distance + 1
+                });
+                if next_distance == distance + 1 {
+                    let next_count = counts.entry(next).or_insert(0);
+                    *next_count = next_count.saturating_add(count);
+                }
+            }
+        }
+        Ok(counts.get(to).copied().unwrap_or(0))
+    }
+
+    /// Find the center of this graph, which are the nodes whose
+    /// eccentricity is the smallest. The eccentricity of a node is the
+    /// distance from it to the node furthest away from it, where the
+    /// distance between 2 nodes is the number of edges on the shortest path
+    /// going from one to the other (the costs of the edges are ignored). The
+    /// nodes are returned in no particular order, and a graph with no nodes
+    /// has no center.
+    /// 
+    /// The eccentricities are found with a breadth-first search from every
+    /// node, which takes O(V * (V + E)) time. Edges are followed in their
+    /// direction, so every node must be able to reach every other node. If
+    /// that is not the case, the eccentricity of some nodes would be
+    /// infinite, so an `AgcError` of kind `Disconnected` is returned instead
+    /// of a center that only considers part of the graph.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     let edges = [('a', 'b'), ('b', 'c'), ('c', 'd')];
+    ///     for (left, right) in edges.iter() {
+    ///         let edge = Edge::new(*left, *right, 1, EdgeKind::Bidirectional);
+    ///         matrix.push(edge).unwrap();
+    ///     }
+    ///     let mut center = matrix.graph_center().unwrap();
+    ///     center.sort();
+    ///     assert_eq!(center, vec!['b', 'c']);
+    /// ```
+    pub fn graph_center(&self) -> AgcResult<Vec<K>> {
+        let nodes = self.all_nodes();
+        let mut center = Vec::new();
+        let mut smallest = usize::MAX;
+        for node in nodes.iter() {
+            let distances = self.hop_distances(node);
+            if distances.len() != nodes.len() {
+                return Err(AgcError::new(
+                    AgcErrorKind::Disconnected,
+                    "not every node can be reached from every other node"
+                ));
+            }
+            let eccentricity = distances.values().copied().max().unwrap_or(0);
+            if eccentricity < smallest {
+                smallest = eccentricity;
+                center.clear();
+            }
+            if eccentricity == smallest {
+                center.push((*node).clone());
+            }
+        }
+        Ok(center)
+    }
+
+    /// Find the number of edges on the shortest path from `from` to every
+    /// node that can be reached from it, including `from` itself.
+    fn hop_distances<'a>(&'a self, from: &'a K) -> HashMap<&'a K, usize> {
+        let mut distances = HashMap::new();
+        let mut queue = VecDeque::new();
+        distances.insert(from, 0);
+        queue.push_back(from);
+        while let Some(node) = queue.pop_front() {
+            let distance = distances[node];
+            if let Some(adjacent) = self.get_adjacent(node) {
+                for next in adjacent.keys() {
+                    distances.entry(next).or_insert_with(|| {
+                        queue.push_back(next);
+                        distance + 1
+                    });
+                }
+            }
+        }
+        distances
+    }
+}
+
+impl<K, V> AdjacencyMatrix<K, V>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike + Zero
+{
+    /// Find the eccentricity of every node in this graph, along with the
+    /// radius, diameter, center and periphery of the graph (see
+    /// `GraphMetrics`). Unlike `graph_center`, the distance between 2 nodes
+    /// is the total cost of the cheapest path from one to the other,
+    /// following the direction of the edges.
+    /// 
+    /// The cost of the cheapest path between every pair of nodes is found
+    /// once with the Floyd-Warshall algorithm, which takes O(V^3) time, and
+    /// every metric is worked out from those costs. An `AgcError` is
+    /// returned if the graph has no nodes (`InvalidArgument`), if some node
+    /// cannot reach another node (`Disconnected`) or if the graph has a
+    /// cycle with a negative total cost (`NegativeCycle`), since the
+    /// eccentricities would not be defined.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     let edges = [('a', 'b', 1), ('b', 'c', 2), ('c', 'd', 3)];
+    ///     for (left, right, cost) in edges.iter() {
+    ///         let kind = EdgeKind::Bidirectional;
+    ///         matrix.push(Edge::new(*left, *right, *cost, kind)).unwrap();
+    ///     }
+    ///     let metrics = matrix.graph_metrics().unwrap();
+    ///     assert_eq!(metrics.radius(), 3);
+    ///     assert_eq!(metrics.diameter(), 6);
+    ///     assert_eq!(metrics.center(), ['c']);
+    /// ```
+    pub fn graph_metrics(&self) -> AgcResult<GraphMetrics<K, V>> {
+        let nodes = self.all_nodes().into_iter().collect::<Vec<&K>>();
+        if nodes.is_empty() {
+            return Err(AgcError::new(
+                AgcErrorKind::InvalidArgument,
+                "a graph with no nodes has no metrics."
+            ));
+        }
+        let mut costs = nodes
+            .iter()
+            .enumerate()
+            .map(|(from, node)| nodes
+                .iter()
+                .enumerate()
+                .map(|(to, other)| if from == to {
+                    Some(V::zero())
+                } else {
+                    self.get_edge(node, other).copied()
+                })
+                .collect::<Vec<Option<V>>>()
+            )
+            .collect::<Vec<Vec<Option<V>>>>();
+        for through in 0..nodes.len() {
+            let from_through = costs[through].clone();
+            for row in costs.iter_mut() {
+                let to_through = match row[through] {
+                    Some(cost) => cost,
+                    None => continue
+                };
+                for (cost, after) in row.iter_mut().zip(from_through.iter()) {
+                    if let Some(after) = after {
+                        let total = to_through + *after;
+                        if !matches!(cost, Some(cost) if *cost <= total) {
+                            *cost = Some(total);
+                        }
+                    }
+                }
+            }
+        }
+        // A node on a negative cycle can reach itself for less than nothing
+        let negative = costs.iter().enumerate().any(|(index, row)| {
+            matches!(row[index], Some(cost) if cost < V::zero())
+        });
+        if negative {
+            return Err(AgcError::new(
+                AgcErrorKind::NegativeCycle,
+                "the graph has a cycle with a negative total cost."
+            ));
+        }
+        let mut eccentricities = HashMap::new();
+        for (node, row) in nodes.iter().zip(costs.iter()) {
+            let eccentricity = row.iter().try_fold(V::zero(), |largest, cost| {
+                cost.map(|cost| largest.max(cost))
+            });
+            match eccentricity {
+                Some(eccentricity) => {
+                    eccentricities.insert((*node).clone(), eccentricity);
+                },
+                None => return Err(AgcError::new(
+                    AgcErrorKind::Disconnected,
+                    "not every node can be reached from every other node"
+                ))
+            }
+        }
+        let radius = *eccentricities.values().min().unwrap();
+        let diameter = *eccentricities.values().max().unwrap();
+        let with_eccentricity = |target: V| eccentricities
+            .iter()
+            .filter(|(_, eccentricity)| **eccentricity == target)
+            .map(|(node, _)| node.clone())
+            .collect::<Vec<K>>();
+        let center = with_eccentricity(radius);
+        let periphery = with_eccentricity(diameter);
+        Ok(GraphMetrics {eccentricities, radius, diameter, center, periphery})
+    }
+
+    /// Find every simple path from `from` to `to` whose total cost is at
+    /// most `budget`, along with its cost. A simple path never visits the
+    /// same node twice, so if `from` and `to` are the same node, the only
+    /// path is the one with no edges, which costs nothing. The paths are
+    /// sorted from the cheapest to the most expensive, and paths with the
+    /// same cost are in no particular order. An `AgcError` of kind
+    /// `NotFound` is returned if either node is not in the graph.
+    /// 
+    /// The paths are found with a depth-first search from `from`, which
+    /// keeps track of the nodes on the current path so that none of them are
+    /// visited again. If no edge has a negative cost, a path can only get
+    /// more expensive as it grows, so the search stops following a path as
+    /// soon as it goes over the budget. Otherwise, every simple path has to
+    /// be tried. There can be exponentially many simple paths, so this is
+    /// only meant for small graphs. The paths found are sorted with
+    /// `mergesort_by`.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     let edges = [
+    ///         ('a', 'b', 1), ('b', 'd', 1), ('a', 'c', 2), ('c', 'd', 5)
+    ///     ];
+    ///     for (left, right, cost) in edges.iter() {
+    ///         matrix.push(Edge::new(*left, *right, *cost, EdgeKind::ToRight))
+    ///             .unwrap();
+    ///     }
+    ///     let paths = matrix.paths_within_cost(&'a', &'d', 5).unwrap();
+    ///     assert_eq!(paths, vec![(vec!['a', 'b', 'd'], 2)]);
+    /// ```
+    pub fn paths_within_cost(
+        &self,
+        from: &K,
+        to: &K,
+        budget: V
+    ) -> AgcResult<Vec<(Vec<K>, V)>> {
+        self.check_nodes_exist(&[from, to])?;
+        if from == to {
+            let paths = if V::zero() <= budget {
+                vec![(vec![from.clone()], V::zero())]
+            } else {
+                Vec::new()
+            };
+            return Ok(paths);
+        }
+        let prune = self.nodes().all(|node| {
+            self.get_adjacent(node)
+                .unwrap()
+                .values()
+                .all(|cost| *cost >= V::zero())
+        });
+        let mut paths = Vec::new();
+        let mut path = vec![from];
+        let mut costs = vec![V::zero()];
+        let mut on_path = HashSet::new();
+        on_path.insert(from);
+        let mut stack = vec![self.get_adjacent(from).into_iter().flatten()];
+        while let Some(adjacent) = stack.last_mut() {
+            let (next, edge) = match adjacent.next() {
+                Some(step) => step,
+                None => {
+                    // Every edge out of this node has been tried
+                    stack.pop();
+                    on_path.remove(path.pop().unwrap());
+                    costs.pop();
+                    continue;
+                }
+            };
+            if on_path.contains(next) {
+                continue;
+            }
+            let cost = *costs.last().unwrap() + *edge;
+            if prune && cost > budget {
+                continue;
+            }
+            if next == to {
+                if cost <= budget {
+                    let mut nodes = path
+                        .iter()
+                        .map(|node| (*node).clone())
+                        .collect::<Vec<K>>();
+                    nodes.push(next.clone());
+                    paths.push((nodes, cost));
+                }
+                continue;
+            }
+            path.push(next);
+            costs.push(cost);
+            on_path.insert(next);
+            stack.push(self.get_adjacent(next).into_iter().flatten());
+        }
+        mergesort_by(&mut paths, true, |a, b| a.1.cmp(&b.1))?;
+        Ok(paths)
+    }
+
+    /// Find the cost of the cheapest path from `source` to every node that
+    /// can be reached from it, including `source` itself, which costs
+    /// nothing. Nodes which cannot be reached are left out. An `AgcError` of
+    /// kind `NotFound` is returned if `source` is not in the graph.
+    /// 
+    /// This is Dijkstra's algorithm, which keeps the nodes that have been
+    /// reached so far in a min-heap keyed on the cost of getting to them. The
+    /// cheapest node is taken off the heap each time, and its cost is final,
+    /// because any other way of getting to it would have to go through a
+    /// node which costs at least as much. This takes O((V + E) log V) time.
+    /// 
+    /// That reasoning falls apart if an edge can have a negative cost, as a
+    /// path could get cheaper by going through a more expensive node. An
+    /// `AgcError` of kind `InvalidArgument` is returned if any edge in the
+    /// graph has a negative cost, in which case `find_negative_cycle` and the
+    /// Floyd-Warshall algorithm used by `graph_metrics` still work.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     let edges = [('a', 'b', 4), ('a', 'c', 1), ('c', 'b', 2)];
+    ///     for (left, right, cost) in edges.iter() {
+    ///         matrix.push(Edge::new(*left, *right, *cost, EdgeKind::ToRight))
+    ///             .unwrap();
+    ///     }
+    ///     let costs = matrix.dijkstra(&'a').unwrap();
+    ///     assert_eq!(costs[&'a'], 0);
+    ///     assert_eq!(costs[&'b'], 3);
+    ///     assert_eq!(costs[&'c'], 1);
+    /// ```
+    pub fn dijkstra(&self, source: &K) -> AgcResult<HashMap<K, V>> {
+        self.check_nodes_exist(&[source])?;
+        let search = self.best_first_search(source, None, |_| V::zero())?;
+        Ok(search.costs
+            .into_iter()
+            .map(|(node, cost)| (node.clone(), cost))
+            .collect())
+    }
+
+    /// Find the cheapest path from `source` to `target` with Dijkstra's
+    /// algorithm (see `dijkstra`), returning its cost and the nodes on it
+    /// from `source` to `target`. `Ok(None)` is returned if `target` cannot
+    /// be reached from `source`. An `AgcError` of kind `NotFound` is returned
+    /// if either node is not in the graph, and one of kind `InvalidArgument`
+    /// is returned if any edge has a negative cost.
+    /// 
+    /// Whenever a cheaper way of getting to a node is found, the node it was
+    /// reached from is recorded as its predecessor. The path is rebuilt by
+    /// following the predecessors back from `target` to `source`. The search
+    /// stops as soon as the cost of `target` is final.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     let edges = [('a', 'b', 4), ('a', 'c', 1), ('c', 'b', 2)];
+    ///     for (left, right, cost) in edges.iter() {
+    ///         matrix.push(Edge::new(*left, *right, *cost, EdgeKind::ToRight))
+    ///             .unwrap();
+    ///     }
+    ///     let path = matrix.dijkstra_path(&'a', &'b').unwrap();
+    ///     assert_eq!(path, Some((3, vec!['a', 'c', 'b'])));
+    ///     assert_eq!(matrix.dijkstra_path(&'b', &'a').unwrap(), None);
+    /// ```
+    pub fn dijkstra_path(
+        &self,
+        source: &K,
+        target: &K
+    ) -> AgcResult<Option<CostedPath<K, V>>> {
+        self.check_nodes_exist(&[source, target])?;
+        let zero = |_: &K| V::zero();
+        let search = self.best_first_search(source, Some(target), zero)?;
+        Ok(search.path_to(target))
+    }
+
+    /// Find the cost of the cheapest path from `source` to every node that
+    /// can be reached from it, like `dijkstra`, but allowing edges with
+    /// negative costs. Nodes which cannot be reached are left out. An
+    /// `AgcError` of kind `NotFound` is returned if `source` is not in the
+    /// graph, and one of kind `NegativeCycle` is returned if a cycle with a
+    /// negative total cost can be reached from `source`, since going around
+    /// it again and again makes the paths after it as cheap as you like.
+    /// 
+    /// This is the Bellman-Ford algorithm, which relaxes every edge over and
+    /// over, in the same way as `find_negative_cycle`: if going through an
+    /// edge gives a cheaper way of getting to the node at its end, the cost
+    /// of that node is lowered. A cheapest path never has more than `V - 1`
+    /// edges, so the costs are final after `V - 1` passes, and a pass which
+    /// still lowers a cost after that can only be caused by a negative cycle.
+    /// The passes stop early if nothing changes. This takes O(VE) time, which
+    /// is slower than Dijkstra's algorithm.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     let edges = [('a', 'b', 4), ('a', 'c', 1), ('b', 'c', -5)];
+    ///     for (left, right, cost) in edges.iter() {
+    ///         matrix.push(Edge::new(*left, *right, *cost, EdgeKind::ToRight))
+    ///             .unwrap();
+    ///     }
+    ///     let costs = matrix.bellman_ford(&'a').unwrap();
+    ///     assert_eq!(costs[&'b'], 4);
+    ///     assert_eq!(costs[&'c'], -1);
+    /// ```
+    pub fn bellman_ford(&self, source: &K) -> AgcResult<HashMap<K, V>> {
+        self.check_nodes_exist(&[source])?;
+        let edges = self.edges().collect::<Vec<Edge<K, V>>>();
+        let node_count = self.all_nodes().len();
+        let mut costs: HashMap<&K, V> = HashMap::new();
+        costs.insert(source, V::zero());
+        // The extra pass at the end looks for negative cycles
+        for pass in 0..node_count {
+            if relax_edges(&edges, &mut costs, None).is_none() {
+                break;
+            }
+            if pass + 1 == node_count {
+                return Err(AgcError::new(
+                    AgcErrorKind::NegativeCycle,
+                    "a cycle with a negative total cost can be reached."
+                ));
+            }
+        }
+        Ok(costs
+            .into_iter()
+            .map(|(node, cost)| (node.clone(), cost))
+            .collect())
+    }
+
+    /// Find the cheapest path from `start` to `goal` with A* search,
+    /// returning its cost and the nodes on it from `start` to `goal`.
+    /// `Ok(None)` is returned if `goal` cannot be reached from `start`. An
+    /// `AgcError` of kind `NotFound` is returned if either node is not in
+    /// the graph, and one of kind `InvalidArgument` is returned if any edge
+    /// has a negative cost.
+    /// 
+    /// A* search works like Dijkstra's algorithm (see `dijkstra_path`), but
+    /// the heap is keyed on the cost of getting to a node plus
+    /// `heuristic(node)`, which is a guess of the cost of getting from the
+    /// node to `goal`. Nodes which look like they lead towards `goal` are
+    /// expanded first, so a good heuristic lets the search reach `goal`
+    /// after expanding fewer nodes. A heuristic which always returns 0 turns
+    /// this back into Dijkstra's algorithm.
+    /// 
+    /// The path is only guaranteed to be the cheapest if the heuristic is
+    /// admissible, which means it never guesses more than the real cost of
+    /// getting to `goal`. As a node is not expanded again once it has been
+    /// taken off the heap, the heuristic must also be consistent: the guess
+    /// for a node can be no more than the cost of an edge out of it plus the
+    /// guess for the node at the other end. The straight-line distance to
+    /// `goal` on a map is both.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<i32, i32>::new();
+    ///     let kind = EdgeKind::Bidirectional;
+    ///     for node in 0..5 {
+    ///         matrix.push(Edge::new(node, node + 1, 1, kind)).unwrap();
+    ///     }
+    ///     let heuristic = |node: &i32| (5 - node).abs();
+    ///     let path = matrix.astar(&0, &5, heuristic).unwrap();
+    ///     assert_eq!(path, Some((5, vec![0, 1, 2, 3, 4, 5])));
+    /// ```
+    pub fn astar<H>(
+        &self,
+        start: &K,
+        goal: &K,
+        heuristic: H
+    ) -> AgcResult<Option<CostedPath<K, V>>>
+    where
+        H: Fn(&K) -> V
+    {
+        self.astar_counted(start, goal, heuristic).map(|(_, path)| path)
+    }
+
+    /// Find the cheapest path from `start` to `goal` with A* search like
+    /// `astar`, but also return the number of nodes that were expanded,
+    /// which is the number of nodes taken off the heap to have their edges
+    /// followed. This can be used to see how much work a heuristic saves,
+    /// by comparing it with a heuristic which always returns 0.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<i32, i32>::new();
+    ///     let kind = EdgeKind::Bidirectional;
+    ///     for node in -5..5 {
+    ///         matrix.push(Edge::new(node, node + 1, 1, kind)).unwrap();
+    ///     }
+    ///     let (blind, _) = matrix.astar_counted(&0, &5, |_| 0).unwrap();
+    ///     let heuristic = |node: &i32| (5 - node).abs();
+    ///     let (guided, _) = matrix.astar_counted(&0, &5, heuristic).unwrap();
+    ///     assert!(guided < blind);
+    /// ```
+    pub fn astar_counted<H>(
+        &self,
+        start: &K,
+        goal: &K,
+        heuristic: H
+    ) -> AgcResult<(usize, Option<CostedPath<K, V>>)>
+    where
+        H: Fn(&K) -> V
+    {
+        self.check_nodes_exist(&[start, goal])?;
+        let search = self.best_first_search(start, Some(goal), heuristic)?;
+        Ok((search.expanded, search.path_to(goal)))
+    }
+
+    /// Run Dijkstra's algorithm from `source`, or A* search if `heuristic`
+    /// does not always return 0, stopping early once the cost of `target`
+    /// is final if a target is given.
+    fn best_first_search<'a, H>(
+        &'a self,
+        source: &'a K,
+        target: Option<&K>,
+        heuristic: H
+    ) -> AgcResult<CheapestPaths<'a, K, V>>
+    where
+        H: Fn(&K) -> V
+    {
+        let negative = self.nodes().any(|node| {
+            self.get_adjacent(node)
+                .unwrap()
+                .values()
+                .any(|cost| *cost < V::zero())
+        });
+        if negative {
+            return Err(AgcError::new(
+                AgcErrorKind::InvalidArgument,
+                "edges with negative costs cannot be searched this way."
+            ));
+        }
+        // Entries are (cost + heuristic, cost, node). Ties are broken in
+        // favour of the node furthest from `source`, as it is likely to be
+        // closer to the target.
+        let compare = |a: &(V, V, &K), b: &(V, V, &K)| {
+            a.0.cmp(&b.0).then(b.1.cmp(&a.1))
+        };
+        let mut best: HashMap<&K, V> = HashMap::new();
+        let mut settled: HashMap<&K, V> = HashMap::new();
+        let mut predecessors: HashMap<&K, &K> = HashMap::new();
+        let mut heap = vec![(heuristic(source), V::zero(), source)];
+        best.insert(source, V::zero());
+        while !heap.is_empty() {
+            // A node can be in the heap more than once, and the cheapest
+            // entry for it comes out first.
+            let (_, cost, node) = heap.swap_remove(0);
+            sift_down_by(&mut heap[..], 0, false, compare);
+            if settled.contains_key(node) {
+                continue;
+            }
+            settled.insert(node, cost);
+            if target == Some(node) {
+                break;
+            }
+            for (next, edge) in self.get_adjacent(node).into_iter().flatten() {
+                if settled.contains_key(next) {
+                    continue;
+                }
+                let total = cost + *edge;
+                if !matches!(best.get(next), Some(known) if *known <= total) {
+                    best.insert(next, total);
+                    predecessors.insert(next, node);
+                    heap.push((total + heuristic(next), total, next));
+                    let last = heap.len() - 1;
+                    sift_up_by(&mut heap[..], last, false, compare);
+                }
+            }
+        }
+        let expanded = settled.len();
+        Ok(CheapestPaths {costs: settled, predecessors, expanded})
+    }
+}
\ No newline at end of file
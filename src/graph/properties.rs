@@ -0,0 +1,190 @@
+//! Functions which check the structure of a graph.
+
+use std::collections::{HashMap, HashSet};
+use crate::{
+    error::{AgcError, AgcErrorKind, AgcResult},
+    graph::maps::AdjacencyMatrix,
+    traits::{AgcHashable, AgcNumberLike}
+};
+
+/// The largest number of nodes that `AdjacencyMatrix::is_isomorphic` will
+/// search through before returning an error.
+pub const MAX_ISOMORPHISM_NODES: usize = 10;
+
+impl<K, V> AdjacencyMatrix<K, V>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    /// Check if this graph is a tree when the direction of its edges is
+    /// ignored. A tree is a graph where every node can be reached from every
+    /// other node (it is connected) and there are no cycles.
+    /// 
+    /// A connected graph with `n` nodes needs at least `n - 1` edges, and any
+    /// more than that would create a cycle, so this function checks that the
+    /// graph is connected with a depth-first search from any node and that it
+    /// has exactly `n - 1` edges. Edges going both ways between the same 2
+    /// nodes are counted as 1 edge. A graph with no nodes is not a tree.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<i32, i32>::new();
+    ///     matrix.push(Edge::new(0, 1, 1, EdgeKind::ToRight)).unwrap();
+    ///     matrix.push(Edge::new(0, 2, 1, EdgeKind::Bidirectional)).unwrap();
+    ///     assert!(matrix.is_tree());
+    ///     matrix.push(Edge::new(1, 2, 1, EdgeKind::ToRight)).unwrap();
+    ///     assert!(!matrix.is_tree());
+    /// ```
+    pub fn is_tree(&self) -> bool {
+        let adjacency = self.undirected_adjacency();
+        let start = match adjacency.keys().next() {
+            Some(start) => *start,
+            None => return false
+        };
+        let degrees = adjacency.values().map(|adjacent| adjacent.len());
+        let edge_count = degrees.sum::<usize>() / 2;
+        if edge_count != adjacency.len() - 1 {
+            return false;
+        }
+        let mut visited = HashSet::new();
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            if visited.insert(node) {
+                stack.extend(adjacency[node].iter().copied());
+            }
+        }
+        visited.len() == adjacency.len()
+    }
+
+    /// Check if this graph is isomorphic to `other`, which means that the
+    /// nodes of this graph can be renamed so that it becomes exactly the same
+    /// graph as `other`. Every edge must keep its direction and its cost.
+    /// 
+    /// Graphs with different numbers of nodes or edges cannot be isomorphic,
+    /// and neither can graphs whose nodes have different numbers of incoming
+    /// and outgoing edges, so these are checked first. After that, a
+    /// backtracking search tries to map each node of this graph to a node in
+    /// `other` with the same number of incoming and outgoing edges, checking
+    /// that the edges between the nodes mapped so far are the same in both
+    /// graphs. This takes O(V!) time in the worst case, so it is only meant
+    /// for small graphs.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut left = AdjacencyMatrix::<i32, i32>::new();
+    ///     left.push(Edge::new(0, 1, 1, EdgeKind::ToRight)).unwrap();
+    ///     left.push(Edge::new(1, 2, 2, EdgeKind::ToRight)).unwrap();
+    ///     let mut right = AdjacencyMatrix::<i32, i32>::new();
+    ///     right.push(Edge::new(5, 3, 1, EdgeKind::ToRight)).unwrap();
+    ///     right.push(Edge::new(3, 4, 2, EdgeKind::ToRight)).unwrap();
+    ///     assert_eq!(left.is_isomorphic(&right), Ok(true));
+    ///     *right.get_mut_edge(&3, &4).unwrap() = 1;
+    ///     assert_eq!(left.is_isomorphic(&right), Ok(false));
+    /// ```
+    /// 
+    /// If both graphs could still be isomorphic after the quick checks above
+    /// and they have more than `MAX_ISOMORPHISM_NODES` nodes, an `AgcError`
+    /// of kind `InvalidArgument` is returned instead of running the search
+    /// for a very long time.
+    pub fn is_isomorphic(
+        &self,
+        other: &AdjacencyMatrix<K, V>
+    ) -> AgcResult<bool> {
+        let nodes = self.all_nodes().into_iter().collect::<Vec<&K>>();
+        let other_nodes = other.all_nodes().into_iter().collect::<Vec<&K>>();
+        if nodes.len() != other_nodes.len()
+        || self.edge_count() != other.edge_count() {
+            return Ok(false);
+        }
+        let degrees = self.degrees();
+        let other_degrees = other.degrees();
+        let mut sequence = degrees.values().collect::<Vec<_>>();
+        let mut other_sequence = other_degrees.values().collect::<Vec<_>>();
+        sequence.sort();
+        other_sequence.sort();
+        if sequence != other_sequence {
+            return Ok(false);
+        }
+        if nodes.len() > MAX_ISOMORPHISM_NODES {
+            return Err(AgcError::new(
+                AgcErrorKind::InvalidArgument,
+                "the graphs have too many nodes to check."
+            ));
+        }
+        let candidates = nodes
+            .iter()
+            .map(|node| other_nodes
+                .iter()
+                .copied()
+                .filter(|other_node| {
+                    degrees[node] == other_degrees[other_node]
+                })
+                .collect::<Vec<&K>>()
+            )
+            .collect::<Vec<Vec<&K>>>();
+        let mut mapping = Vec::with_capacity(nodes.len());
+        Ok(self.extend_isomorphism(other, &nodes, &candidates, &mut mapping))
+    }
+
+    /// Try to map the rest of `nodes` to nodes in `other`, given that the
+    /// first `mapping.len()` nodes have been mapped to the nodes in `mapping`.
+    fn extend_isomorphism<'a>(
+        &self,
+        other: &AdjacencyMatrix<K, V>,
+        nodes: &[&K],
+        candidates: &[Vec<&'a K>],
+        mapping: &mut Vec<&'a K>
+    ) -> bool {
+        let index = mapping.len();
+        if index == nodes.len() {
+            return true;
+        }
+        let node = nodes[index];
+        for candidate in candidates[index].iter().copied() {
+            if mapping.contains(&candidate) {
+                continue;
+            }
+            let consistent = nodes.iter().zip(mapping.iter()).all(
+                |(mapped, image)| {
+                    self.get_edge(node, mapped)
+                        == other.get_edge(candidate, image)
+                    && self.get_edge(mapped, node)
+                        == other.get_edge(image, candidate)
+                }
+            );
+            if consistent {
+                mapping.push(candidate);
+                if self.extend_isomorphism(other, nodes, candidates, mapping) {
+                    return true;
+                }
+                mapping.pop();
+            }
+        }
+        false
+    }
+
+    /// Count the directed edges in this graph.
+    fn edge_count(&self) -> usize {
+        self.nodes()
+            .filter_map(|node| self.get_adjacent(node))
+            .map(|adjacent| adjacent.len())
+            .sum()
+    }
+
+    /// Map every node to the number of edges going out of it and into it.
+    fn degrees(&self) -> HashMap<&K, (usize, usize)> {
+        let mut degrees = self.all_nodes()
+            .into_iter()
+            .map(|node| (node, (0, 0)))
+            .collect::<HashMap<&K, (usize, usize)>>();
+        for from in self.nodes() {
+            for to in self.get_adjacent(from).into_iter().flatten() {
+                degrees.get_mut(from).unwrap().0 += 1;
+                degrees.get_mut(to.0).unwrap().1 += 1;
+            }
+        }
+        degrees
+    }
+}
\ No newline at end of file
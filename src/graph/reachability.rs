@@ -0,0 +1,134 @@
+//! Functions which find out which nodes can be reached from which.
+
+use std::collections::{HashMap, HashSet};
+use crate::{
+    graph::{disjoint_set::DisjointSet, maps::AdjacencyMatrix},
+    traits::{AgcHashable, AgcNumberLike}
+};
+
+impl<K, V> AdjacencyMatrix<K, V>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    /// Find the transitive closure of this graph, which maps every node to
+    /// the set of nodes that can be reached from it by following 1 or more
+    /// edges. A node is only in its own set if it is part of a cycle. Every
+    /// node in the graph, including nodes which only have edges going into
+    /// them, is a key in the returned `HashMap`.
+    /// 
+    /// This uses the same triple loop as the Floyd-Warshall algorithm, but
+    /// with booleans instead of distances. Every node `k` is tried as a
+    /// stepping stone, and if `i` can reach `k` and `k` can reach `j`, then
+    /// `i` can reach `j`. This takes O(V^3) time and O(V^2) memory.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     matrix.push(Edge::new('a', 'b', 1, EdgeKind::ToRight)).unwrap();
+    ///     matrix.push(Edge::new('b', 'c', 1, EdgeKind::ToRight)).unwrap();
+    ///     let closure = matrix.transitive_closure();
+    ///     assert!(closure[&'a'].contains(&'c'));
+    ///     assert!(closure[&'c'].is_empty());
+    /// ```
+    pub fn transitive_closure(&self) -> HashMap<K, HashSet<K>> {
+        let nodes = self.all_nodes().into_iter().collect::<Vec<&K>>();
+        let indices = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (*node, index))
+            .collect::<HashMap<&K, usize>>();
+        let count = nodes.len();
+        let mut reachable = vec![vec![false; count]; count];
+        for from in self.nodes() {
+            for to in self.get_adjacent(from).unwrap().keys() {
+                reachable[indices[from]][indices[to]] = true;
+            }
+        }
+        for k in 0..count {
+            let through = reachable[k].clone();
+            for row in reachable.iter_mut().filter(|row| row[k]) {
+                for (reached, via_k) in row.iter_mut().zip(through.iter()) {
+                    *reached |= *via_k;
+                }
+            }
+        }
+        nodes
+            .iter()
+            .enumerate()
+            .map(|(i, from)| {
+                let reached = (0..count)
+                    .filter(|j| reachable[i][*j])
+                    .map(|j| nodes[j].clone())
+                    .collect::<HashSet<K>>();
+                ((*from).clone(), reached)
+            })
+            .collect()
+    }
+
+    /// Split the nodes of this graph into connected components, ignoring the
+    /// direction of the edges. 2 nodes are in the same component if there is
+    /// a path between them, so every node can reach every other node in its
+    /// component and none outside of it. A node with no edges is a component
+    /// of its own. The components and the nodes in them are in no particular
+    /// order.
+    /// 
+    /// Every node starts off in a set of its own in a `DisjointSet`, and the
+    /// sets of the 2 nodes on each edge are merged, so the sets left at the
+    /// end are the components. This takes nearly O(V + E) time.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     matrix.push(Edge::new('a', 'b', 1, EdgeKind::ToRight)).unwrap();
+    ///     matrix.push(Edge::new('c', 'b', 1, EdgeKind::ToRight)).unwrap();
+    ///     matrix.push(Edge::new('x', 'y', 1, EdgeKind::ToRight)).unwrap();
+    ///     let mut components = matrix.connected_components();
+    ///     for component in components.iter_mut() {
+    ///         component.sort();
+    ///     }
+    ///     components.sort();
+    ///     assert_eq!(components, vec![vec!['a', 'b', 'c'], vec!['x', 'y']]);
+    /// ```
+    pub fn connected_components(&self) -> Vec<Vec<K>> {
+        let mut sets = self.component_sets();
+        let mut components: HashMap<K, Vec<K>> = HashMap::new();
+        for node in self.all_nodes() {
+            let root = sets.find(node).unwrap().clone();
+            components.entry(root).or_default().push(node.clone());
+        }
+        components.into_values().collect()
+    }
+
+    /// Count the connected components of this graph, ignoring the direction
+    /// of the edges (see `connected_components`).
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     matrix.push(Edge::new('a', 'b', 1, EdgeKind::ToRight)).unwrap();
+    ///     matrix.push(Edge::new('x', 'y', 1, EdgeKind::ToRight)).unwrap();
+    ///     assert_eq!(matrix.component_count(), 2);
+    /// ```
+    pub fn component_count(&self) -> usize {
+        self.component_sets().set_count()
+    }
+
+    /// Put every node in a `DisjointSet`, with the nodes on either end of
+    /// each edge in the same set.
+    fn component_sets(&self) -> DisjointSet<K> {
+        let mut sets = DisjointSet::new();
+        for node in self.all_nodes() {
+            sets.make_set(node.clone());
+        }
+        for from in self.nodes() {
+            for to in self.get_adjacent(from).unwrap().keys() {
+                sets.union(from, to).unwrap();
+            }
+        }
+        sets
+    }
+}
\ No newline at end of file
@@ -0,0 +1,146 @@
+//! Functions which find spanning trees of a graph.
+
+use std::collections::HashSet;
+use crate::{
+    error::{AgcError, AgcErrorKind, AgcResult},
+    graph::{
+        disjoint_set::DisjointSet,
+        maps::{AdjacencyMatrix, Edge, EdgeKind}
+    },
+    sort::mergesort::mergesort_by,
+    structures::heap::{sift_down_by, sift_up_by},
+    traits::{AgcHashable, AgcNumberLike}
+};
+
+impl<K, V> AdjacencyMatrix<K, V>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    /// Find a minimum spanning tree of this graph, which is a set of edges
+    /// joining every node together with the smallest possible total cost.
+    /// The direction of the edges is ignored, and if there are edges going
+    /// both ways between 2 nodes, the cheaper one is used. The edges of the
+    /// tree are returned as `Bidirectional` edges in the order they were
+    /// added to the tree. An `AgcError` of kind `NotFound` is returned if
+    /// `start` is not in the graph, and one of kind `Disconnected` is
+    /// returned if some nodes cannot be reached from `start`, as there is no
+    /// tree which joins every node.
+    /// 
+    /// This is Prim's algorithm, which grows the tree outwards from `start`.
+    /// Every edge leading out of the tree is kept in a min-heap, and the
+    /// cheapest one going to a node which is not in the tree yet is added
+    /// each time. This takes O(E log E) time.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     let edges = [('a', 'b', 1), ('b', 'c', 2), ('a', 'c', 3)];
+    ///     for (left, right, cost) in edges.iter() {
+    ///         let kind = EdgeKind::Bidirectional;
+    ///         matrix.push(Edge::new(*left, *right, *cost, kind)).unwrap();
+    ///     }
+    ///     let tree = matrix.prim_mst(&'a').unwrap();
+    ///     assert_eq!(tree.len(), 2);
+    ///     assert_eq!(tree.iter().map(|edge| edge.cost).sum::<i32>(), 3);
+    /// ```
+    pub fn prim_mst(&self, start: &K) -> AgcResult<Vec<Edge<K, V>>> {
+        self.check_nodes_exist(&[start])?;
+        let adjacency = self.undirected_adjacency();
+        // Entries are (cost, node in the tree, node outside of it)
+        let compare = |a: &(V, &K, &K), b: &(V, &K, &K)| a.0.cmp(&b.0);
+        let mut heap = Vec::new();
+        let mut in_tree = HashSet::new();
+        let mut tree = Vec::with_capacity(adjacency.len().saturating_sub(1));
+        let mut next = Some(start);
+        while let Some(node) = next {
+            in_tree.insert(node);
+            for neighbour in adjacency[node].iter() {
+                if !in_tree.contains(neighbour) {
+                    let cost = self.undirected_cost(node, neighbour);
+                    heap.push((cost, node, *neighbour));
+                    let last = heap.len() - 1;
+                    sift_up_by(&mut heap[..], last, false, compare);
+                }
+            }
+            next = None;
+            while !heap.is_empty() {
+                let (cost, from, to) = heap.swap_remove(0);
+                sift_down_by(&mut heap[..], 0, false, compare);
+                if !in_tree.contains(to) {
+                    let kind = EdgeKind::Bidirectional;
+                    tree.push(Edge::new(from.clone(), to.clone(), cost, kind));
+                    next = Some(to);
+                    break;
+                }
+            }
+        }
+        if in_tree.len() != adjacency.len() {
+            return Err(AgcError::new(
+                AgcErrorKind::Disconnected,
+                "not every node can be reached from the start of the tree."
+            ));
+        }
+        Ok(tree)
+    }
+
+    /// Find a minimum spanning tree of this graph, which is a set of edges
+    /// joining every node together with the smallest possible total cost,
+    /// like `prim_mst`. The direction of the edges is ignored, and the tree
+    /// is returned as `Bidirectional` edges from the cheapest to the most
+    /// expensive. An `AgcError` of kind `Disconnected` is returned if the
+    /// nodes cannot all be joined together.
+    /// 
+    /// This is Kruskal's algorithm, which sorts every edge by its cost with
+    /// `mergesort_by` and goes through them from the cheapest. Each node
+    /// starts in a set of its own in a `DisjointSet`, and an edge is added to
+    /// the tree if its nodes are in different sets, which are then merged. An
+    /// edge between 2 nodes in the same set would make a cycle, so it is
+    /// skipped. This takes O(E log E) time.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     let edges = [('a', 'b', 1), ('b', 'c', 2), ('a', 'c', 3)];
+    ///     for (left, right, cost) in edges.iter() {
+    ///         let kind = EdgeKind::Bidirectional;
+    ///         matrix.push(Edge::new(*left, *right, *cost, kind)).unwrap();
+    ///     }
+    ///     let tree = matrix.kruskal_mst().unwrap();
+    ///     let costs = tree.iter().map(|edge| edge.cost).collect::<Vec<_>>();
+    ///     assert_eq!(costs, [1, 2]);
+    /// ```
+    pub fn kruskal_mst(&self) -> AgcResult<Vec<Edge<K, V>>> {
+        let nodes = self.all_nodes();
+        let mut sets = DisjointSet::new();
+        for node in nodes.iter() {
+            sets.make_set((*node).clone());
+        }
+        let mut edges = self.edges().collect::<Vec<Edge<K, V>>>();
+        mergesort_by(&mut edges, true, |a, b| a.cost.cmp(&b.cost))?;
+        let mut tree = Vec::with_capacity(nodes.len().saturating_sub(1));
+        for edge in edges.into_iter() {
+            if sets.union(&edge.left, &edge.right)? {
+                let kind = EdgeKind::Bidirectional;
+                tree.push(Edge::new(edge.left, edge.right, edge.cost, kind));
+            }
+        }
+        if tree.len() + 1 < nodes.len() {
+            return Err(AgcError::new(
+                AgcErrorKind::Disconnected,
+                "not every node can be joined to the rest of the tree."
+            ));
+        }
+        Ok(tree)
+    }
+
+    /// Get the cost of the cheapest edge between `a` and `b` in either
+    /// direction. There must be at least 1 edge between them.
+    fn undirected_cost(&self, a: &K, b: &K) -> V {
+        let there = self.get_edge(a, b);
+        let back = self.get_edge(b, a);
+        *there.into_iter().chain(back).min().unwrap()
+    }
+}
\ No newline at end of file
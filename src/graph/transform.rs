@@ -0,0 +1,123 @@
+//! Functions which build new graphs out of existing ones.
+
+use std::collections::HashMap;
+use crate::{
+    error::{AgcError, AgcErrorKind, AgcResult},
+    graph::maps::{AdjacencyMatrix, Edge, EdgeKind},
+    traits::{AgcHashable, AgcNumberLike}
+};
+
+impl<K, V> AdjacencyMatrix<K, V>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    /// Create a new graph with the same edges and costs as this one, but
+    /// where every node has been relabelled by `f`. This is useful for
+    /// turning node IDs into names, for example.
+    /// 
+    /// `f` must give every node a different label. If 2 different nodes are
+    /// given the same label, their edges would be merged together, so an
+    /// `AgcError` of kind `AlreadyExists` is returned instead.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<i32, i32>::new();
+    ///     matrix.push(Edge::new(0, 1, 5, EdgeKind::ToRight)).unwrap();
+    ///     let named = matrix.map_nodes(|node| format!("node{}", node))
+    ///         .unwrap();
+    ///     let (from, to) = ("node0".to_string(), "node1".to_string());
+    ///     assert_eq!(named.get_edge(&from, &to), Some(&5));
+    ///     assert!(matrix.map_nodes(|node| node / 2).is_err());
+    /// ```
+    pub fn map_nodes<K2, G>(&self, f: G) -> AgcResult<AdjacencyMatrix<K2, V>>
+    where
+        K2: AgcHashable + Clone,
+        G: Fn(&K) -> K2
+    {
+        let mut labels: HashMap<&K, K2> = HashMap::new();
+        let mut originals: HashMap<K2, &K> = HashMap::new();
+        for node in self.all_nodes() {
+            let label = f(node);
+            if originals.insert(label.clone(), node).is_some() {
+                return Err(AgcError::new(
+                    AgcErrorKind::AlreadyExists,
+                    "2 different nodes cannot be mapped to the same node."
+                ));
+            }
+            labels.insert(node, label);
+        }
+        let mut mapped = AdjacencyMatrix::new();
+        for from in self.nodes() {
+            mapped.register_node(&labels[from]);
+            for (to, cost) in self.get_adjacent(from).unwrap().iter() {
+                mapped.push(Edge::new(
+                    labels[from].clone(),
+                    labels[to].clone(),
+                    *cost,
+                    EdgeKind::ToRight
+                ))?;
+            }
+        }
+        Ok(mapped)
+    }
+
+    /// Contract the edge between `a` and `b`, merging them into a single node
+    /// called `merged`. Every edge going to or from `a` or `b` goes to or
+    /// from `merged` instead, except for the edges between `a` and `b`,
+    /// which would become edges from `merged` to itself and are removed. If
+    /// `a` and `b` both had an edge to or from the same node, only the
+    /// cheaper edge is kept, just like when pushing an edge that already
+    /// exists. `merged` can be `a` or `b` itself.
+    /// 
+    /// The graph is rebuilt with the new labels, which takes O(V + E) time.
+    /// An `AgcError` of kind `NotFound` is returned if there is no edge from
+    /// `a` to `b` or from `b` to `a`, and one of kind `AlreadyExists` is
+    /// returned if `merged` is another node in the graph. The graph is not
+    /// changed if an error is returned.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     matrix.push(Edge::new('a', 'b', 1, EdgeKind::ToRight)).unwrap();
+    ///     matrix.push(Edge::new('b', 'c', 2, EdgeKind::ToRight)).unwrap();
+    ///     matrix.contract_edge(&'a', &'b', 'x').unwrap();
+    ///     assert_eq!(matrix.get_edge(&'x', &'c'), Some(&2));
+    ///     assert!(!matrix.registered(&'a'));
+    ///     assert!(matrix.contract_edge(&'x', &'b', 'y').is_err());
+    /// ```
+    pub fn contract_edge(&mut self, a: &K, b: &K, merged: K) -> AgcResult<()> {
+        if self.get_edge(a, b).is_none() && self.get_edge(b, a).is_none() {
+            return Err(AgcError::new(
+                AgcErrorKind::NotFound,
+                "there is no edge between the nodes to contract."
+            ));
+        }
+        if merged != *a && merged != *b && self.all_nodes().contains(&merged) {
+            return Err(AgcError::new(
+                AgcErrorKind::AlreadyExists,
+                "the merged node is already another node in the graph."
+            ));
+        }
+        let label = |node: &K| if node == a || node == b {
+            merged.clone()
+        } else {
+            node.clone()
+        };
+        let mut contracted = AdjacencyMatrix::new();
+        for from in self.nodes() {
+            contracted.register_node(&label(from));
+            for (to, cost) in self.get_adjacent(from).unwrap().iter() {
+                let (from, to) = (label(from), label(to));
+                if from != to {
+                    let edge = Edge::new(from, to, *cost, EdgeKind::ToRight);
+                    contracted.push(edge)?;
+                }
+            }
+        }
+        *self = contracted;
+        Ok(())
+    }
+}
\ No newline at end of file
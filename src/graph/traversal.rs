@@ -0,0 +1,148 @@
+//! Functions which visit the nodes of a graph one at a time.
+
+use std::collections::{HashSet, VecDeque};
+use crate::{
+    error::AgcResult,
+    graph::maps::AdjacencyMatrix,
+    traits::{AgcHashable, AgcNumberLike}
+};
+
+impl<K, V> AdjacencyMatrix<K, V>
+where
+    K: AgcHashable + Clone,
+    V: AgcNumberLike
+{
+    /// List the nodes that can be reached from `start` in the order that a
+    /// breadth-first search visits them, starting with `start` itself. The
+    /// costs of the edges are ignored. An `AgcError` of kind `NotFound` is
+    /// returned if `start` is not in the graph.
+    /// 
+    /// The nodes waiting to be visited are kept in a queue, so every node
+    /// one edge away from `start` is visited before any node two edges
+    /// away, and so on. The neighbours of a node are visited in no
+    /// particular order. This takes O(V + E) time.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     let edges = [('a', 'b'), ('b', 'c'), ('a', 'd'), ('d', 'e')];
+    ///     for (left, right) in edges.iter() {
+    ///         matrix.push(Edge::new(*left, *right, 1, EdgeKind::ToRight))
+    ///             .unwrap();
+    ///     }
+    ///     let order = matrix.bfs(&'a').unwrap();
+    ///     assert_eq!(order[0], 'a');
+    ///     // 'b' and 'd' are one edge away, 'c' and 'e' are two
+    ///     assert!(order[1..3].contains(&'b') && order[1..3].contains(&'d'));
+    ///     assert!(order[3..].contains(&'c') && order[3..].contains(&'e'));
+    /// ```
+    pub fn bfs(&self, start: &K) -> AgcResult<Vec<K>> {
+        self.check_nodes_exist(&[start])?;
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        let mut order = Vec::new();
+        visited.insert(start);
+        queue.push_back(start);
+        while let Some(node) = queue.pop_front() {
+            order.push(node.clone());
+            if let Some(adjacent) = self.get_adjacent(node) {
+                for next in adjacent.keys() {
+                    if visited.insert(next) {
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+        Ok(order)
+    }
+
+    /// List the nodes that can be reached from `start` in the order that a
+    /// depth-first search visits them, starting with `start` itself. The
+    /// costs of the edges are ignored. An `AgcError` of kind `NotFound` is
+    /// returned if `start` is not in the graph.
+    /// 
+    /// The search follows edges away from `start` for as long as it can find
+    /// a node it has not visited, and only goes back to try another edge
+    /// when it gets stuck. Instead of recursing, the neighbours still to be
+    /// tried for each node on the current path are kept on a stack, so the
+    /// depth of the graph is not limited by the size of the call stack. The
+    /// neighbours of a node are tried in no particular order, but in the same
+    /// order as `dfs_recursive`. This takes O(V + E) time.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     let edges = [('a', 'b'), ('b', 'c'), ('c', 'a'), ('c', 'd')];
+    ///     for (left, right) in edges.iter() {
+    ///         matrix.push(Edge::new(*left, *right, 1, EdgeKind::ToRight))
+    ///             .unwrap();
+    ///     }
+    ///     assert_eq!(matrix.dfs(&'a').unwrap(), vec!['a', 'b', 'c', 'd']);
+    /// ```
+    pub fn dfs(&self, start: &K) -> AgcResult<Vec<K>> {
+        self.check_nodes_exist(&[start])?;
+        let mut visited = HashSet::new();
+        let mut order = vec![start.clone()];
+        visited.insert(start);
+        let mut stack = vec![self.get_adjacent(start).into_iter().flatten()];
+        while let Some(adjacent) = stack.last_mut() {
+            match adjacent.next() {
+                Some((next, _)) => if visited.insert(next) {
+                    order.push(next.clone());
+                    stack.push(self.get_adjacent(next).into_iter().flatten());
+                },
+                None => {
+                    // Every neighbour of this node has been visited
+                    stack.pop();
+                }
+            }
+        }
+        Ok(order)
+    }
+
+    /// List the nodes that can be reached from `start` in the order that a
+    /// depth-first search visits them, like `dfs`, but by calling itself on
+    /// each neighbour instead of keeping a stack. This is shorter, but a long
+    /// enough path in the graph can overflow the call stack.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+    ///     let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    ///     let edges = [('a', 'b'), ('b', 'c'), ('c', 'a'), ('c', 'd')];
+    ///     for (left, right) in edges.iter() {
+    ///         matrix.push(Edge::new(*left, *right, 1, EdgeKind::ToRight))
+    ///             .unwrap();
+    ///     }
+    ///     let order = matrix.dfs_recursive(&'a').unwrap();
+    ///     assert_eq!(order, vec!['a', 'b', 'c', 'd']);
+    /// ```
+    pub fn dfs_recursive(&self, start: &K) -> AgcResult<Vec<K>> {
+        self.check_nodes_exist(&[start])?;
+        let mut visited = HashSet::new();
+        let mut order = Vec::new();
+        self.dfs_visit(start, &mut visited, &mut order);
+        Ok(order)
+    }
+
+    /// Visit `node` and then every node that can be reached from it which
+    /// has not been visited yet. Used by `dfs_recursive`.
+    fn dfs_visit<'a>(
+        &'a self,
+        node: &'a K,
+        visited: &mut HashSet<&'a K>,
+        order: &mut Vec<K>
+    ) {
+        visited.insert(node);
+        order.push(node.clone());
+        if let Some(adjacent) = self.get_adjacent(node) {
+            for next in adjacent.keys() {
+                if !visited.contains(next) {
+                    self.dfs_visit(next, visited, order);
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
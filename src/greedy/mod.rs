@@ -0,0 +1,76 @@
+//! Greedy algorithms, which build up an answer by always making the choice
+//! which looks best at the moment.
+
+use crate::{
+    error::{AgcError, AgcErrorKind, AgcResult},
+    sort::argsort_by
+};
+
+/// Find the largest total value that fits in a knapsack which can carry a
+/// total weight of `capacity`, where any fraction of an item can be taken.
+/// Item `i` weighs `weights[i]` and is worth `values[i]`, and taking a
+/// fraction of an item takes the same fraction of its weight and value.
+/// 
+/// Since items can be split, the best choice is always to take as much as
+/// possible of the item with the highest value per unit of weight, so the
+/// items are sorted by that ratio and taken in that order until the knapsack
+/// is full, taking only part of the last item if it does not fit. This takes
+/// O(n log n) time. This greedy choice does not work when items cannot be
+/// split (the 0/1 knapsack problem), because a light item with a high ratio
+/// can leave space that no other whole item fits into.
+/// 
+/// An `AgcError` of kind `InvalidArgument` is returned if `weights` and
+/// `values` have different lengths, if any weight is not positive or any
+/// value is negative, or if `capacity` is negative.
+/// 
+/// # Example
+/// ```
+///     use algocol::greedy::knapsack_fractional;
+///     let weights = [10.0, 20.0, 30.0];
+///     let values = [60.0, 100.0, 120.0];
+///     // All of the first 2 items and 2/3 of the last item
+///     let best = knapsack_fractional(&weights, &values, 50.0).unwrap();
+///     assert!((best - 240.0).abs() < 1e-9);
+/// ```
+pub fn knapsack_fractional(
+    weights: &[f64],
+    values: &[f64],
+    capacity: f64
+) -> AgcResult<f64> {
+    if weights.len() != values.len() {
+        return Err(AgcError::new(
+            AgcErrorKind::InvalidArgument,
+            format!(
+                "there are {} weights but {} values",
+                weights.len(),
+                values.len()
+            )
+        ));
+    }
+    // Comparisons with NaN are always false, so NaN is rejected too
+    if !weights.iter().all(|weight| *weight > 0.0)
+    || !values.iter().all(|value| *value >= 0.0)
+    || capacity.is_nan() || capacity < 0.0 {
+        return Err(AgcError::new(
+            AgcErrorKind::InvalidArgument,
+            "weights must be positive, and values and capacity must not be \
+            negative"
+        ));
+    }
+    let ratios = weights
+        .iter()
+        .zip(values.iter())
+        .map(|(weight, value)| value / weight)
+        .collect::<Vec<f64>>();
+    let mut remaining = capacity;
+    let mut total = 0.0;
+    for index in argsort_by(&ratios, false, |a, b| a.total_cmp(b)) {
+        if remaining <= 0.0 {
+            break;
+        }
+        let taken = weights[index].min(remaining);
+        total += ratios[index] * taken;
+        remaining -= taken;
+    }
+    Ok(total)
+}
\ No newline at end of file
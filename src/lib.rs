@@ -31,12 +31,35 @@
 //! 1. `i`: This function is iterative (as opposed to recursive)
 //! 2. `r`: This function is recursive
 //! 3. `f`: This function requires an auxiliary function
+//! 
+//! # Features
+//! 
+//! The building blocks of some algorithms, such as the `merge` function used
+//! by mergesort and the `partition` function used by quicksort, are only
+//! meant to be used by the other functions in this crate. They can be made
+//! public by enabling the `internals` feature.
+//! 
+#![cfg_attr(feature = "internals", doc = "```")]
+#![cfg_attr(not(feature = "internals"), doc = "```compile_fail")]
+//!     use algocol::sort::merge;
+//!     let mut array = [1, 3, 2, 4];
+//!     merge(&mut array[..], 0, 1, 3, true, |a, b| a.cmp(b)).unwrap();
+//!     assert_eq!(array, [1, 2, 3, 4]);
+//! ```
 
+pub mod arrays;
 pub mod binarysearch;
 pub mod error;
+pub mod geometry;
 pub mod graph;
+pub mod greedy;
 pub mod macros;
+pub mod numeric;
+pub mod search;
 pub mod sort;
+pub mod strings;
+pub mod structures;
+pub mod testing;
 pub mod traits;
 pub mod utils;
 
@@ -10,7 +10,7 @@
 /// ```
 ///     use algocol::alreadysorted;
 ///     pub const fn is_sorted<T>(slice: &[T]) -> bool {
-///         already_sorted!(slice.len() boolean)
+///         alreadysorted!(bool slice.len())
 ///     }
 /// ```
 #[macro_export]
@@ -58,4 +58,24 @@ macro_rules! agctryoption {
             ))
         }
     }
-}
\ No newline at end of file
+}
+
+/// Declare a function which is only part of the public API if the
+/// `internals` feature is enabled. Otherwise, the function is `pub(crate)`
+/// so that it can still be used by the other functions in this crate.
+/// 
+/// This is used for the building blocks of some algorithms, such as `merge`
+/// and `partition`, which are not very useful by themselves.
+macro_rules! internal {
+    ($(#[$meta: meta])* pub fn $($rest: tt)*) => {
+        #[cfg(feature = "internals")]
+        $(#[$meta])*
+        pub fn $($rest)*
+
+        #[cfg(not(feature = "internals"))]
+        $(#[$meta])*
+        pub(crate) fn $($rest)*
+    };
+}
+
+pub(crate) use internal;
\ No newline at end of file
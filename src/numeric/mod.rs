@@ -0,0 +1,39 @@
+//! Numerical algorithms.
+
+/// The number of elements that `pairwise_sum` adds up directly instead of
+/// splitting them up further.
+const PAIRWISE_CUTOFF: usize = 8;
+
+/// Add up the numbers in `slice` with pairwise (or cascade) summation, which
+/// is much more accurate than adding them up one by one.
+/// 
+/// Every time 2 floating point numbers are added, the result is rounded,
+/// which introduces a small error. When adding the numbers one by one (like
+/// `slice.iter().fold(0.0, |sum, x| sum + x)`), the running total keeps
+/// growing while the numbers added to it stay small, so more and more of
+/// each number is rounded away, and the worst-case error grows in proportion
+/// to `n`. Adding a tiny number to a large total can even lose the tiny
+/// number completely.
+/// 
+/// Pairwise summation splits the slice into 2 halves, adds up each half
+/// recursively, and then adds the 2 totals together, so numbers are mostly
+/// added to other numbers of a similar size. The worst-case error only grows
+/// in proportion to `log n`, while still taking O(n) time. Slices with at
+/// most 8 elements are added up one by one to avoid the cost of recursing
+/// all the way down.
+/// 
+/// # Example
+/// ```
+///     use algocol::numeric::pairwise_sum;
+///     let tenths = vec![0.1; 1000];
+///     let naive = tenths.iter().fold(0.0, |sum, x| sum + x);
+///     let pairwise = pairwise_sum(&tenths);
+///     assert!((pairwise - 100.0).abs() < (naive - 100.0).abs());
+/// ```
+pub fn pairwise_sum(slice: &[f64]) -> f64 {
+    if slice.len() <= PAIRWISE_CUTOFF {
+        return slice.iter().sum();
+    }
+    let middle = slice.len()/2;
+    pairwise_sum(&slice[..middle]) + pairwise_sum(&slice[middle..])
+}
\ No newline at end of file
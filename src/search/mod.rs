@@ -0,0 +1,77 @@
+//! Search functions for sequences which do not need to be sorted.
+//! 
+//! Every function in `binarysearch` expects the sequence to be sorted. The
+//! functions here check each element one by one from the front instead, so
+//! they work on any sequence, but take O(n) time.
+
+use std::convert::AsRef;
+
+pub use linearsearch as sc_linear_i;
+pub use linearsearch_by as sc_linear_if;
+pub use linearsearch_all_by as sc_linear_aif;
+
+/// Find the index of the first element in `sequence` which is equal to
+/// `item`. If there is no such element, `None` is returned.
+/// 
+/// # Example
+/// ```
+///     use algocol::search::linearsearch;
+///     let array = [4, 8, 1, 8, 3];
+///     assert_eq!(linearsearch(&array[..], &8), Some(1));
+///     assert_eq!(linearsearch(&array[..], &5), None);
+/// ```
+pub fn linearsearch<S, T>(sequence: &S, item: &T) -> Option<usize>
+where
+    S: AsRef<[T]> + ?Sized,
+    T: PartialEq
+{
+    linearsearch_by(sequence, |element| element == item)
+}
+
+/// Find the index of the first element in `sequence` for which `predicate`
+/// returns `true`. If there is no such element, `None` is returned.
+/// 
+/// # Example
+/// ```
+///     use algocol::search::linearsearch_by;
+///     let array = [4, 8, 1, 8, 3];
+///     assert_eq!(linearsearch_by(&array[..], |x| x % 2 == 1), Some(2));
+///     assert_eq!(linearsearch_by(&array[..], |x| *x > 10), None);
+/// ```
+pub fn linearsearch_by<F, S, T>(sequence: &S, predicate: F) -> Option<usize>
+where
+    S: AsRef<[T]> + ?Sized,
+    F: Fn(&T) -> bool
+{
+    for (index, element) in sequence.as_ref().iter().enumerate() {
+        if predicate(element) {
+            return Some(index);
+        }
+    }
+    None
+}
+
+/// Find the index of every element in `sequence` for which `predicate`
+/// returns `true`, from first to last. If there are no such elements, the
+/// `Vec` is empty.
+/// 
+/// # Example
+/// ```
+///     use algocol::search::linearsearch_all_by;
+///     let array = [4, 8, 1, 8, 3];
+///     assert_eq!(linearsearch_all_by(&array[..], |x| *x == 8), [1, 3]);
+///     assert!(linearsearch_all_by(&array[..], |x| *x > 10).is_empty());
+/// ```
+pub fn linearsearch_all_by<F, S, T>(sequence: &S, predicate: F) -> Vec<usize>
+where
+    S: AsRef<[T]> + ?Sized,
+    F: Fn(&T) -> bool
+{
+    let mut indices = Vec::new();
+    for (index, element) in sequence.as_ref().iter().enumerate() {
+        if predicate(element) {
+            indices.push(index);
+        }
+    }
+    indices
+}
\ No newline at end of file
@@ -7,7 +7,7 @@ use std::{
 use crate::{
     alreadysorted,
     error::AgcResult,
-    utils::priority
+    utils::{cmp::debug_check_swap, priority}
 };
 
 /// This function sorts a slice using the bubblesort algorithm, where each
@@ -61,13 +61,42 @@ pub fn bubblesort_by<F, S, T>(
     ascending: bool,
     compare: F
 ) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    bubblesort_counted_by(sequence, ascending, compare)
+        .map(|(_, sequence)| sequence)
+}
+
+/// Sort a slice with bubble sort like `bubblesort_by`, but also return the
+/// number of swaps made. Each swap fixes exactly 1 pair of elements which
+/// are in the wrong order, so this is the number of inversions in the slice,
+/// which is (n^2 - n)/2 for a slice sorted in the opposite order.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::bubblesort::bubblesort_counted_by;
+///     let mut array = [2, 1, 3, 5, 4];
+///     let (swaps, sorted) = bubblesort_counted_by(
+///         &mut array[..], true, |a, b| a.cmp(b)
+///     ).unwrap();
+///     assert_eq!(sorted, [1, 2, 3, 4, 5]);
+///     assert_eq!(swaps, 2);
+/// ```
+pub fn bubblesort_counted_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<(usize, &mut [T])>
 where
     S: AsMut<[T]> + ?Sized,
     F: Fn(&T, &T) -> Ordering + Copy
 {   
     let sequence = sequence.as_mut();
     let length = sequence.len();
-    alreadysorted!(result length, return sequence);
+    let mut swaps = 0;
+    alreadysorted!(result length, return (swaps, sequence));
     let mut sorted = false;
     if ascending {
         while !sorted {
@@ -77,6 +106,8 @@ where
                     compare(&sequence[index-1], &sequence[index])
                 ) {
                     sequence.swap(index, index-1);
+                    debug_check_swap(sequence, index-1, index, true, compare);
+                    swaps += 1;
                     sorted = false;
                 }
             }
@@ -89,10 +120,12 @@ where
                     compare(&sequence[index-1], &sequence[index])
                 ) {
                     sequence.swap(index, index-1);
+                    debug_check_swap(sequence, index-1, index, false, compare);
+                    swaps += 1;
                     sorted = false;
                 }
             }
         }
     }
-    Ok(sequence)
+    Ok((swaps, sequence))
 }
\ No newline at end of file
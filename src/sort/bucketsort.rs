@@ -0,0 +1,90 @@
+//! Stores the bucket sort functions.
+
+use std::{
+    cmp::Ordering,
+    convert::AsMut
+};
+use crate::{
+    error::{AgcError, AgcErrorKind, AgcResult},
+    sort::insertionsort::insertionsort_by
+};
+
+/// This function sorts a slice using the bucket sort algorithm. `key_fn`
+/// maps each element to a number in `[0, 1)`, which decides which of the
+/// `bucket_count` buckets it goes in, with the first bucket holding the
+/// elements with keys in `[0, 1/bucket_count)` and so on. Each bucket is
+/// then sorted with insertion sort, and the buckets are joined back together
+/// in order. For the result to be sorted, an element which comes before
+/// another according to `compare` must not have a larger key. You can
+/// choose whether to sort in ascending or descending order by toggling the
+/// `ascending` argument between `true` or `false`.
+/// 
+/// When the keys are spread evenly over `[0, 1)` and there are about as many
+/// buckets as elements, each bucket only holds a few elements, so this takes
+/// O(n) time on average. If most elements land in the same bucket, this
+/// becomes insertion sort and takes O(n^2) time.
+/// 
+/// An `AgcError` of kind `OutOfBounds` is returned if `key_fn` returns a
+/// number outside of `[0, 1)` (including NaN), and one of kind
+/// `InvalidArgument` is returned if `bucket_count` is 0. In both cases,
+/// `sequence` is left untouched.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::bucketsort::bucketsort_by;
+///     let mut array = [0.42, 0.32, 0.23, 0.52, 0.25, 0.47, 0.51];
+///     bucketsort_by(
+///         &mut array[..], true, 5, |x| *x, |a, b| a.partial_cmp(b).unwrap()
+///     ).unwrap();
+///     assert_eq!(array, [0.23, 0.25, 0.32, 0.42, 0.47, 0.51, 0.52]);
+/// ```
+pub fn bucketsort_by<F, K, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    bucket_count: usize,
+    key_fn: K,
+    compare: F
+) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    T: Clone,
+    K: Fn(&T) -> f64,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_mut();
+    if bucket_count == 0 {
+        return Err(AgcError::new(
+            AgcErrorKind::InvalidArgument,
+            "there must be at least 1 bucket"
+        ));
+    }
+    let mut indices = Vec::with_capacity(sequence.len());
+    for element in sequence.iter() {
+        let key = key_fn(element);
+        if !(0.0..1.0).contains(&key) {
+            return Err(AgcError::new(
+                AgcErrorKind::OutOfBounds,
+                format!("key ({}) is not in [0, 1)", key)
+            ));
+        }
+        // Rounding could give `bucket_count` for keys just below 1
+        let index = (key * bucket_count as f64) as usize;
+        indices.push(index.min(bucket_count - 1));
+    }
+    let mut buckets = vec![Vec::new(); bucket_count];
+    for (element, index) in sequence.iter().zip(indices) {
+        buckets[index].push(element.clone());
+    }
+    if !ascending {
+        buckets.reverse();
+    }
+    let mut slots = sequence.iter_mut();
+    for bucket in buckets.iter_mut() {
+        insertionsort_by(bucket, ascending, compare)?;
+        // `drain` goes first so that no slot is skipped when it runs out
+        for (element, slot) in bucket.drain(..).zip(slots.by_ref()) {
+            *slot = element;
+        }
+    }
+    Ok(sequence)
+}
\ No newline at end of file
@@ -0,0 +1,143 @@
+//! Stores the cocktail shaker sort functions.
+
+use std::{
+    convert::AsMut,
+    cmp::{Ord, Ordering}
+};
+use crate::{
+    alreadysorted,
+    error::AgcResult,
+    utils::{cmp::debug_check_swap, priority}
+};
+
+/// This function sorts a slice using the cocktail shaker sort algorithm,
+/// which is a bubble sort that goes back and forth. Each pass first sweeps
+/// from left to right, swapping adjacent elements which are out of order so
+/// that the largest element ends up at the end, before sweeping from right
+/// to left to bring the smallest element to the front. The elements which
+/// have been moved to either end are skipped in the next pass. You can
+/// choose whether to sort in ascending or descending order by toggling the
+/// `ascending` argument between `true` or `false`.
+/// 
+/// This algorithm's time complexity is O(n^2), but it stops as soon as a
+/// sweep makes no swaps, so slices which are almost sorted take fewer passes
+/// than they would with bubble sort.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::cocktailsort::cocktailsort;
+///     let mut array = [2, 3, 4, 5, 1];
+///     cocktailsort(&mut array[..], true).unwrap();
+///     assert_eq!(array, [1, 2, 3, 4, 5]);
+/// ```
+pub fn cocktailsort<S, T>(
+    sequence: &mut S, ascending: bool
+) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    T: Ord
+{
+    cocktailsort_by(sequence, ascending, |a, b| a.cmp(b))
+}
+
+/// This function sorts a slice using the cocktail shaker sort algorithm,
+/// which is a bubble sort that goes back and forth. Each pass first sweeps
+/// from left to right, swapping adjacent elements which are out of order so
+/// that the largest element ends up at the end, before sweeping from right
+/// to left to bring the smallest element to the front. The elements which
+/// have been moved to either end are skipped in the next pass. You can
+/// choose whether to sort in ascending or descending order by toggling the
+/// `ascending` argument between `true` or `false`. This function requires
+/// another function to tell it the order whether 1 element is larger or
+/// smaller than the other element.
+/// 
+/// This algorithm's time complexity is O(n^2), but it stops as soon as a
+/// sweep makes no swaps, so slices which are almost sorted take fewer passes
+/// than they would with bubble sort.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::cocktailsort::cocktailsort_by;
+///     let mut array = [2, 3, 4, 5, 1];
+///     cocktailsort_by(
+///         &mut array[..], false, |a, b| a.cmp(b)
+///     ).unwrap();
+///     assert_eq!(array, [5, 4, 3, 2, 1]);
+/// ```
+pub fn cocktailsort_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    cocktailsort_counted_by(sequence, ascending, compare)
+        .map(|(_, sequence)| sequence)
+}
+
+/// Sort a slice with cocktail shaker sort like `cocktailsort_by`, but also
+/// return the number of swaps made. Like bubble sort, each swap fixes
+/// exactly 1 pair of adjacent elements which are in the wrong order, so this
+/// is the number of inversions in the slice.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::cocktailsort::cocktailsort_counted_by;
+///     let mut array = [2, 3, 4, 5, 1];
+///     let (swaps, sorted) = cocktailsort_counted_by(
+///         &mut array[..], true, |a, b| a.cmp(b)
+///     ).unwrap();
+///     assert_eq!(sorted, [1, 2, 3, 4, 5]);
+///     assert_eq!(swaps, 4);
+/// ```
+pub fn cocktailsort_counted_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<(usize, &mut [T])>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_mut();
+    let length = sequence.len();
+    let mut swaps = 0;
+    alreadysorted!(result length, return (swaps, sequence));
+    let out_of_order = |a: &T, b: &T| if ascending {
+        priority::is_gt(compare(a, b))
+    } else {
+        priority::is_lt(compare(a, b))
+    };
+    // Every element before `start` and from `end` onwards is in place
+    let mut start = 0;
+    let mut end = length;
+    let mut sorted = false;
+    while !sorted {
+        sorted = true;
+        for index in start+1..end {
+            if out_of_order(&sequence[index-1], &sequence[index]) {
+                sequence.swap(index, index-1);
+                debug_check_swap(sequence, index-1, index, ascending, compare);
+                swaps += 1;
+                sorted = false;
+            }
+        }
+        end -= 1;
+        if sorted {
+            break;
+        }
+        sorted = true;
+        for index in (start+1..end).rev() {
+            if out_of_order(&sequence[index-1], &sequence[index]) {
+                sequence.swap(index, index-1);
+                debug_check_swap(sequence, index-1, index, ascending, compare);
+                swaps += 1;
+                sorted = false;
+            }
+        }
+        start += 1;
+    }
+    Ok((swaps, sequence))
+}
\ No newline at end of file
@@ -0,0 +1,128 @@
+//! Stores the comb sort functions.
+
+use std::{
+    convert::AsMut,
+    cmp::{Ord, Ordering}
+};
+use crate::{
+    alreadysorted,
+    error::AgcResult,
+    utils::{cmp::debug_check_swap, priority}
+};
+
+/// This function sorts a slice using the comb sort algorithm. Comb sort
+/// works like bubble sort, except that it compares and swaps elements which
+/// are a gap apart instead of adjacent elements. The gap starts at the
+/// length of the slice and is divided by 1.3 after every pass until it
+/// becomes 1, at which point comb sort becomes bubble sort and stops once a
+/// pass makes no swaps. Small elements near the end of the slice (turtles)
+/// only move 1 index per pass in bubble sort, but comb sort moves them
+/// towards the front in far fewer passes. You can choose whether to sort in
+/// ascending or descending order by toggling the `ascending` argument
+/// between `true` or `false`.
+/// 
+/// This algorithm's time complexity is O(n^2) in the worst case, but it is
+/// usually much faster than bubble sort.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::combsort::combsort;
+///     let mut array = [2, 3, 4, 5, 1];
+///     combsort(&mut array[..], true).unwrap();
+///     assert_eq!(array, [1, 2, 3, 4, 5]);
+/// ```
+pub fn combsort<S, T>(
+    sequence: &mut S, ascending: bool
+) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    T: Ord
+{
+    combsort_by(sequence, ascending, |a, b| a.cmp(b))
+}
+
+/// This function sorts a slice using the comb sort algorithm. Comb sort
+/// works like bubble sort, except that it compares and swaps elements which
+/// are a gap apart instead of adjacent elements. The gap starts at the
+/// length of the slice and is divided by 1.3 after every pass until it
+/// becomes 1, at which point comb sort becomes bubble sort and stops once a
+/// pass makes no swaps. You can choose whether to sort in ascending or
+/// descending order by toggling the `ascending` argument between `true` or
+/// `false`. This function requires another function to tell it the order
+/// whether 1 element is larger or smaller than the other element.
+/// 
+/// This algorithm's time complexity is O(n^2) in the worst case, but it is
+/// usually much faster than bubble sort.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::combsort::combsort_by;
+///     let mut array = [2, 3, 4, 5, 1];
+///     combsort_by(
+///         &mut array[..], false, |a, b| a.cmp(b)
+///     ).unwrap();
+///     assert_eq!(array, [5, 4, 3, 2, 1]);
+/// ```
+pub fn combsort_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    combsort_counted_by(sequence, ascending, compare)
+        .map(|(_, sequence)| sequence)
+}
+
+/// Sort a slice with comb sort like `combsort_by`, but also return the
+/// number of swaps made. Swapping elements a gap apart can fix several
+/// inversions at once, so this is usually far fewer than the number of
+/// swaps that bubble sort would make on the same slice.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::combsort::combsort_counted_by;
+///     let mut array = [9, 8, 7, 6, 5, 4, 3, 2, 1];
+///     let (swaps, sorted) = combsort_counted_by(
+///         &mut array[..], true, |a, b| a.cmp(b)
+///     ).unwrap();
+///     assert_eq!(sorted, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+///     // Bubble sort would swap every one of the 36 pairs
+///     assert!(swaps < 36);
+/// ```
+pub fn combsort_counted_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<(usize, &mut [T])>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_mut();
+    let length = sequence.len();
+    let mut swaps = 0;
+    alreadysorted!(result length, return (swaps, sequence));
+    let mut gap = length;
+    let mut sorted = false;
+    while !sorted || gap > 1 {
+        // Dividing by 1.3 without going through floating point numbers
+        gap = (gap * 10 / 13).max(1);
+        sorted = true;
+        for index in gap..length {
+            let ordering = compare(&sequence[index-gap], &sequence[index]);
+            if (ascending && priority::is_gt(ordering))
+            || (!ascending && priority::is_lt(ordering)) {
+                sequence.swap(index, index-gap);
+                debug_check_swap(
+                    sequence, index-gap, index, ascending, compare
+                );
+                swaps += 1;
+                sorted = false;
+            }
+        }
+    }
+    Ok((swaps, sequence))
+}
\ No newline at end of file
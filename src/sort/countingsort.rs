@@ -0,0 +1,104 @@
+//! Stores the counting sort functions.
+//! 
+//! Unlike the other sorting algorithms in this module, counting sort does
+//! not compare elements with each other. Instead, it counts how many times
+//! each value appears in the slice and writes every value back that many
+//! times, from the smallest value to the largest. This takes O(n + k) time,
+//! where `k` is the difference between the largest and smallest values in
+//! the slice, which beats the O(n log n) lower bound of comparison sorts when
+//! `k` is small. The trade-off is O(k) extra memory for the counts, so it is
+//! only suitable for integers which are close together. The values are
+//! rebuilt from their counts, so there is no need for the sort to be stable.
+
+use std::convert::AsMut;
+use crate::error::{AgcError, AgcErrorKind, AgcResult};
+
+/// The largest number of counts that `countingsort` will allocate, which is
+/// the difference between the largest and smallest values plus 1.
+pub const MAX_COUNTING_RANGE: usize = 1 << 24;
+
+/// Integers which can be sorted by `countingsort`. Each value is mapped to a
+/// `usize`, which is used to find its count, and can be rebuilt from that
+/// `usize`.
+pub trait CountingKey: Copy + Ord {
+    /// Convert this value to the index of its count.
+    fn to_index(self) -> usize;
+
+    /// Convert the index of a count back into the value it counts.
+    fn from_index(index: usize) -> Self;
+}
+
+macro_rules! impl_counting_key {
+    ($($t: ty),*) => {
+        $(
+            impl CountingKey for $t {
+                fn to_index(self) -> usize {
+                    self as usize
+                }
+
+                fn from_index(index: usize) -> Self {
+                    index as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_counting_key!(u8, u16, u32, usize);
+
+/// Sort a slice of integers with counting sort. The smallest and largest
+/// values are found first, and a count is kept for every value between
+/// them. After every element has been counted, the values are written back
+/// in ascending or descending order depending on `ascending`.
+/// 
+/// An `AgcError` of kind `OutOfBounds` is returned and `sequence` is left
+/// untouched if there are more than `MAX_COUNTING_RANGE` values between the
+/// smallest and largest values, since that many counts would take up too
+/// much memory.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::countingsort::countingsort;
+///     let mut array = [3u8, 1, 4, 1, 5, 9, 2, 6];
+///     countingsort(&mut array[..], true).unwrap();
+///     assert_eq!(array, [1, 1, 2, 3, 4, 5, 6, 9]);
+/// ```
+pub fn countingsort<S, T>(
+    sequence: &mut S,
+    ascending: bool
+) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    T: CountingKey
+{
+    let sequence = sequence.as_mut();
+    let (min, max) = match (sequence.iter().min(), sequence.iter().max()) {
+        (Some(min), Some(max)) => (min.to_index(), max.to_index()),
+        _ => return Ok(sequence)
+    };
+    if max - min >= MAX_COUNTING_RANGE {
+        return Err(AgcError::new(
+            AgcErrorKind::OutOfBounds,
+            format!(
+                "the range of values ({}) is larger than {}",
+                max - min,
+                MAX_COUNTING_RANGE
+            )
+        ));
+    }
+    let mut counts = vec![0usize; max - min + 1];
+    for value in sequence.iter() {
+        counts[value.to_index() - min] += 1;
+    }
+    let mut index = 0;
+    for step in 0..counts.len() {
+        let offset = if ascending {step} else {counts.len() - 1 - step};
+        let value = T::from_index(min + offset);
+        let count = counts[offset];
+        for slot in sequence[index..index+count].iter_mut() {
+            *slot = value;
+        }
+        index += count;
+    }
+    Ok(sequence)
+}
\ No newline at end of file
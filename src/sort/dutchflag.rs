@@ -0,0 +1,96 @@
+//! Stores the Dutch national flag sort.
+
+use std::{
+    cmp::Ordering,
+    convert::AsMut
+};
+
+/// Sort a slice whose elements fall into 3 categories, using Edsger
+/// Dijkstra's solution to the Dutch national flag problem. `classify` tells
+/// the function which category an element belongs to: `Ordering::Less` for
+/// the bottom band, `Ordering::Equal` for the middle band and
+/// `Ordering::Greater` for the top band. The bands are placed in that order
+/// from the front of the slice to the back. Elements in the same band are not
+/// kept in their original order.
+/// 
+/// The slice is split into 4 regions which are kept track of with 3 indices:
+/// `[0, low)` holds the bottom band, `[low, middle)` the middle band,
+/// `[middle, high)` the elements not yet classified and `[high, length)` the
+/// top band. Each step either moves `middle` forward or `high` backward, so
+/// `classify` is called exactly once per element and at most 1 swap is made
+/// per element. This algorithm's time complexity is O(n).
+/// 
+/// The indices where the middle band and the top band start are returned.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::dutchflag::dutch_flag_sort_by;
+///     let mut array = [2, 0, 1, 2, 1, 0];
+///     let bands = dutch_flag_sort_by(&mut array[..], |x| x.cmp(&1));
+///     assert_eq!(array, [0, 0, 1, 1, 2, 2]);
+///     assert_eq!(bands, (2, 4));
+/// ```
+pub fn dutch_flag_sort_by<F, S, T>(
+    sequence: &mut S,
+    classify: F
+) -> (usize, usize)
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T) -> Ordering
+{
+    dutch_flag_sort_counted_by(sequence, classify).1
+}
+
+/// Sort a slice into 3 bands like `dutch_flag_sort_by`, but also return the
+/// number of swaps made. Swapping an element with itself is skipped and not
+/// counted. Every swap either puts an element into the bottom band or the
+/// top band for good, so this is never more than the length of the slice.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::dutchflag::dutch_flag_sort_counted_by;
+///     let mut array = [0, 2, 1, 1, 0];
+///     let (swaps, bands) = dutch_flag_sort_counted_by(
+///         &mut array[..], |x| x.cmp(&1)
+///     );
+///     assert_eq!(array, [0, 0, 1, 1, 2]);
+///     assert_eq!(bands, (2, 4));
+///     assert_eq!(swaps, 1);
+/// ```
+pub fn dutch_flag_sort_counted_by<F, S, T>(
+    sequence: &mut S,
+    classify: F
+) -> (usize, (usize, usize))
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T) -> Ordering
+{
+    let sequence = sequence.as_mut();
+    let mut low = 0;
+    let mut middle = 0;
+    let mut high = sequence.len();
+    let mut swaps = 0;
+    while middle < high {
+        match classify(&sequence[middle]) {
+            Ordering::Less => {
+                if low != middle {
+                    sequence.swap(low, middle);
+                    swaps += 1;
+                }
+                low += 1;
+                middle += 1;
+            },
+            Ordering::Equal => middle += 1,
+            Ordering::Greater => {
+                // The element swapped in from `high` has not been classified
+                // yet, so `middle` stays where it is.
+                high -= 1;
+                if middle != high {
+                    sequence.swap(middle, high);
+                    swaps += 1;
+                }
+            }
+        }
+    }
+    (swaps, (low, high))
+}
\ No newline at end of file
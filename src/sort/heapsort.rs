@@ -0,0 +1,111 @@
+//! Stores the heap sort functions.
+
+use std::{
+    cmp::{Ord, Ordering},
+    convert::AsMut
+};
+use crate::{
+    alreadysorted,
+    error::AgcResult,
+    structures::heap::{repair_heap_by, sift_down_by}
+};
+
+/// This function sorts a slice using the heap sort algorithm. The slice is
+/// first turned into a binary heap (see `structures::heap`) whose root is the
+/// element that should go last. The root is then swapped with the last
+/// element of the heap, the heap shrinks by 1 so that the root stays there,
+/// and the new root is sifted down to fix the heap. This repeats until the
+/// heap is empty. You can choose whether to sort in ascending or descending
+/// order by toggling the `ascending` argument between `true` or `false`.
+/// 
+/// This algorithm's time complexity is O(n log n) in every case, and it
+/// sorts the slice in-place, but it is not stable.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::heapsort::heapsort;
+///     let mut array = [5, 1, 4, 2, 3];
+///     heapsort(&mut array[..], true).unwrap();
+///     assert_eq!(array, [1, 2, 3, 4, 5]);
+/// ```
+pub fn heapsort<S, T>(
+    sequence: &mut S,
+    ascending: bool
+) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    T: Ord
+{
+    heapsort_by(sequence, ascending, |a, b| a.cmp(b))
+}
+
+/// This function sorts a slice using the heap sort algorithm. The slice is
+/// first turned into a binary heap (see `structures::heap`) whose root is the
+/// element that should go last. The root is then swapped with the last
+/// element of the heap, the heap shrinks by 1 so that the root stays there,
+/// and the new root is sifted down to fix the heap. This repeats until the
+/// heap is empty. You can choose whether to sort in ascending or descending
+/// order by toggling the `ascending` argument between `true` or `false`. This
+/// function requires another function to tell it the order whether 1
+/// element is larger or smaller than the other element.
+/// 
+/// This algorithm's time complexity is O(n log n) in every case, and it
+/// sorts the slice in-place, but it is not stable.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::heapsort::heapsort_by;
+///     let mut array = [5, 1, 4, 2, 3];
+///     heapsort_by(&mut array[..], false, |a, b| a.cmp(b)).unwrap();
+///     assert_eq!(array, [5, 4, 3, 2, 1]);
+/// ```
+pub fn heapsort_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    heapsort_counted_by(sequence, ascending, compare)
+        .map(|(_, sequence)| sequence)
+}
+
+/// Sort a slice with heap sort like `heapsort_by`, but also return the
+/// number of swaps made, counting the swaps made while building the heap,
+/// moving each root to the end and sifting the new roots down.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::heapsort::heapsort_counted_by;
+///     let mut array = [1, 2, 3];
+///     let (swaps, sorted) = heapsort_counted_by(
+///         &mut array[..], true, |a, b| a.cmp(b)
+///     ).unwrap();
+///     assert_eq!(sorted, [1, 2, 3]);
+///     assert_eq!(swaps, 4);
+/// ```
+pub fn heapsort_counted_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<(usize, &mut [T])>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_mut();
+    let length = sequence.len();
+    alreadysorted!(result length, return (0, sequence));
+    // A max-heap keeps the largest element at the root, which goes last when
+    // sorting in ascending order.
+    let is_max_heap = ascending;
+    let mut swaps = repair_heap_by(sequence, is_max_heap, compare);
+    for end in (1..length).rev() {
+        sequence.swap(0, end);
+        swaps += 1;
+        swaps += sift_down_by(&mut sequence[..end], 0, is_max_heap, compare);
+    }
+    Ok((swaps, sequence))
+}
\ No newline at end of file
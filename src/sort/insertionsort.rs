@@ -6,8 +6,13 @@ use std::{
 };
 use crate::{
     alreadysorted,
+    binarysearch::binarysearch_unchecked_by,
     error::AgcResult,
-    utils::priority
+    utils::{
+        cmp::debug_check_swap,
+        priority,
+        slice::transfer_element
+    }
 };
 
 /// This function sorts a slice using the insertion sort algorithm. In this
@@ -69,6 +74,73 @@ pub fn insertionsort_by<F, S, T>(
     ascending: bool,
     compare: F
 ) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    insertionsort_counted_by(sequence, ascending, compare)
+        .map(|(_, sequence)| sequence)
+}
+
+/// Sort a slice with insertion sort like `insertionsort_by`, but also return
+/// the number of swaps made. Each element is swapped leftwards past every
+/// element before it that should come after it, so this is the number of
+/// inversions in the slice, and 0 for a slice which is already sorted.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::insertionsort::insertionsort_counted_by;
+///     let mut array = [1, 2, 5, 3, 4];
+///     let (swaps, sorted) = insertionsort_counted_by(
+///         &mut array[..], true, |a, b| a.cmp(b)
+///     ).unwrap();
+///     assert_eq!(sorted, [1, 2, 3, 4, 5]);
+///     assert_eq!(swaps, 2);
+/// ```
+pub fn insertionsort_counted_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<(usize, &mut [T])>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_mut();
+    let length = sequence.len();
+    alreadysorted!(result length, return (0, sequence));
+    let swaps = gapped_insertion_pass(sequence, 1, ascending, compare);
+    Ok((swaps, sequence))
+}
+
+/// This function sorts a slice using binary insertion sort. Like insertion
+/// sort, each element is inserted into the sorted sub-slice on its left, but
+/// the location to insert it at is found with a binary search, and the
+/// element is then moved there in a single rotation. You can choose whether
+/// to sort in ascending or descending order by toggling the `ascending`
+/// argument between `true` or `false`. This function requires another
+/// function to tell it the order whether 1 element is larger or smaller than
+/// the other element.
+/// 
+/// Each element only needs O(log n) comparisons to find where it goes, but
+/// moving it there still shifts up to n elements, so this algorithm's time
+/// complexity is still O(n^2). This makes it faster than `insertionsort_by`
+/// when comparing elements is expensive. Elements are inserted after any
+/// elements equal to them, so the sort is stable.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::insertionsort::binary_insertionsort_by;
+///     let mut array = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+///     binary_insertionsort_by(&mut array[..], true, |a, b| a.0.cmp(&b.0))
+///         .unwrap();
+///     assert_eq!(array, [(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+/// ```
+pub fn binary_insertionsort_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<&mut [T]>
 where
     S: AsMut<[T]> + ?Sized,
     F: Fn(&T, &T) -> Ordering + Copy
@@ -76,21 +148,123 @@ where
     let sequence = sequence.as_mut();
     let length = sequence.len();
     alreadysorted!(result length, return sequence);
+    // Treating the element being inserted as coming after the elements equal
+    // to it makes the binary search return the rightmost insertion point.
+    let after_equal = |item: &T, element: &T| match compare(item, element) {
+        Ordering::Equal if ascending => Ordering::Greater,
+        Ordering::Equal => Ordering::Less,
+        ordering => ordering
+    };
     for index in 1..length {
-        let mut location = index - 1;
+        let location = binarysearch_unchecked_by(
+            &sequence[..index],
+            &sequence[index],
+            ascending,
+            after_equal
+        );
+        transfer_element(sequence, index, location)?;
+    }
+    Ok(sequence)
+}
+
+/// Sort a slice with insertion sort like `insertionsort_by`, but give up
+/// once `compare` has been called `max_comparisons` times. The first value
+/// returned is `true` if the slice was sorted within the budget and `false`
+/// if the budget ran out first.
+/// 
+/// This is an anytime algorithm, which can be stopped at any point and still
+/// leave something useful behind. Insertion sort grows a sorted run at the
+/// front of the slice one element at a time, so if the budget runs out, the
+/// slice holds the same elements as before with a sorted prefix, apart from
+/// the element which was being inserted. A slice which is already nearly
+/// sorted needs few comparisons, so it is likely to be finished in time.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::insertionsort::sort_budgeted_by;
+///     let compare = |a: &i32, b: &i32| a.cmp(b);
+///     let mut array = [5, 4, 3, 2, 1];
+///     let (done, _) = sort_budgeted_by(&mut array, true, 3, compare)
+///         .unwrap();
+///     assert!(!done);
+///     let (done, sorted) = sort_budgeted_by(&mut array, true, 20, compare)
+///         .unwrap();
+///     assert!(done);
+///     assert_eq!(sorted, [1, 2, 3, 4, 5]);
+/// ```
+pub fn sort_budgeted_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    max_comparisons: usize,
+    compare: F
+) -> AgcResult<(bool, &mut [T])>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_mut();
+    let length = sequence.len();
+    alreadysorted!(result length, return (true, sequence));
+    let mut comparisons = 0;
+    for index in 1..length {
+        let mut location = index;
+        while location > 0 {
+            if comparisons == max_comparisons {
+                return Ok((false, sequence));
+            }
+            comparisons += 1;
+            let ordering = compare(&sequence[location-1], &sequence[location]);
+            let out_of_order = if ascending {
+                priority::is_gt(ordering)
+            } else {
+                priority::is_lt(ordering)
+            };
+            if !out_of_order {
+                break;
+            }
+            sequence.swap(location-1, location);
+            debug_check_swap(
+                sequence, location-1, location, ascending, compare
+            );
+            location -= 1;
+        }
+    }
+    Ok((true, sequence))
+}
+
+/// Insertion sort every sub-sequence made up of elements which are `gap`
+/// indices apart from each other. When `gap` is 1, this sorts the whole
+/// slice. This is used by insertion sort and shell sort. The number of swaps
+/// made is returned.
+pub(crate) fn gapped_insertion_pass<F, T>(
+    sequence: &mut [T],
+    gap: usize,
+    ascending: bool,
+    compare: F
+) -> usize
+where
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let mut swaps = 0;
+    for index in gap..sequence.len() {
+        let mut location = index - gap;
         while if ascending {
             priority::is_gt(
-                compare(&sequence[location], &sequence[location+1])
+                compare(&sequence[location], &sequence[location+gap])
             )
         } else {
             priority::is_lt(
-                compare(&sequence[location], &sequence[location+1])
+                compare(&sequence[location], &sequence[location+gap])
             )
         } {
-            sequence.swap(location, location+1);
-            if location == 0 {break;}
-            location -= 1;
+            sequence.swap(location, location+gap);
+            debug_check_swap(
+                sequence, location, location+gap, ascending, compare
+            );
+            swaps += 1;
+            if location < gap {break;}
+            location -= gap;
         }
     }
-    Ok(sequence)
+    swaps
 }
\ No newline at end of file
@@ -0,0 +1,150 @@
+//! Stores the introsort functions.
+
+use std::{
+    cmp::{Ord, Ordering},
+    convert::AsMut
+};
+use crate::{
+    alreadysorted,
+    error::AgcResult,
+    sort::{
+        heapsort::heapsort_counted_by,
+        insertionsort::insertionsort_counted_by,
+        quicksort::counted_partition
+    }
+};
+
+/// Slices with fewer elements than this are sorted with insertion sort by
+/// introsort.
+pub const INTROSORT_THRESHOLD: usize = 16;
+
+/// Introsort (introspective sort) is quicksort which keeps an eye on itself.
+/// Quicksort is fast on most slices, but a bad choice of pivots, such as the
+/// last element of a slice which is already sorted, makes it take O(n^2)
+/// time. Introsort starts out as quicksort, but once it has recursed more
+/// than `2 * log2(n)` times, it sorts the sub-slice it is on with heap sort
+/// instead, which always takes O(n log n) time. Sub-slices with fewer than
+/// `INTROSORT_THRESHOLD` elements are sorted with insertion sort, which is
+/// faster on such small slices. You can choose whether to sort in ascending
+/// or descending order by toggling the `ascending` argument between `true` or
+/// `false`.
+/// 
+/// This algorithm's time complexity is O(n log n) in the worst case. It is
+/// the recommended sorting algorithm in this module when you do not need a
+/// stable sort.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::introsort::introsort;
+///     let mut array = (0..1000).collect::<Vec<i32>>();
+///     introsort(&mut array[..], false).unwrap();
+///     assert_eq!(array, (0..1000).rev().collect::<Vec<i32>>());
+/// ```
+pub fn introsort<S, T>(
+    sequence: &mut S,
+    ascending: bool
+) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    T: Ord
+{
+    introsort_by(sequence, ascending, |a, b| a.cmp(b))
+}
+
+/// Introsort (introspective sort) is quicksort which keeps an eye on itself.
+/// Quicksort is fast on most slices, but a bad choice of pivots, such as the
+/// last element of a slice which is already sorted, makes it take O(n^2)
+/// time. Introsort starts out as quicksort, but once it has recursed more
+/// than `2 * log2(n)` times, it sorts the sub-slice it is on with heap sort
+/// instead, which always takes O(n log n) time. Sub-slices with fewer than
+/// `INTROSORT_THRESHOLD` elements are sorted with insertion sort, which is
+/// faster on such small slices. This function requires another function to
+/// tell it the order whether 1 element is larger or smaller than the other
+/// element.
+/// 
+/// This algorithm's time complexity is O(n log n) in the worst case. It is
+/// the recommended sorting algorithm in this module when you do not need a
+/// stable sort.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::introsort::introsort_by;
+///     let mut array = [5, 1, 4, 2, 3];
+///     introsort_by(&mut array[..], true, |a, b| a.cmp(b)).unwrap();
+///     assert_eq!(array, [1, 2, 3, 4, 5]);
+/// ```
+pub fn introsort_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    introsort_counted_by(sequence, ascending, compare)
+        .map(|(_, sequence)| sequence)
+}
+
+/// Sort a slice with introsort like `introsort_by`, but also return the
+/// number of swaps made, whether they were made by the partitions, heap sort
+/// or insertion sort.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::introsort::introsort_counted_by;
+///     let mut array = [2, 1, 3, 4, 5];
+///     let (swaps, sorted) = introsort_counted_by(
+///         &mut array[..], true, |a, b| a.cmp(b)
+///     ).unwrap();
+///     assert_eq!(sorted, [1, 2, 3, 4, 5]);
+///     assert_eq!(swaps, 1);
+/// ```
+pub fn introsort_counted_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<(usize, &mut [T])>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_mut();
+    let length = sequence.len();
+    let mut swaps = 0;
+    alreadysorted!(result length, return (swaps, sequence));
+    let log2 = (usize::BITS - 1 - length.leading_zeros()) as usize;
+    introsort_slice(sequence, 2 * log2, ascending, compare, &mut swaps)?;
+    Ok((swaps, sequence))
+}
+
+/// Sort `slice` with introsort, switching to heap sort if quicksort has to
+/// recurse more than `depth` more times. The number of swaps made is added
+/// to `swaps`.
+fn introsort_slice<F, T>(
+    slice: &mut [T],
+    depth: usize,
+    ascending: bool,
+    compare: F,
+    swaps: &mut usize
+) -> AgcResult<()>
+where
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let length = slice.len();
+    if length < INTROSORT_THRESHOLD {
+        *swaps += insertionsort_counted_by(slice, ascending, compare)?.0;
+    } else if depth == 0 {
+        *swaps += heapsort_counted_by(slice, ascending, compare)?.0;
+    } else {
+        let pivot = counted_partition(
+            slice, 0, length, ascending, compare, swaps
+        )?;
+        let (before, after) = slice.split_at_mut(pivot);
+        introsort_slice(before, depth - 1, ascending, compare, swaps)?;
+        introsort_slice(
+            &mut after[1..], depth - 1, ascending, compare, swaps
+        )?;
+    }
+    Ok(())
+}
\ No newline at end of file
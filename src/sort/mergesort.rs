@@ -7,116 +7,276 @@ use std::{
 use crate::{
     alreadysorted,
     error::{AgcResult, AgcError, AgcErrorKind},
-    utils::{priority, slice::transfer_element}
+    macros::internal,
+    utils::{priority, random::XorShift, slice::transfer_element}
 };
 
-/// This is the merge algorithm used by merge sort. This function takes a
-/// contiguous segment of a slice, and merges the 2 parts of the slices into
-/// one ordered slice. It assumes that the 2 sub-slices are already sorted in
-/// the correct order, so when it merges the 2 slices together, the final slice
-/// will be ordered correctly. The location and sizes of the 2 slices must be
-/// provided by filling in the parameters for `left`, `middle` and `right`.
-/// `left` tells the function where the first element of the first sub-slice
-/// is, `middle` is the location of the last element of the first sub-slice and
-/// `right` is the location of the last element of the second sub-slice. This
-/// means that the first element of the second sub-slice will be `middle+1`,
-/// assuming that `right > middle`. If `middle == right`, the length of the
-/// second sub-slice is 0. The value of `left`, `middle` and `right` must be
-/// in the following order: `left <= middle <= right`.
-/// 
-/// `compare` is the function used to check the ordering of 2 elements.
-/// 
-/// # Notes
+internal! {
+    /// This is the merge algorithm used by merge sort. This function takes a
+    /// contiguous segment of a slice, and merges the 2 parts of the slices into
+    /// one ordered slice. It assumes that the 2 sub-slices are already sorted
+    /// in the correct order, so when it merges the 2 slices together, the final
+    /// slice will be ordered correctly. The location and sizes of the 2 slices
+    /// must be provided by filling in the parameters for `left`, `middle` and
+    /// `right`. `left` tells the function where the first element of the first
+    /// sub-slice is, `middle` is the location of the last element of the first
+    /// sub-slice and `right` is the location of the last element of the second
+    /// sub-slice. This means that the first element of the second sub-slice
+    /// will be `middle+1`, assuming that `right > middle`. If
+    /// `middle == right`, the length of the second sub-slice is 0. The value of
+    /// `left`, `middle` and `right` must be in the following order:
+    /// `left <= middle <= right`.
+    /// 
+    /// `compare` is the function used to check the ordering of 2 elements.
+    /// 
+    /// # Notes
+    /// 
+    /// This function merges a slice in-place.
+    /// 
+    /// This function is only public if the `internals` feature is enabled.
+    /// 
+    /// # Example
+    /// 
+    #[cfg_attr(feature = "internals", doc = "```")]
+    #[cfg_attr(not(feature = "internals"), doc = "```ignore")]
+    ///     use algocol::sort::mergesort::merge;
+    ///     let mut array = [7, 6, 1, 3, 5, 2, 4, 6, 8];
+    ///     let result = merge(&mut array[..], 2, 4, 8, true, |a, b| a.cmp(b));
+    ///     println!("{:?}", result);
+    ///     assert_eq!(array, [7, 6, 1, 2, 3, 4, 5, 6, 8]);
+    /// ```
+    pub fn merge<F, T>(
+        slice: &mut [T],
+        left: usize,
+        middle: usize,
+        right: usize,
+        ascending: bool,
+        compare: F
+    ) -> AgcResult<&mut [T]>
+    where
+        F: Fn(&T, &T) -> Ordering + Copy
+    {
+        check_merge_bounds(slice.len(), left, middle, right)?;
+        // [deposit..., left..., right...];
+        // ^ d          ^ l      ^ r
+        // deposit_size left_size right_size
+        let mut left_size = middle - left + 1;
+        let mut right_size = right - middle;
+        let mut deposit_size = 0;
+        while left_size > 0 && right_size > 0 {
+            let ordering = compare(
+                &slice[left+deposit_size],
+                &slice[left+deposit_size+left_size]
+            );
+            // Equal elements are taken from the left first to keep the merge
+            // stable.
+            if (priority::is_le(ordering) && ascending)
+            || (priority::is_ge(ordering) && !ascending) {
+                left_size -= 1;
+            } else {
+                transfer_element(
+                    slice,
+                    left+deposit_size+left_size,
+                    left+deposit_size
+                )?;
+                right_size -= 1;
+            }
+            deposit_size += 1;
+        }
+        Ok(slice)
+    }
+}
+
+internal! {
+    /// Merge 2 sorted sub-slices like `merge`, but by copying them into a
+    /// scratch buffer first and writing the merged elements back in a single
+    /// pass. `left`, `middle` and `right` have the same meaning as in `merge`.
+    /// This takes O(n) time, where `n` is `right - left + 1`, at the cost of
+    /// allocating a `Vec` of that many elements, which is why `T` must
+    /// implement `Clone`.
+    /// 
+    /// This function is only public if the `internals` feature is enabled.
+    /// 
+    /// # Example
+    /// 
+    #[cfg_attr(feature = "internals", doc = "```")]
+    #[cfg_attr(not(feature = "internals"), doc = "```ignore")]
+    ///     use algocol::sort::mergesort::merge_buffered;
+    ///     let mut array = [7, 6, 1, 3, 5, 2, 4, 6, 8];
+    ///     merge_buffered(&mut array[..], 2, 4, 8, true, |a, b| a.cmp(b))
+    ///         .unwrap();
+    ///     assert_eq!(array, [7, 6, 1, 2, 3, 4, 5, 6, 8]);
+    /// ```
+    pub fn merge_buffered<F, T>(
+        slice: &mut [T],
+        left: usize,
+        middle: usize,
+        right: usize,
+        ascending: bool,
+        compare: F
+    ) -> AgcResult<&mut [T]>
+    where
+        T: Clone,
+        F: Fn(&T, &T) -> Ordering + Copy
+    {
+        check_merge_bounds(slice.len(), left, middle, right)?;
+        let buffer = slice[left..=right].to_vec();
+        let (left_run, right_run) = buffer.split_at(middle - left + 1);
+        let (mut i, mut j) = (0, 0);
+        for slot in slice[left..=right].iter_mut() {
+            let take_left = j >= right_run.len() || (i < left_run.len() && {
+                // Equal elements are taken from the left first to keep the
+                // merge stable.
+                let ordering = compare(&left_run[i], &right_run[j]);
+                (priority::is_le(ordering) && ascending)
+                || (priority::is_ge(ordering) && !ascending)
+            });
+            if take_left {
+                *slot = left_run[i].clone();
+                i += 1;
+            } else {
+                *slot = right_run[j].clone();
+                j += 1;
+            }
+        }
+        Ok(slice)
+    }
+}
+
+/// Merge 2 sorted sub-slices, choosing between `merge` and `merge_buffered`
+/// depending on how many elements are being merged. `left`, `middle` and
+/// `right` have the same meaning as in `merge`.
 /// 
-/// This function merges a slice in-place.
+/// `merge` does not allocate, but every element it takes from the right run
+/// is rotated into place, which costs O(n^2) time in the worst case. This is
+/// cheap when the runs are short. `merge_buffered` takes O(n) time but has
+/// to copy every element into a new `Vec` first. Merges of up to
+/// `max_stack_alloc` elements (`right - left + 1`) are done in-place with
+/// `merge`, and longer merges use `merge_buffered`. Passing 0 always uses
+/// `merge_buffered`, while passing `usize::MAX` never allocates. Both give
+/// the same stable result.
 /// 
 /// # Example
-/// 
 /// ```
-///     use algocol::sort::mergesort::merge;
-///     let mut array = [7, 6, 1, 3, 5, 2, 4, 6, 8];
-///     let result = merge(&mut array[..], 2, 4, 8, true, |a, b| a.cmp(b));
-///     println!("{:?}", result);
-///     assert_eq!(array, [7, 6, 1, 2, 3, 4, 5, 6, 8]);
+///     use algocol::sort::mergesort::merge_auto_by;
+///     let mut array = [1, 3, 5, 2, 4, 6];
+///     merge_auto_by(&mut array[..], 0, 2, 5, true, 16, |a, b| a.cmp(b))
+///         .unwrap();
+///     assert_eq!(array, [1, 2, 3, 4, 5, 6]);
 /// ```
-pub fn merge<'t, F, T>(
-    slice: &'t mut [T],
+pub fn merge_auto_by<F, T>(
+    slice: &mut [T],
     left: usize,
     middle: usize,
     right: usize,
     ascending: bool,
+    max_stack_alloc: usize,
     compare: F
-) -> AgcResult<&'t mut [T]>
+) -> AgcResult<&mut [T]>
 where
+    T: Clone,
     F: Fn(&T, &T) -> Ordering + Copy
 {
-    // Start of error checking section
-    if left > middle {
-        return Err(
-            AgcError::new(
-                AgcErrorKind::WrongOrder,
-                format!(
-                    "Left ({}) cannot be greater than middle ({})",
-                    left,
-                    middle
-                )
-            )
-        );
-    } else if middle > right {
-        return Err(
-            AgcError::new(
-                AgcErrorKind::WrongOrder,
-                format!(
-                    "Right ({}) cannot be smaller than middle ({})",
-                    right,
-                    middle
-                )
-            )
-        );
+    // Checked here first, since `right - left` could overflow otherwise
+    check_merge_bounds(slice.len(), left, middle, right)?;
+    if right - left < max_stack_alloc {
+        merge(slice, left, middle, right, ascending, compare)
+    } else {
+        merge_buffered(slice, left, middle, right, ascending, compare)
     }
-    let length = slice.len();
-    if left > length {
-        return Err(AgcError::new(AgcErrorKind::OutOfBounds, format!(
-            "Left ({}) is out of bounds.",
-            left
-        )));
-    } else if middle > length {
-        return Err(AgcError::new(AgcErrorKind::OutOfBounds, format!(
-            "Middle ({}) is out of bounds.",
-            middle
-        )));
-    } else if right > length {
-        return Err(AgcError::new(AgcErrorKind::OutOfBounds, format!(
-            "Right ({}) is out of bounds.",
-            right
-        )));
+}
+
+/// Merge 2 sorted slices, `left` and `right`, into a new `Vec`. Both slices
+/// must be sorted in ascending order according to `compare`, which gives the
+/// primary order of the elements. When 2 elements have the same priority,
+/// `tiebreak` decides which of them comes first, and if `tiebreak` says that
+/// they are equal too, the element from `left` comes first.
+/// 
+/// `tiebreak` is only used to choose between the 2 elements at the front of
+/// `left` and `right`, so the elements within each slice stay in the same
+/// order. If `left` and `right` are also sorted by `tiebreak` within each
+/// group of equal elements, the merged `Vec` is sorted by `compare` and then
+/// by `tiebreak`.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::mergesort::merge_by_with_tiebreak;
+///     let left = [(1, 'b'), (2, 'a')];
+///     let right = [(1, 'a'), (2, 'b')];
+///     let merged = merge_by_with_tiebreak(
+///         &left, &right, |a, b| a.0.cmp(&b.0), |a, b| a.1.cmp(&b.1)
+///     );
+///     assert_eq!(merged, [(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+/// ```
+pub fn merge_by_with_tiebreak<T, F, G>(
+    left: &[T],
+    right: &[T],
+    compare: F,
+    tiebreak: G
+) -> Vec<T>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> Ordering,
+    G: Fn(&T, &T) -> Ordering
+{
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        let ordering = compare(&left[i], &right[j])
+            .then_with(|| tiebreak(&left[i], &right[j]));
+        if priority::is_le(ordering) {
+            merged.push(left[i].clone());
+            i += 1;
+        } else {
+            merged.push(right[j].clone());
+            j += 1;
+        }
     }
-    // End of error checking section
-    // [deposit..., left..., right...];
-    // ^ d          ^ l      ^ r
-    // deposit_size left_size right_size
-    let mut left_size = middle - left + 1;
-    let mut right_size = right - middle;
-    let mut deposit_size = 0;
-    while left_size > 0 && right_size > 0 {
-        if priority::is_lt(
-            compare(
-                &slice[left+deposit_size],
-                &slice[left+deposit_size+left_size]
-            )
-        ) == ascending {
-            left_size -= 1;
+    merged.extend_from_slice(&left[i..]);
+    merged.extend_from_slice(&right[j..]);
+    merged
+}
+
+/// Interleave `left` and `right` into a new `Vec` like a riffle shuffle,
+/// where a deck of cards is split in 2 and the halves are let go of one card
+/// at a time. This works just like `merge_by_with_tiebreak`, except that
+/// the next element is picked by a (weighted) coin flip instead of by
+/// comparing the 2 elements at the front of `left` and `right`. The coin
+/// flips are made by a `XorShift` generator seeded with `seed`, so the same
+/// seed always gives the same result.
+/// 
+/// Like a real riffle shuffle, the chance of taking the next element from
+/// `left` is the number of elements left in `left` divided by the number of
+/// elements left in both slices. The elements from each slice stay in the
+/// same order, just like they do in a merge, which is why a single riffle
+/// shuffle does not shuffle a deck very well.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::mergesort::riffle_merge;
+///     let shuffled = riffle_merge(&[1, 2, 3], &[4, 5, 6], 42);
+///     assert_eq!(shuffled.len(), 6);
+///     let from_left = shuffled.iter().filter(|x| **x <= 3);
+///     assert_eq!(from_left.copied().collect::<Vec<i32>>(), [1, 2, 3]);
+/// ```
+pub fn riffle_merge<T: Clone>(left: &[T], right: &[T], seed: u64) -> Vec<T> {
+    let mut random = XorShift::new(seed);
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        let left_remaining = left.len() - i;
+        let remaining = left_remaining + right.len() - j;
+        if random.next_below(remaining) < left_remaining {
+            merged.push(left[i].clone());
+            i += 1;
         } else {
-            transfer_element(
-                slice,
-                left+deposit_size+left_size,
-                left+deposit_size
-            )?;
-            right_size -= 1;
+            merged.push(right[j].clone());
+            j += 1;
         }
-        deposit_size += 1;
     }
-    Ok(slice)
+    merged.extend_from_slice(&left[i..]);
+    merged.extend_from_slice(&right[j..]);
+    merged
 }
 
 /// This function sorts an unordered slice using the merge sort algorithm.
@@ -124,7 +284,9 @@ where
 /// sorting them one by one, before working its way up by **merging** the
 /// smaller slices which have already been sorted.
 /// 
-/// This algorithm's time complexity is O(n^2).
+/// This algorithm's time complexity is O(n log n). Each merge copies the 2
+/// slices being merged into a buffer (see `merge_buffered`), which is why
+/// `T` must implement `Clone`.
 /// 
 /// # Example
 /// ```
@@ -139,7 +301,7 @@ pub fn mergesort<S, T>(
 ) -> AgcResult<&mut [T]>
 where
     S: AsMut<[T]> + ?Sized,
-    T: Ord
+    T: Ord + Clone
 {
     mergesort_by(sequence, ascending, |a, b| a.cmp(b))
 }
@@ -150,10 +312,16 @@ where
 /// its way up by **merging** the smaller slices which have already been
 /// sorted.
 /// 
-/// This algorithm's time complexity is O(n^2). This function is adapted from
-/// GeeksforGeeks' C++
+/// This algorithm's time complexity is O(n log n). This function is adapted
+/// from GeeksforGeeks' C++
 /// [implemetation](https://www.geeksforgeeks.org/iterative-merge-sort/).
 /// 
+/// The slices are merged with `merge_buffered`, which copies them into a
+/// buffer and merges them back in a single pass, so `T` must implement
+/// `Clone`. Merging in-place with `merge` would avoid the extra memory, but
+/// every element it moves shifts the elements in between over by 1, making
+/// each merge take O(n^2) time in the worst case.
+/// 
 /// # Example
 /// ```
 ///     use algocol::sort::mergesort::mergesort_by;
@@ -170,6 +338,7 @@ pub fn mergesort_by<F, S, T>(
 ) -> AgcResult<&mut [T]>
 where
     S: AsMut<[T]> + ?Sized,
+    T: Clone,
     F: Fn(&T, &T) -> Ordering + Copy
 {
     let sequence = sequence.as_mut();
@@ -188,7 +357,7 @@ where
             let middle = min(left+size-1, length-1);
             // The last element in the 2 sub-slices.
             let right = min(left+2*size-1, length-1);
-            merge(sequence, left, middle, right, ascending, &compare)?;
+            merge_buffered(sequence, left, middle, right, ascending, compare)?;
         }
         size <<= 1;
     }
@@ -239,11 +408,46 @@ where
 ///     ).unwrap(); // 10 operations are made.
 ///     assert_eq!(array, [1, 2, 3, 4, 5]);
 /// ```
-pub fn mergesort_recursively_by<'t, F, S, T>(
-    sequence: &'t mut S,
+pub fn mergesort_recursively_by<F, S, T>(
+    sequence: &mut S,
     ascending: bool,
     compare: F
-) -> AgcResult<&'t mut [T]>
+) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let (_, sequence) = mergesort_recursively_depth_by(
+        sequence,
+        ascending,
+        compare
+    )?;
+    Ok(sequence)
+}
+
+/// Sort a slice like `mergesort_recursively_by` and return the recursion
+/// depth it reached along with the sorted slice. The depth is the largest
+/// number of nested calls which each had at least 2 elements to sort, so a
+/// slice with fewer than 2 elements has a depth of 0.
+/// 
+/// Since merge sort always splits a slice in half, the depth is
+/// `ceil(log2(n))` for `n` elements, no matter what order they are in.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::mergesort::mergesort_recursively_depth_by;
+///     let mut array = [5, 4, 3, 2, 1];
+///     let (depth, sorted) = mergesort_recursively_depth_by(
+///         &mut array[..], true, |a, b| a.cmp(b)
+///     ).unwrap();
+///     assert_eq!(depth, 3);
+///     assert_eq!(sorted, [1, 2, 3, 4, 5]);
+/// ```
+pub fn mergesort_recursively_depth_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<(usize, &mut [T])>
 where
     S: AsMut<[T]> + ?Sized,
     F: Fn(&T, &T) -> Ordering + Copy
@@ -251,11 +455,267 @@ where
     let sequence = sequence.as_mut();
     let length = sequence.len();
     if length <= 1 {
-        return Ok(sequence);
+        return Ok((0, sequence));
     }
     let middle = length/2;
-    mergesort_recursively_by(&mut sequence[..middle], ascending, compare)?;
-    mergesort_recursively_by(&mut sequence[middle..], ascending, compare)?;
+    let (before, _) = mergesort_recursively_depth_by(
+        &mut sequence[..middle],
+        ascending,
+        compare
+    )?;
+    let (after, _) = mergesort_recursively_depth_by(
+        &mut sequence[middle..],
+        ascending,
+        compare
+    )?;
     merge(sequence, 0, middle-1, length-1, ascending, compare)?;
-    Ok(sequence)
+    Ok((1 + before.max(after), sequence))
+}
+
+/// Count the number of "significant inversions" in a sequence. A significant
+/// inversion is a pair of indices `(i, j)` where `i < j` and
+/// `sequence[i] > 2 * sequence[j]`.
+/// 
+/// Checking every pair would take O(n^2) time. Instead, this function sorts
+/// a clone of `sequence` with merge sort and counts the significant
+/// inversions between the 2 sorted halves before they are merged, bringing
+/// the time complexity down to O(n log n). `sequence` itself is left
+/// untouched.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::mergesort::count_significant_inversions;
+///     let array = [1, 3, 5, 2, 0];
+///     // (1, 0), (3, 0), (5, 2), (5, 0) and (2, 0)
+///     assert_eq!(count_significant_inversions(&array[..]), 5);
+/// ```
+pub fn count_significant_inversions<S>(sequence: &S) -> u64
+where
+    S: AsRef<[i64]> + ?Sized
+{
+    let mut sequence = sequence.as_ref().to_vec();
+    count_significant_inversions_recursively(&mut sequence)
+}
+
+fn count_significant_inversions_recursively(sequence: &mut [i64]) -> u64 {
+    let length = sequence.len();
+    if length <= 1 {
+        return 0;
+    }
+    let middle = length/2;
+    let mut count = count_significant_inversions_recursively(
+        &mut sequence[..middle]
+    ) + count_significant_inversions_recursively(&mut sequence[middle..]);
+    // Both halves are sorted, so for each element in the left half, the
+    // number of elements in the right half that it is more than twice of can
+    // only grow as we walk forward.
+    // i128 is used so that doubling an i64 can never overflow.
+    let mut right = middle;
+    for left in 0..middle {
+        while right < length
+        && sequence[left] as i128 > 2 * sequence[right] as i128 {
+            right += 1;
+        }
+        count += (right - middle) as u64;
+    }
+    // The bounds are always valid, so this never fails.
+    merge_buffered(sequence, 0, middle-1, length-1, true, |a, b| a.cmp(b))
+        .unwrap();
+    count
+}
+
+/// Sort a singly linked list with merge sort and return the index of its new
+/// first node. The list is stored in `nodes`, where each node is a value and
+/// the index of the next node in the list (or `None` for the last node), and
+/// `head` is the index of the first node. The values are not moved. Instead,
+/// the `next` indices are changed so that walking the list from the new
+/// head visits the values in sorted order. Nodes which are not in the list
+/// are left alone.
+/// 
+/// Merge sort suits linked lists better than slices. 2 sorted lists can be
+/// merged by relinking their nodes, so unlike merging slices, no elements
+/// have to be shifted around and no extra buffer is needed. This function
+/// sorts bottom-up: it merges neighbouring lists of 1 node, then of 2 nodes,
+/// then of 4 nodes, and so on, so it only needs O(1) extra memory and takes
+/// O(n log n) time. The merge is stable.
+/// 
+/// An `AgcError` of kind `InvalidArgument` is returned if a `next` index is
+/// out of bounds or if the list has a cycle in it.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::mergesort::mergesort_linked_by;
+///     // 3 -> 1 -> 2
+///     let mut nodes = vec![(3, Some(1)), (1, Some(2)), (2, None)];
+///     let head = mergesort_linked_by(&mut nodes, Some(0), true, |a, b| {
+///         a.cmp(b)
+///     }).unwrap();
+///     // 1 -> 2 -> 3
+///     assert_eq!(head, Some(1));
+///     assert_eq!(nodes, [(3, None), (1, Some(2)), (2, Some(0))]);
+/// ```
+pub fn mergesort_linked_by<F, T>(
+    nodes: &mut [(T, Option<usize>)],
+    head: Option<usize>,
+    ascending: bool,
+    compare: F
+) -> AgcResult<Option<usize>>
+where
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let mut length = 0;
+    let mut current = head;
+    while let Some(index) = current {
+        if index >= nodes.len() {
+            return Err(AgcError::new(
+                AgcErrorKind::InvalidArgument,
+                format!("Index {} in the list is out of bounds.", index)
+            ));
+        } else if length >= nodes.len() {
+            return Err(AgcError::new(
+                AgcErrorKind::InvalidArgument,
+                "The list has a cycle in it."
+            ));
+        }
+        length += 1;
+        current = nodes[index].1;
+    }
+    let mut head = head;
+    let mut size = 1;
+    while size < length {
+        let mut rest = head.take();
+        let mut tail: Option<usize> = None;
+        while let Some(left) = rest {
+            let right = cut_linked(nodes, left, size);
+            rest = right.and_then(|right| cut_linked(nodes, right, size));
+            let (merged_head, merged_tail) = merge_linked(
+                nodes, left, right, ascending, compare
+            );
+            match tail {
+                Some(tail) => nodes[tail].1 = Some(merged_head),
+                None => head = Some(merged_head)
+            }
+            tail = Some(merged_tail);
+        }
+        size *= 2;
+    }
+    Ok(head)
+}
+
+/// Cut the linked list starting at `start` after `size` nodes, and return
+/// the index of the first node after the cut.
+fn cut_linked<T>(
+    nodes: &mut [(T, Option<usize>)],
+    start: usize,
+    size: usize
+) -> Option<usize> {
+    let mut last = start;
+    for _ in 1..size {
+        match nodes[last].1 {
+            Some(next) => last = next,
+            None => return None
+        }
+    }
+    nodes[last].1.take()
+}
+
+/// Merge the sorted linked lists starting at `left` and `right`, returning
+/// the indices of the first and last nodes of the merged list.
+fn merge_linked<F, T>(
+    nodes: &mut [(T, Option<usize>)],
+    left: usize,
+    right: Option<usize>,
+    ascending: bool,
+    compare: F
+) -> (usize, usize)
+where
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let (mut left, mut right) = (Some(left), right);
+    let mut head = None;
+    let mut tail: Option<usize> = None;
+    while let (Some(l), Some(r)) = (left, right) {
+        let ordering = compare(&nodes[l].0, &nodes[r].0);
+        // Equal nodes are taken from the left first to keep the merge
+        // stable.
+        let next = if (priority::is_le(ordering) && ascending)
+        || (priority::is_ge(ordering) && !ascending) {
+            left = nodes[l].1;
+            l
+        } else {
+            right = nodes[r].1;
+            r
+        };
+        match tail {
+            Some(tail) => nodes[tail].1 = Some(next),
+            None => head = Some(next)
+        }
+        tail = Some(next);
+    }
+    // The rest of the list which has not run out is already sorted, so it
+    // is attached as it is.
+    let mut last = match (tail, left.or(right)) {
+        (Some(tail), rest) => {
+            nodes[tail].1 = rest;
+            tail
+        },
+        (None, rest) => {
+            head = rest;
+            rest.unwrap()
+        }
+    };
+    while let Some(next) = nodes[last].1 {
+        last = next;
+    }
+    (head.unwrap(), last)
+}
+
+/// Return an `AgcError` if `left`, `middle` and `right` are out of order or
+/// out of bounds for a slice with `length` elements.
+fn check_merge_bounds(
+    length: usize,
+    left: usize,
+    middle: usize,
+    right: usize
+) -> AgcResult<()> {
+    if left > middle {
+        return Err(
+            AgcError::new(
+                AgcErrorKind::WrongOrder,
+                format!(
+                    "Left ({}) cannot be greater than middle ({})",
+                    left,
+                    middle
+                )
+            )
+        );
+    } else if middle > right {
+        return Err(
+            AgcError::new(
+                AgcErrorKind::WrongOrder,
+                format!(
+                    "Right ({}) cannot be smaller than middle ({})",
+                    right,
+                    middle
+                )
+            )
+        );
+    }
+    if left > length {
+        return Err(AgcError::new(AgcErrorKind::OutOfBounds, format!(
+            "Left ({}) is out of bounds.",
+            left
+        )));
+    } else if middle > length {
+        return Err(AgcError::new(AgcErrorKind::OutOfBounds, format!(
+            "Middle ({}) is out of bounds.",
+            middle
+        )));
+    } else if right >= length {
+        return Err(AgcError::new(AgcErrorKind::OutOfBounds, format!(
+            "Right ({}) is out of bounds.",
+            right
+        )));
+    }
+    Ok(())
 }
\ No newline at end of file
@@ -39,23 +39,46 @@
 
 use std::{
     cmp::{Ord, Ordering},
-    convert::AsRef
+    convert::{AsMut, AsRef}
+};
+use crate::{
+    error::{AgcError, AgcErrorKind, AgcResult},
+    utils::{cmp::CountingComparator, priority, slice::apply_permutation}
 };
-use crate::utils::priority;
 
 pub mod bubblesort;
+pub mod bucketsort;
+pub mod cocktailsort;
+pub mod combsort;
+pub mod countingsort;
+pub mod dutchflag;
+pub mod heapsort;
 pub mod insertionsort;
+pub mod introsort;
 pub mod mergesort;
+pub mod network;
 pub mod quicksort;
+pub mod radixsort;
 pub mod selectionsort;
+pub mod shellsort;
 pub mod timsort;
 
 pub use crate::sort::{
     bubblesort::*,
+    bucketsort::*,
+    cocktailsort::*,
+    combsort::*,
+    countingsort::*,
+    dutchflag::*,
+    heapsort::*,
     insertionsort::*,
+    introsort::*,
     mergesort::*,
+    network::*,
     quicksort::*,
+    radixsort::*,
     selectionsort::*,
+    shellsort::*,
     timsort::*
 };
 
@@ -64,30 +87,55 @@ pub use self::{
         bubblesort as s_bubble_i,
         bubblesort_by as s_bubble_if,
     },
+    cocktailsort::{
+        cocktailsort as s_cocktail_i,
+        cocktailsort_by as s_cocktail_if
+    },
+    combsort::{
+        combsort as s_comb_i,
+        combsort_by as s_comb_if
+    },
+    dutchflag::{
+        dutch_flag_sort_by as s_dutch_if
+    },
+    heapsort::{
+        heapsort as s_heap_i,
+        heapsort_by as s_heap_if
+    },
     insertionsort::{
         insertionsort as s_insert_i,
         insertionsort_by as s_insert_if
     },
+    introsort::{
+        introsort as s_intro_i,
+        introsort_by as s_intro_if
+    },
     mergesort::{
-        merge,
         mergesort as s_merge_i,
         mergesort_by as s_merge_if,
         mergesort_recursively as s_merge_r,
         mergesort_recursively_by as s_merge_rf
     },
-    quicksort::{
-        partition
-    },
     selectionsort::{
         selectionsort as s_select_i,
         selectionsort_by as s_select_if
     },
+    shellsort::{
+        shellsort as s_shell_i,
+        shellsort_by as s_shell_if
+    },
     timsort::{
         timsort as s_tim_i,
         timsort_by as s_tim_if
     }
 };
 
+#[cfg(feature = "internals")]
+pub use self::{
+    mergesort::{merge, merge_buffered},
+    quicksort::{partition, partition_median3, partition_randomized}
+};
+
 /// Checks to see if a slice is correctly ordered in ascending or descending
 /// order. The sequence that you passed must have elements that implement
 /// `std::cmp::Ord`. If you want to check if the sequence is in ascending
@@ -159,4 +207,581 @@ where
         }
     }
     true
+}
+
+/// Works like `is_sorted_by`, but also returns the number of times that
+/// `compare` was called. `is_sorted_by` stops at the first pair of elements
+/// which is out of order, so this is less than `verification_comparisons` of
+/// the length of `sequence` if the slice is not sorted.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::is_sorted_counted_by;
+///     let compare = |a: &i32, b: &i32| a.cmp(b);
+///     let array = [1, 2, 3, 4, 5];
+///     assert_eq!(is_sorted_counted_by(&array, true, compare), (true, 4));
+///     let array = [2, 1, 3, 4, 5];
+///     assert_eq!(is_sorted_counted_by(&array, true, compare), (false, 1));
+/// ```
+pub fn is_sorted_counted_by<F, S, T>(
+    sequence: &S,
+    ascending: bool,
+    compare: F
+) -> (bool, usize)
+where
+    S: AsRef<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let counter = CountingComparator::new(compare);
+    let sorted = is_sorted_by(sequence, ascending, |a, b| {
+        counter.compare(a, b)
+    });
+    (sorted, counter.count())
+}
+
+/// The number of comparisons that `is_sorted_by` makes to verify that a
+/// sorted slice of length `length` is sorted, which is `length - 1` (or 0 for
+/// an empty slice).
+/// 
+/// Checking that a slice is sorted only needs each element to be compared
+/// with the one after it, since the order is transitive, so it takes O(n)
+/// time. Sorting a slice with comparisons takes O(n log n) time in the worst
+/// case, because there are `n!` ways to order `n` elements and each
+/// comparison can rule out at most half of the ones left, so at least
+/// `log2(n!)` comparisons are needed to find the right one, which grows like
+/// `n log n`. `is_sorted_counted_by` can be used to check this count.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::verification_comparisons;
+///     assert_eq!(verification_comparisons(5), 4);
+///     assert_eq!(verification_comparisons(0), 0);
+/// ```
+pub fn verification_comparisons(length: usize) -> usize {
+    length.saturating_sub(1)
+}
+
+/// The sorting algorithms in this module, for functions which let you choose
+/// which algorithm to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortAlgorithm {
+    Bubble,
+    Selection,
+    Insertion,
+    Merge,
+    MergeRecursive,
+    Tim,
+    Quick,
+    QuickRecursive,
+    Shell,
+    Cocktail,
+    Comb,
+    Heap,
+    Intro,
+    BinaryInsertion
+}
+
+impl SortAlgorithm {
+    /// Every algorithm in `SortAlgorithm`.
+    pub const ALL: [SortAlgorithm; 14] = [
+        SortAlgorithm::Bubble,
+        SortAlgorithm::Selection,
+        SortAlgorithm::Insertion,
+        SortAlgorithm::Merge,
+        SortAlgorithm::MergeRecursive,
+        SortAlgorithm::Tim,
+        SortAlgorithm::Quick,
+        SortAlgorithm::QuickRecursive,
+        SortAlgorithm::Shell,
+        SortAlgorithm::Cocktail,
+        SortAlgorithm::Comb,
+        SortAlgorithm::Heap,
+        SortAlgorithm::Intro,
+        SortAlgorithm::BinaryInsertion
+    ];
+
+    /// Sort `sequence` with this algorithm. `Tim` uses
+    /// `timsort::DEFAULT_RUN` as its run size. `T` must implement `Clone`
+    /// because `Merge` and `Tim` merge through a buffer.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::sort::SortAlgorithm;
+    ///     let mut array = [5, 4, 3, 2, 1];
+    ///     SortAlgorithm::Quick.sort_by(
+    ///         &mut array[..], true, |a, b| a.cmp(b)
+    ///     ).unwrap();
+    ///     assert_eq!(array, [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn sort_by<F, S, T>(
+        self,
+        sequence: &mut S,
+        ascending: bool,
+        compare: F
+    ) -> AgcResult<&mut [T]>
+    where
+        S: AsMut<[T]> + ?Sized,
+        T: Clone,
+        F: Fn(&T, &T) -> Ordering + Copy
+    {
+        use SortAlgorithm::*;
+        match self {
+            Bubble => bubblesort_by(sequence, ascending, compare),
+            Selection => selectionsort_by(sequence, ascending, compare),
+            Insertion => insertionsort_by(sequence, ascending, compare),
+            Merge => mergesort_by(sequence, ascending, compare),
+            MergeRecursive => {
+                mergesort_recursively_by(sequence, ascending, compare)
+            },
+            Tim => timsort_by(sequence, ascending, DEFAULT_RUN, compare),
+            Quick => quicksort_by(sequence, ascending, compare),
+            QuickRecursive => {
+                quicksort_recursively_by(sequence, ascending, compare)
+            },
+            Shell => shellsort_by(sequence, ascending, compare),
+            Cocktail => cocktailsort_by(sequence, ascending, compare),
+            Comb => combsort_by(sequence, ascending, compare),
+            Heap => heapsort_by(sequence, ascending, compare),
+            Intro => introsort_by(sequence, ascending, compare),
+            BinaryInsertion => {
+                binary_insertionsort_by(sequence, ascending, compare)
+            }
+        }
+    }
+
+    /// Sort `sequence` with this algorithm like `sort_by`, but also return
+    /// the number of swaps made, using the counted variant of the algorithm
+    /// (such as `quicksort_counted_by`). `Merge`, `MergeRecursive`, `Tim`
+    /// and `BinaryInsertion` move elements with merges and rotations instead
+    /// of swaps, so `None` is returned for them.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::sort::SortAlgorithm;
+    ///     let mut array = [5, 4, 3, 2, 1];
+    ///     let (swaps, sorted) = SortAlgorithm::Bubble.sort_counted_by(
+    ///         &mut array[..], true, |a, b| a.cmp(b)
+    ///     ).unwrap();
+    ///     assert_eq!(sorted, [1, 2, 3, 4, 5]);
+    ///     assert_eq!(swaps, Some(10));
+    /// ```
+    pub fn sort_counted_by<F, S, T>(
+        self,
+        sequence: &mut S,
+        ascending: bool,
+        compare: F
+    ) -> AgcResult<(Option<usize>, &mut [T])>
+    where
+        S: AsMut<[T]> + ?Sized,
+        T: Clone,
+        F: Fn(&T, &T) -> Ordering + Copy
+    {
+        use SortAlgorithm::*;
+        let sequence = sequence.as_mut();
+        let (swaps, sequence) = match self {
+            Bubble => bubblesort_counted_by(sequence, ascending, compare),
+            Selection => {
+                selectionsort_counted_by(sequence, ascending, compare)
+            },
+            Insertion => {
+                insertionsort_counted_by(sequence, ascending, compare)
+            },
+            Quick => quicksort_counted_by(sequence, ascending, compare),
+            QuickRecursive => {
+                quicksort_recursively_counted_by(sequence, ascending, compare)
+            },
+            Shell => shellsort_counted_by(sequence, ascending, compare),
+            Cocktail => cocktailsort_counted_by(sequence, ascending, compare),
+            Comb => combsort_counted_by(sequence, ascending, compare),
+            Heap => heapsort_counted_by(sequence, ascending, compare),
+            Intro => introsort_counted_by(sequence, ascending, compare),
+            Merge | MergeRecursive | Tim | BinaryInsertion => {
+                return self.sort_by(sequence, ascending, compare)
+                    .map(|sequence| (None, sequence));
+            }
+        }?;
+        Ok((Some(swaps), sequence))
+    }
+
+    /// Whether this algorithm is stable by itself, which means that
+    /// elements which are equal keep the order that they were in before the
+    /// sort.
+    pub fn is_stable(self) -> bool {
+        use SortAlgorithm::*;
+        match self {
+            Bubble | Insertion | Merge | MergeRecursive | Tim | Cocktail
+            | BinaryInsertion => true,
+            Selection | Quick | QuickRecursive | Shell | Comb | Heap
+            | Intro => false
+        }
+    }
+}
+
+/// Sort `sequence` with `algorithm`, choosing whether the sort has to be
+/// stable, like the difference between `slice::sort` and
+/// `slice::sort_unstable`. If `require_stable` is `true` and `algorithm` is
+/// not stable by itself (see `SortAlgorithm::is_stable`), it is wrapped with
+/// `stable_adapter_by`, which costs O(n) extra memory. Otherwise, `algorithm`
+/// is used as it is, so equal elements may end up in any order if it is not
+/// stable.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::{sort_with, SortAlgorithm};
+///     let mut pairs = [(1, 'a'), (1, 'b'), (0, 'c')];
+///     let compare = |a: &(i32, char), b: &(i32, char)| a.0.cmp(&b.0);
+///     let algorithm = SortAlgorithm::Selection;
+///     sort_with(&mut pairs[..], algorithm, true, true, compare).unwrap();
+///     assert_eq!(pairs, [(0, 'c'), (1, 'a'), (1, 'b')]);
+/// ```
+pub fn sort_with<F, S, T>(
+    sequence: &mut S,
+    algorithm: SortAlgorithm,
+    ascending: bool,
+    require_stable: bool,
+    compare: F
+) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    T: Clone,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    if require_stable && !algorithm.is_stable() {
+        stable_adapter_by(sequence, algorithm, ascending, compare)
+    } else {
+        algorithm.sort_by(sequence, ascending, compare)
+    }
+}
+
+/// Sort `sequence` stably with any algorithm in `SortAlgorithm`, even one
+/// which is not stable by itself, such as quicksort. A sort is stable if
+/// elements which are equal keep the order that they were in before the sort.
+/// 
+/// This works by sorting the indices of the elements instead of the elements
+/// themselves. When 2 elements are equal, their indices are compared instead,
+/// so no 2 indices are ever considered equal and the order of the sorted
+/// indices does not depend on whether `algorithm` is stable. The sorted
+/// indices are then used to reorder `sequence` with `apply_permutation`.
+/// This costs O(n) extra memory for the indices.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::{stable_adapter_by, SortAlgorithm};
+///     let mut pairs = [(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')];
+///     stable_adapter_by(
+///         &mut pairs[..], SortAlgorithm::Quick, true, |a, b| a.0.cmp(&b.0)
+///     ).unwrap();
+///     assert_eq!(pairs, [(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]);
+/// ```
+pub fn stable_adapter_by<F, S, T>(
+    sequence: &mut S,
+    algorithm: SortAlgorithm,
+    ascending: bool,
+    compare: F
+) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_mut();
+    let mut indices = (0..sequence.len()).collect::<Vec<usize>>();
+    {
+        let elements: &[T] = sequence;
+        // The direction is applied to the elements here so that the indices
+        // of equal elements are always compared in ascending order.
+        algorithm.sort_by(&mut indices, true, |a: &usize, b: &usize| {
+            let ordering = compare(&elements[*a], &elements[*b]);
+            let ordering = if ascending {ordering} else {ordering.reverse()};
+            ordering.then(a.cmp(b))
+        })?;
+    }
+    apply_permutation(sequence, &mut indices)?;
+    Ok(sequence)
+}
+
+/// Find the indices of the elements of `sequence` in sorted order, so that
+/// `sequence[indices[0]]` is the first element after sorting, and so on.
+/// `sequence` itself is not changed. Equal elements keep the order that they
+/// were in, as the indices are sorted with mergesort.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::argsort_by;
+///     let array = [30, 10, 20, 10];
+///     let indices = argsort_by(&array[..], true, |a, b| a.cmp(b));
+///     assert_eq!(indices, [1, 3, 2, 0]);
+/// ```
+pub fn argsort_by<F, S, T>(
+    sequence: &S,
+    ascending: bool,
+    compare: F
+) -> Vec<usize>
+where
+    S: AsRef<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_ref();
+    let mut indices = (0..sequence.len()).collect::<Vec<usize>>();
+    // Mergesort only fails if `merge` is given bad indices, which it never
+    // does here.
+    mergesort_by(&mut indices, ascending, |a: &usize, b: &usize| {
+        compare(&sequence[*a], &sequence[*b])
+    }).unwrap();
+    indices
+}
+
+/// How `ranks_by` ranks elements which are equal to each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RankMethod {
+    /// Equal elements share the lowest rank that any of them would have
+    /// gotten, and the ranks after them are skipped ("1224" ranking).
+    Competition,
+    /// Equal elements share the same rank, and the next different element
+    /// gets the rank after that ("1223" ranking).
+    Dense
+}
+
+/// Find the rank of every element in `sequence`, where the first element in
+/// sorted order has a rank of 1. The rank of `sequence[i]` is stored at index
+/// `i` of the returned `Vec`. Elements which are equal to each other share
+/// the same rank, and `method` decides what rank the elements after them get.
+/// 
+/// The elements are first sorted with `argsort_by`, and the ranks are then
+/// handed out by walking through the sorted indices, so this takes
+/// O(n log n) time.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::{ranks_by, RankMethod::{Competition, Dense}};
+///     let array = [30, 10, 20, 10];
+///     let compare = |a: &i32, b: &i32| a.cmp(b);
+///     let ranks = ranks_by(&array[..], true, Competition, compare);
+///     assert_eq!(ranks, [4, 1, 3, 1]);
+///     let ranks = ranks_by(&array[..], true, Dense, compare);
+///     assert_eq!(ranks, [3, 1, 2, 1]);
+/// ```
+pub fn ranks_by<F, S, T>(
+    sequence: &S,
+    ascending: bool,
+    method: RankMethod,
+    compare: F
+) -> Vec<usize>
+where
+    S: AsRef<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_ref();
+    let order = argsort_by(sequence, ascending, compare);
+    let mut ranks = vec![0; sequence.len()];
+    let mut rank = 0;
+    for (position, index) in order.iter().enumerate() {
+        let tied = position > 0 && priority::is_eq(
+            compare(&sequence[*index], &sequence[order[position-1]])
+        );
+        if !tied {
+            rank = match method {
+                RankMethod::Competition => position + 1,
+                RankMethod::Dense => rank + 1
+            };
+        }
+        ranks[*index] = rank;
+    }
+    ranks
+}
+
+/// Sort a copy of `sequence` and remove every element which is equal to the
+/// element before it, so that only 1 element with each priority is left. The
+/// sorted, deduplicated `Vec` is returned and `sequence` is not changed.
+/// 
+/// The copy is sorted with mergesort, which is stable, so the element that
+/// is kept from each group of equal elements is the one which came first in
+/// `sequence`.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::sort_unique_by;
+///     let array = [3, 1, 3, 2, 1, 3];
+///     let unique = sort_unique_by(&array[..], true, |a, b| a.cmp(b));
+///     assert_eq!(unique, Ok(vec![1, 2, 3]));
+/// ```
+pub fn sort_unique_by<F, S, T>(
+    sequence: &S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<Vec<T>>
+where
+    S: AsRef<[T]> + ?Sized,
+    T: Clone,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let mut sorted = sequence.as_ref().to_vec();
+    mergesort_by(&mut sorted, ascending, compare)?;
+    sorted.dedup_by(|later, earlier| priority::is_eq(compare(earlier, later)));
+    Ok(sorted)
+}
+
+/// Sort `sequence` with mergesort and return a mask showing which positions
+/// hold a different value after sorting, along with the sorted slice. This
+/// is useful for seeing how much of a slice a sort actually had to change.
+/// 
+/// A snapshot of `sequence` is taken before it is sorted, and the element at
+/// each index is compared with the snapshot using `PartialEq`. An element
+/// which moved to a position holding an equal value is therefore not marked.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::sort_with_moved_mask_by;
+///     let mut array = [1, 2, 4, 3, 5];
+///     let (mask, sorted) = sort_with_moved_mask_by(
+///         &mut array, true, |a, b| a.cmp(b)
+///     ).unwrap();
+///     assert_eq!(sorted, [1, 2, 3, 4, 5]);
+///     assert_eq!(mask, [false, false, true, true, false]);
+/// ```
+pub fn sort_with_moved_mask_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<(Vec<bool>, &mut [T])>
+where
+    S: AsMut<[T]> + ?Sized,
+    T: Clone + PartialEq,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_mut();
+    let snapshot = sequence.to_vec();
+    let sorted = mergesort_by(sequence, ascending, compare)?;
+    let mask = sorted
+        .iter()
+        .zip(snapshot.iter())
+        .map(|(after, before)| after != before)
+        .collect();
+    Ok((mask, sorted))
+}
+
+/// Sort every slice in `slices` on its own with mergesort, such as the rows
+/// of a matrix. Instead of stopping at the first slice which fails to sort,
+/// every slice is sorted and a `Vec` with the result for each slice is
+/// returned, in the same order as `slices`, so you can see which slices
+/// were sorted and which were not.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::sort_each_by;
+///     let mut first = [3, 1, 2];
+///     let mut second = [9, 8];
+///     let mut rows = [&mut first[..], &mut second[..]];
+///     let results = sort_each_by(&mut rows, true, |a, b| a.cmp(b));
+///     assert!(results.iter().all(|result| result.is_ok()));
+///     assert_eq!(first, [1, 2, 3]);
+///     assert_eq!(second, [8, 9]);
+/// ```
+pub fn sort_each_by<F, T>(
+    slices: &mut [&mut [T]],
+    ascending: bool,
+    compare: F
+) -> Vec<AgcResult<()>>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    slices
+        .iter_mut()
+        .map(|slice| mergesort_by(*slice, ascending, compare).map(|_| ()))
+        .collect()
+}
+
+/// Sort the rows of a table by several of its columns. `column_order` lists
+/// the columns to sort by as `(column, ascending)` pairs, from the most
+/// important to the least important. Rows are ordered by the first column in
+/// `column_order`, rows which are equal in that column are ordered by the
+/// second one, and so on. Rows which are equal in every listed column keep
+/// the order that they were in.
+/// 
+/// An `AgcError` of kind `OutOfBounds` is returned and `rows` is left
+/// untouched if any column in `column_order` is too big for one of the rows.
+/// The rows are sorted with `stable_adapter_by` using mergesort, so they are
+/// moved around without being cloned.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::sort_columns_by;
+///     let mut rows = vec![vec![1, 2], vec![2, 1], vec![3, 2], vec![4, 1]];
+///     // Column 1 in ascending order, then column 0 in descending order
+///     sort_columns_by(&mut rows, &[(1, true), (0, false)]).unwrap();
+///     assert_eq!(rows, [[4, 1], [2, 1], [3, 2], [1, 2]]);
+/// ```
+pub fn sort_columns_by<T: Ord>(
+    rows: &mut [Vec<T>],
+    column_order: &[(usize, bool)]
+) -> AgcResult<()> {
+    let width = rows.iter().map(|row| row.len()).min().unwrap_or(0);
+    if let Some((column, _)) = column_order
+        .iter()
+        .find(|(column, _)| !rows.is_empty() && *column >= width)
+    {
+        return Err(AgcError::new(
+            AgcErrorKind::OutOfBounds,
+            format!(
+                "column {} is out of bounds for a row of length {}",
+                column, width
+            )
+        ));
+    }
+    stable_adapter_by(rows, SortAlgorithm::Merge, true, |a, b| {
+        column_order
+            .iter()
+            .map(|(column, ascending)| {
+                let ordering = a[*column].cmp(&b[*column]);
+                if *ascending {ordering} else {ordering.reverse()}
+            })
+            .find(|ordering| !priority::is_eq(*ordering))
+            .unwrap_or(Ordering::Equal)
+    })?;
+    Ok(())
+}
+
+/// Find the smallest number of swaps needed to sort `sequence` in ascending
+/// order. `sequence` is not changed.
+/// 
+/// Sorting `sequence` moves each element from its index to the index where
+/// it belongs, which forms a permutation. A permutation can be split into
+/// cycles, where each element in a cycle belongs at the index of the next
+/// one. A cycle of length `c` takes `c - 1` swaps to put right, since each
+/// swap puts 1 element in its place and the last swap puts 2. So the total
+/// number of swaps is `n` minus the number of cycles. The permutation is
+/// found with `argsort_by`, so this takes O(n log n) time.
+/// 
+/// If `sequence` has equal elements, they are assumed to keep their order,
+/// so the count may be higher than the true minimum, which could swap equal
+/// elements past each other.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::min_swaps_to_sort;
+///     // Swap 4 with 1 and 3 with 2
+///     assert_eq!(min_swaps_to_sort(&[4, 3, 2, 1][..]), 2);
+///     // 3 -> 1 -> 2 -> 3 is a cycle of length 3
+///     assert_eq!(min_swaps_to_sort(&[3, 1, 2][..]), 2);
+/// ```
+pub fn min_swaps_to_sort<S, T>(sequence: &S) -> usize
+where
+    S: AsRef<[T]> + ?Sized,
+    T: Ord
+{
+    let order = argsort_by(sequence, true, |a, b| a.cmp(b));
+    let mut visited = vec![false; order.len()];
+    let mut cycles = 0;
+    for start in 0..order.len() {
+        if visited[start] {
+            continue;
+        }
+        cycles += 1;
+        let mut index = start;
+        while !visited[index] {
+            visited[index] = true;
+            index = order[index];
+        }
+    }
+    order.len() - cycles
 }
\ No newline at end of file
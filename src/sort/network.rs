@@ -0,0 +1,88 @@
+//! Sorting networks for sorting very small slices.
+//! 
+//! A sorting network is a fixed list of comparators, each of which compares
+//! the elements at 2 indices and swaps them if they are out of order. The
+//! same comparisons are made no matter what the elements are, which makes
+//! them very fast on tiny slices as there is no bookkeeping to do. The
+//! networks here use the smallest number of comparators known to be
+//! possible for each length.
+
+use std::cmp::Ordering;
+use crate::{
+    error::AgcResult,
+    sort::insertionsort::insertionsort_by,
+    utils::{cmp::debug_check_swap, priority}
+};
+
+/// The largest slice that `sort_small_by` has a sorting network for.
+pub const MAX_NETWORK_LENGTH: usize = 8;
+
+/// The comparators of the sorting network for each length, from 0 to
+/// `MAX_NETWORK_LENGTH`.
+const NETWORKS: [&[(usize, usize)]; MAX_NETWORK_LENGTH + 1] = [
+    &[],
+    &[],
+    &[(0, 1)],
+    &[(0, 2), (0, 1), (1, 2)],
+    &[(0, 1), (2, 3), (0, 2), (1, 3), (1, 2)],
+    &[
+        (0, 3), (1, 4), (0, 2), (1, 3), (0, 1), (2, 4), (1, 2), (3, 4),
+        (2, 3)
+    ],
+    &[
+        (0, 5), (1, 3), (2, 4), (1, 2), (3, 4), (0, 3), (2, 5), (0, 1),
+        (2, 3), (4, 5), (1, 2), (3, 4)
+    ],
+    &[
+        (0, 6), (2, 3), (4, 5), (0, 2), (1, 4), (3, 6), (0, 1), (2, 5),
+        (3, 4), (1, 2), (4, 6), (2, 3), (4, 5), (1, 2), (3, 4), (5, 6)
+    ],
+    &[
+        (0, 2), (1, 3), (4, 6), (5, 7), (0, 4), (1, 5), (2, 6), (3, 7),
+        (0, 1), (2, 3), (4, 5), (6, 7), (2, 4), (3, 5), (1, 4), (3, 6),
+        (1, 2), (3, 4), (5, 6)
+    ]
+];
+
+/// Sort a slice with at most `MAX_NETWORK_LENGTH` elements using the
+/// smallest known sorting network for its length. Longer slices are sorted
+/// with `insertionsort_by` instead. You can choose whether to sort in
+/// ascending or descending order by toggling the `ascending` argument
+/// between `true` or `false`. This function requires another function to
+/// tell it the order whether 1 element is larger or smaller than the other
+/// element.
+/// 
+/// Sorting networks are not stable.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::network::sort_small_by;
+///     let mut array = [3, 1, 4, 1, 5, 9, 2, 6];
+///     sort_small_by(&mut array[..], true, |a, b| a.cmp(b)).unwrap();
+///     assert_eq!(array, [1, 1, 2, 3, 4, 5, 6, 9]);
+/// ```
+pub fn sort_small_by<F, T>(
+    slice: &mut [T],
+    ascending: bool,
+    compare: F
+) -> AgcResult<()>
+where
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let network = match NETWORKS.get(slice.len()) {
+        Some(network) => network,
+        None => {
+            insertionsort_by(slice, ascending, compare)?;
+            return Ok(());
+        }
+    };
+    for (first, second) in network.iter() {
+        let ordering = compare(&slice[*first], &slice[*second]);
+        if (ascending && priority::is_gt(ordering))
+        || (!ascending && priority::is_lt(ordering)) {
+            slice.swap(*first, *second);
+            debug_check_swap(slice, *first, *second, ascending, compare);
+        }
+    }
+    Ok(())
+}
\ No newline at end of file
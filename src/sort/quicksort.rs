@@ -1,52 +1,76 @@
 //! Quicksort functions
-//! 
-//! **Currently not working**
 
 use std::{
     cmp::{Ord, Ordering},
-    convert::AsMut
+    convert::{AsMut, AsRef}
 };
 use crate::{
     alreadysorted,
     error::{AgcResult, AgcError, AgcErrorKind},
-    utils::priority
+    macros::internal,
+    utils::{priority, random::XorShift}
 };
 
-/// The partition function used in quicksort. It takes a pivot element in the
-/// `sequence` and moves the elements smaller than the pivot to the front of
-/// the sequence and the elements larger than the pivot to the back of the
-/// sequence assuming that you are sorting in ascending order. `left` is the
-/// index of the first element in the slice and `right` is the length of the
-/// slice of the `sequence` you want to partition.
-/// `compare` is the function used to check whether 2 elements are smaller,
-/// equal to or greater than each other.
-/// 
-/// # Example
-/// ```
-///     use algocol::sort::quicksort::partition;
-///     let mut sequence = [10, 80, 30, 90, 40, 50, 70];
-///     partition(&mut sequence, 0, 7, true, |a, b| a.cmp(b)).unwrap();
-///     assert_eq!(sequence, [10, 30, 40, 50, 70, 90, 80]);
-/// ```
-pub fn partition<F, S, T>(
-    sequence: &mut S,
+internal! {
+    /// The partition function used in quicksort. It takes a pivot element in
+    /// the `sequence` and moves the elements smaller than the pivot to the
+    /// front of the sequence and the elements larger than the pivot to the back
+    /// of the sequence assuming that you are sorting in ascending order. `left`
+    /// is the index of the first element in the slice and `right` is the length
+    /// of the slice of the `sequence` you want to partition. An `AgcError` of
+    /// kind `WrongOrder` is returned if `left` is not less than `right`, as an
+    /// empty slice has no pivot to partition around. `compare` is the function
+    /// used to check whether 2 elements are smaller, equal to or greater than
+    /// each other.
+    /// 
+    /// This function is only public if the `internals` feature is enabled.
+    /// 
+    /// # Example
+    #[cfg_attr(feature = "internals", doc = "```")]
+    #[cfg_attr(not(feature = "internals"), doc = "```ignore")]
+    ///     use algocol::sort::quicksort::partition;
+    ///     let mut sequence = [10, 80, 30, 90, 40, 50, 70];
+    ///     partition(&mut sequence, 0, 7, true, |a, b| a.cmp(b)).unwrap();
+    ///     assert_eq!(sequence, [10, 30, 40, 50, 70, 90, 80]);
+    /// ```
+    pub fn partition<F, S, T>(
+        sequence: &mut S,
+        left: usize,
+        right: usize,
+        ascending: bool,
+        compare: F
+    ) -> AgcResult<usize>
+    where
+        S: AsMut<[T]> + ?Sized,
+        F: Fn(&T, &T) -> Ordering + Copy
+    {
+        let mut swaps = 0;
+        counted_partition(
+            sequence.as_mut(), left, right, ascending, compare, &mut swaps
+        )
+    }
+}
+
+/// Partition `sequence` like `partition`, adding the number of swaps made to
+/// `swaps`. Swapping an element with itself is skipped and not counted.
+pub(crate) fn counted_partition<F, T>(
+    sequence: &mut [T],
     left: usize,
     right: usize,
     ascending: bool,
-    compare: F
+    compare: F,
+    swaps: &mut usize
 ) -> AgcResult<usize>
 where
-    S: AsMut<[T]> + ?Sized,
     F: Fn(&T, &T) -> Ordering + Copy
 {
-    let sequence = sequence.as_mut();
     let length = sequence.len();
     alreadysorted!(result length, return 0);
-    if left > right {
+    if left >= right {
         return Err(AgcError::new(
             AgcErrorKind::WrongOrder,
             format!(
-                "Left ({}) must be less than or equal to right ({})",
+                "Left ({}) must be less than right ({})",
                 left,
                 right
             )
@@ -95,15 +119,213 @@ where
         let ordering = compare(&sequence[hare], &sequence[pivot]);
         if (priority::is_le(ordering) && ascending)
         || (priority::is_ge(ordering) && !ascending) {
-            sequence.swap(tortoise, hare);
+            if tortoise != hare {
+                sequence.swap(tortoise, hare);
+                *swaps += 1;
+            }
             tortoise += 1;
         }
     }
     // Put the pivot element after the last element smaller than pivot.
-    sequence.swap(tortoise, pivot);
+    if tortoise != pivot {
+        sequence.swap(tortoise, pivot);
+        *swaps += 1;
+    }
     Ok(tortoise)
 }
 
+internal! {
+    /// Partition the elements from `left` up to but not including `right`
+    /// like `partition`, except that the pivot is picked at random instead of
+    /// always being the last element. `rng` must return a random index from
+    /// `left` up to but not including `right`, and the element at that index
+    /// is swapped to the end of the slice before partitioning around it. If
+    /// `rng` returns an index outside of that range, an `AgcError` of kind
+    /// `OutOfBounds` is returned. The index of the pivot after partitioning
+    /// is returned.
+    /// 
+    /// Always using the last element as the pivot makes quicksort take
+    /// O(n^2) time on sorted slices, as every partition only splits off the
+    /// pivot. A random pivot splits the slice evenly often enough that the
+    /// expected time is O(n log n) no matter what the input looks like.
+    /// 
+    /// This function is only public if the `internals` feature is enabled.
+    /// 
+    /// # Example
+    #[cfg_attr(feature = "internals", doc = "```")]
+    #[cfg_attr(not(feature = "internals"), doc = "```ignore")]
+    ///     use algocol::sort::quicksort::partition_randomized;
+    ///     let mut sequence = [10, 80, 30, 90, 40, 50, 70];
+    ///     // The element at index 4 (40) is used as the pivot
+    ///     let pivot = partition_randomized(
+    ///         &mut sequence, 0, 7, true, |a, b| a.cmp(b), || 4
+    ///     ).unwrap();
+    ///     assert_eq!(pivot, 2);
+    ///     assert_eq!(sequence[pivot], 40);
+    /// ```
+    pub fn partition_randomized<F, S, T, R>(
+        sequence: &mut S,
+        left: usize,
+        right: usize,
+        ascending: bool,
+        compare: F,
+        mut rng: R
+    ) -> AgcResult<usize>
+    where
+        S: AsMut<[T]> + ?Sized,
+        F: Fn(&T, &T) -> Ordering + Copy,
+        R: FnMut() -> usize
+    {
+        let sequence = sequence.as_mut();
+        let index = rng();
+        if index < left || index >= right || right > sequence.len() {
+            return Err(AgcError::new(
+                AgcErrorKind::OutOfBounds,
+                format!(
+                    "The random pivot ({}) must be from left ({}) up to but \
+                    not including right ({})",
+                    index,
+                    left,
+                    right
+                )
+            ));
+        }
+        sequence.swap(index, right - 1);
+        partition(sequence, left, right, ascending, compare)
+    }
+}
+
+internal! {
+    /// Partition the elements from `left` up to but not including `right`
+    /// like `partition`, except that the pivot is the median of the first,
+    /// middle and last elements of the range. Those 3 elements are put in
+    /// order first, and the median is then swapped to the end of the range
+    /// so that it is used as the pivot. Ranges with fewer than 3 elements
+    /// are partitioned normally. The index of the pivot after partitioning
+    /// is returned.
+    /// 
+    /// On a slice which is already sorted in either direction, the median of
+    /// the 3 elements is the middle one, so each partition splits the range
+    /// in half instead of only splitting off the pivot.
+    /// 
+    /// This function is only public if the `internals` feature is enabled.
+    /// 
+    /// # Example
+    #[cfg_attr(feature = "internals", doc = "```")]
+    #[cfg_attr(not(feature = "internals"), doc = "```ignore")]
+    ///     use algocol::sort::quicksort::partition_median3;
+    ///     let mut sequence = [1, 2, 3, 4, 5, 6, 7];
+    ///     let pivot = partition_median3(
+    ///         &mut sequence, 0, 7, true, |a, b| a.cmp(b)
+    ///     ).unwrap();
+    ///     assert_eq!(pivot, 3);
+    ///     assert_eq!(sequence[pivot], 4);
+    /// ```
+    pub fn partition_median3<F, S, T>(
+        sequence: &mut S,
+        left: usize,
+        right: usize,
+        ascending: bool,
+        compare: F
+    ) -> AgcResult<usize>
+    where
+        S: AsMut<[T]> + ?Sized,
+        F: Fn(&T, &T) -> Ordering + Copy
+    {
+        let mut swaps = 0;
+        counted_partition_median3(
+            sequence.as_mut(), left, right, ascending, compare, &mut swaps
+        )
+    }
+}
+
+/// Partition `sequence` like `partition_median3`, adding the number of swaps
+/// made to `swaps`.
+fn counted_partition_median3<F, T>(
+    sequence: &mut [T],
+    left: usize,
+    right: usize,
+    ascending: bool,
+    compare: F,
+    swaps: &mut usize
+) -> AgcResult<usize>
+where
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    if left < right && right <= sequence.len() && right - left >= 3 {
+        let middle = left + (right - left) / 2;
+        let last = right - 1;
+        let out_of_order = |a: &T, b: &T| if ascending {
+            priority::is_gt(compare(a, b))
+        } else {
+            priority::is_lt(compare(a, b))
+        };
+        let mut swap = |sequence: &mut [T], a: usize, b: usize| {
+            sequence.swap(a, b);
+            *swaps += 1;
+        };
+        // Sort the first, middle and last elements
+        if out_of_order(&sequence[left], &sequence[middle]) {
+            swap(sequence, left, middle);
+        }
+        if out_of_order(&sequence[middle], &sequence[last]) {
+            swap(sequence, middle, last);
+            if out_of_order(&sequence[left], &sequence[middle]) {
+                swap(sequence, left, middle);
+            }
+        }
+        swap(sequence, middle, last);
+    }
+    counted_partition(sequence, left, right, ascending, compare, swaps)
+}
+
+/// Check that `slice` has been correctly partitioned around the element at
+/// `pivot_index`. If `ascending` is `true`, every element before the pivot
+/// must be less than or equal to the pivot and every element after it must be
+/// greater than or equal to the pivot. If `ascending` is `false`, it is the
+/// other way around. `compare` is the function used to compare 2 elements.
+/// 
+/// This is the condition that `partition` must fulfil for quicksort to work,
+/// so this function can be used to test `partition` without relying on the
+/// exact order that it leaves the other elements in. `false` is returned if
+/// `pivot_index` is out of bounds.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::quicksort::verify_partition_by;
+///     let sequence = [10, 30, 40, 50, 70, 90, 80];
+///     assert!(verify_partition_by(&sequence, 4, true, |a, b| a.cmp(b)));
+///     assert!(!verify_partition_by(&sequence, 5, true, |a, b| a.cmp(b)));
+/// ```
+pub fn verify_partition_by<F, S, T>(
+    slice: &S,
+    pivot_index: usize,
+    ascending: bool,
+    compare: F
+) -> bool
+where
+    S: AsRef<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let slice = slice.as_ref();
+    if pivot_index >= slice.len() {
+        return false;
+    }
+    let pivot = &slice[pivot_index];
+    let before_ok = |element: &T| if ascending {
+        priority::is_le(compare(element, pivot))
+    } else {
+        priority::is_ge(compare(element, pivot))
+    };
+    let after_ok = |element: &T| if ascending {
+        priority::is_ge(compare(element, pivot))
+    } else {
+        priority::is_le(compare(element, pivot))
+    };
+    slice[..pivot_index].iter().all(before_ok)
+    && slice[pivot_index+1..].iter().all(after_ok)
+}
+
 /// Sort a slice using the quicksort algorithm. The algorithm picks a pivot in
 /// the slice and puts the items smaller than it to the left of it and those
 /// larger than it to the right of it. The slice then gets split in 2, the
@@ -156,6 +378,33 @@ pub fn quicksort_by<F, S, T>(
     ascending: bool,
     compare: F
 ) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    quicksort_counted_by(sequence, ascending, compare)
+        .map(|(_, sequence)| sequence)
+}
+
+/// Sort a slice with quicksort like `quicksort_by`, but also return the
+/// number of swaps made by the partitions. Swapping an element with itself
+/// is skipped and not counted, so a partition which leaves every element
+/// where it was makes no swaps.
+/// 
+/// # Example
+/// ```
+///    use algocol::sort::quicksort::quicksort_counted_by;
+///    let mut sequence = (0..100).collect::<Vec<i32>>();
+///    let (swaps, _) = quicksort_counted_by(
+///        &mut sequence[..], true, |a, b| a.cmp(b)
+///    ).unwrap();
+///    assert_eq!(swaps, 0);
+/// ```
+pub fn quicksort_counted_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<(usize, &mut [T])>
 where
     S: AsMut<[T]> + ?Sized,
     F: Fn(&T, &T) -> Ordering + Copy
@@ -164,34 +413,37 @@ where
     struct SegmentPair {
         pub start: usize,
         pub end: usize
-    };
+    }
 
     let sequence = sequence.as_mut();
     let length = sequence.len();
-    alreadysorted!(result length, return sequence);
+    let mut swaps = 0;
+    alreadysorted!(result length, return (swaps, sequence));
 
     // `stack` stores the segments of the sequences yet to be partitioned
     let mut stack: Vec<SegmentPair> = Vec::new();
     stack.push(SegmentPair {start: 0, end: length-1});
     // If there are still segments to be partitioned
     while let Some(segment) = stack.pop() {
-        let pivot = partition(
+        let pivot = counted_partition(
             sequence,
             segment.start,
             segment.end+1,
             ascending,
-            compare
+            compare,
+            &mut swaps
         )?;
-        // If the pivot is in the middle of the segment, then push the 2
-        // subsegments
-        if pivot > segment.start + 1 {
+        // Push whichever subsegments on either side of the pivot are not
+        // empty. `pivot > segment.start` also keeps `pivot-1` from
+        // underflowing when the pivot ends up at index 0.
+        if pivot > segment.start {
             stack.push(SegmentPair {start: segment.start, end: pivot-1});
         }
-        if pivot + 1 < segment.end {
+        if pivot < segment.end {
             stack.push(SegmentPair {start: pivot + 1, end: segment.end});
         }
     }
-    Ok(sequence)
+    Ok((swaps, sequence))
 }
 
 /// Sort a slice using the quicksort algorithm. The algorithm picks a pivot in
@@ -246,6 +498,212 @@ pub fn quicksort_recursively_by<F, S, T>(
     ascending: bool,
     compare: F
 ) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    quicksort_recursively_counted_by(sequence, ascending, compare)
+        .map(|(_, sequence)| sequence)
+}
+
+/// Sort a slice with recursive quicksort like `quicksort_recursively_by`,
+/// but also return the number of swaps made by the partitions. The pivots
+/// are the same as in `quicksort_counted_by`, so both make the same number
+/// of swaps.
+/// 
+/// # Example
+/// ```
+///    use algocol::sort::quicksort::quicksort_recursively_counted_by;
+///    let mut sequence = [3, 1, 2];
+///    let (swaps, sorted) = quicksort_recursively_counted_by(
+///        &mut sequence[..], true, |a, b| a.cmp(b)
+///    ).unwrap();
+///    assert_eq!(sorted, [1, 2, 3]);
+///    assert_eq!(swaps, 2);
+/// ```
+pub fn quicksort_recursively_counted_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<(usize, &mut [T])>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_mut();
+    let mut swaps = 0;
+    recursive_quicksort(sequence, ascending, false, compare, &mut swaps)?;
+    Ok((swaps, sequence))
+}
+
+/// Sort a slice like `quicksort_recursively_by` and return the recursion
+/// depth it reached along with the sorted slice. The depth is the largest
+/// number of nested calls which each had at least 2 elements to sort, so a
+/// slice with fewer than 2 elements has a depth of 0.
+/// 
+/// As the last element of each segment is used as the pivot, a slice which
+/// is already sorted only has its pivot split off in each call, which makes
+/// the depth `n - 1` for `n` elements. A good pivot splits each segment
+/// roughly in half, giving a depth of about `log2(n)` (compare this with
+/// `quicksort_median3_recursively_depth_by`).
+/// 
+/// # Example
+/// ```
+///    use algocol::sort::quicksort::quicksort_recursively_depth_by;
+///    let mut sequence = (0..100).collect::<Vec<i32>>();
+///    let (depth, _) = quicksort_recursively_depth_by(
+///        &mut sequence[..], true, |a, b| a.cmp(b)
+///    ).unwrap();
+///    assert_eq!(depth, 99);
+/// ```
+pub fn quicksort_recursively_depth_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<(usize, &mut [T])>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_mut();
+    let mut swaps = 0;
+    let depth = recursive_quicksort(
+        sequence, ascending, false, compare, &mut swaps
+    )?;
+    Ok((depth, sequence))
+}
+
+/// Sort a slice like `quicksort_recursively_by`, but with the median of the
+/// first, middle and last elements of each segment as the pivot (see
+/// `partition_median3`), and return the recursion depth it reached along
+/// with the sorted slice. The depth is measured in the same way as in
+/// `quicksort_recursively_depth_by`.
+/// 
+/// On a slice which is already sorted in either direction, every segment is
+/// split in half, so the depth is about `log2(n)` for `n` elements.
+/// 
+/// # Example
+/// ```
+///    use algocol::sort::quicksort::quicksort_median3_recursively_depth_by;
+///    let mut sequence = (0..100).collect::<Vec<i32>>();
+///    let (depth, _) = quicksort_median3_recursively_depth_by(
+///        &mut sequence[..], true, |a, b| a.cmp(b)
+///    ).unwrap();
+///    assert!(depth <= 7);
+/// ```
+pub fn quicksort_median3_recursively_depth_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<(usize, &mut [T])>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_mut();
+    let mut swaps = 0;
+    let depth = recursive_quicksort(
+        sequence, ascending, true, compare, &mut swaps
+    )?;
+    Ok((depth, sequence))
+}
+
+/// Sort `sequence` with recursive quicksort, picking pivots with
+/// `partition_median3` if `median3` is `true` or `partition` otherwise, and
+/// return the recursion depth reached. The number of swaps made is added to
+/// `swaps`.
+fn recursive_quicksort<F, T>(
+    sequence: &mut [T],
+    ascending: bool,
+    median3: bool,
+    compare: F,
+    swaps: &mut usize
+) -> AgcResult<usize>
+where
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let length = sequence.len();
+    alreadysorted!(result length, return 0);
+    let pivot = if median3 {
+        counted_partition_median3(
+            sequence, 0, length, ascending, compare, swaps
+        )?
+    } else {
+        counted_partition(sequence, 0, length, ascending, compare, swaps)?
+    };
+    // If `pivot` is 0, the first half is empty and returns straight away
+    let (before, rest) = sequence.split_at_mut(pivot);
+    let before = recursive_quicksort(
+        before,
+        ascending,
+        median3,
+        compare,
+        swaps
+    )?;
+    let after = recursive_quicksort(
+        &mut rest[1..],
+        ascending,
+        median3,
+        compare,
+        swaps
+    )?;
+    Ok(1 + before.max(after))
+}
+
+/// Sort a slice using quicksort, but with a randomly chosen pivot for each
+/// segment (see `partition_randomized`). The random indices come from a
+/// `XorShift` generator seeded with `seed`, so the same seed always sorts a
+/// slice in the same way.
+/// 
+/// Picking the pivot at random means that no particular input, such as an
+/// already sorted slice, makes this take O(n^2) time every time. The
+/// expected time is O(n log n) for every input.
+/// 
+/// # Example
+/// ```
+///    use algocol::sort::quicksort::quicksort_randomized;
+///    let mut sequence = (0..100).collect::<Vec<i32>>();
+///    quicksort_randomized(&mut sequence[..], false, 42).unwrap();
+///    assert_eq!(sequence, (0..100).rev().collect::<Vec<i32>>());
+/// ```
+pub fn quicksort_randomized<S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    seed: u64
+) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    T: Ord
+{
+    quicksort_randomized_by(sequence, ascending, seed, |a, b| a.cmp(b))
+}
+
+/// Sort a slice using quicksort, but with a randomly chosen pivot for each
+/// segment (see `partition_randomized`). The random indices come from a
+/// `XorShift` generator seeded with `seed`, so the same seed always sorts a
+/// slice in the same way.
+/// 
+/// Picking the pivot at random means that no particular input, such as an
+/// already sorted slice, makes this take O(n^2) time every time. The
+/// expected time is O(n log n) for every input.
+/// 
+/// This function requires a `compare` function to work.
+/// 
+/// # Example
+/// ```
+///    use algocol::sort::quicksort::quicksort_randomized_by;
+///    let mut sequence = (0..100).collect::<Vec<i32>>();
+///    quicksort_randomized_by(
+///        &mut sequence[..], false, 42, |a, b| a.cmp(b)
+///    ).unwrap();
+///    assert_eq!(sequence, (0..100).rev().collect::<Vec<i32>>());
+/// ```
+pub fn quicksort_randomized_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    seed: u64,
+    compare: F
+) -> AgcResult<&mut [T]>
 where
     S: AsMut<[T]> + ?Sized,
     F: Fn(&T, &T) -> Ordering + Copy
@@ -253,8 +711,164 @@ where
     let sequence = sequence.as_mut();
     let length = sequence.len();
     alreadysorted!(result length, return sequence);
-    let pivot = partition(sequence, 0, length, ascending, compare)?;
-    quicksort_recursively_by(&mut sequence[..pivot], ascending, compare)?;
-    quicksort_recursively_by(&mut sequence[pivot+1..], ascending, compare)?;
+    let mut random = XorShift::new(seed);
+    // Segments yet to be partitioned, from `start` up to but not including
+    // `end`
+    let mut stack = vec![(0, length)];
+    while let Some((start, end)) = stack.pop() {
+        let pivot = partition_randomized(
+            sequence,
+            start,
+            end,
+            ascending,
+            compare,
+            || start + random.next_below(end - start)
+        )?;
+        if pivot > start + 1 {
+            stack.push((start, pivot));
+        }
+        if pivot + 2 < end {
+            stack.push((pivot + 1, end));
+        }
+    }
     Ok(sequence)
+}
+
+/// Sort a slice using quicksort, but with the median of the first, middle
+/// and last elements of each segment as the pivot (see
+/// `partition_median3`). Unlike `quicksort`, this takes O(n log n) time on
+/// slices which are already sorted in either direction, although some
+/// other inputs can still make it take O(n^2) time.
+/// 
+/// # Example
+/// ```
+///    use algocol::sort::quicksort::quicksort_median3;
+///    let mut sequence = (0..100).collect::<Vec<i32>>();
+///    quicksort_median3(&mut sequence[..], false).unwrap();
+///    assert_eq!(sequence, (0..100).rev().collect::<Vec<i32>>());
+/// ```
+pub fn quicksort_median3<S, T>(
+    sequence: &mut S,
+    ascending: bool
+) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    T: Ord
+{
+    quicksort_median3_by(sequence, ascending, |a, b| a.cmp(b))
+}
+
+/// Sort a slice using quicksort, but with the median of the first, middle
+/// and last elements of each segment as the pivot (see
+/// `partition_median3`). Unlike `quicksort_by`, this takes O(n log n) time
+/// on slices which are already sorted in either direction, although some
+/// other inputs can still make it take O(n^2) time.
+/// 
+/// This function requires a `compare` function to work.
+/// 
+/// # Example
+/// ```
+///    use algocol::sort::quicksort::quicksort_median3_by;
+///    let mut sequence = (0..100).collect::<Vec<i32>>();
+///    quicksort_median3_by(
+///        &mut sequence[..], false, |a, b| a.cmp(b)
+///    ).unwrap();
+///    assert_eq!(sequence, (0..100).rev().collect::<Vec<i32>>());
+/// ```
+pub fn quicksort_median3_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_mut();
+    let length = sequence.len();
+    alreadysorted!(result length, return sequence);
+    // Segments yet to be partitioned, from `start` up to but not including
+    // `end`
+    let mut stack = vec![(0, length)];
+    while let Some((start, end)) = stack.pop() {
+        let pivot = partition_median3(
+            sequence,
+            start,
+            end,
+            ascending,
+            compare
+        )?;
+        if pivot > start + 1 {
+            stack.push((start, pivot));
+        }
+        if pivot + 2 < end {
+            stack.push((pivot + 1, end));
+        }
+    }
+    Ok(sequence)
+}
+
+/// Rearrange `sequence` so that the element which would be at index `k` if
+/// the sequence were sorted is moved to index `k` (this is called
+/// quickselect). Every element before index `k` comes before or is equal to
+/// the `k`th element, and every element after it comes after or is equal to
+/// it, in the order given by `ascending`. The rest of the sequence is not
+/// sorted.
+/// 
+/// The part of the sequence before index `k`, the `k`th element and the part
+/// after it are returned, like `slice::select_nth_unstable`, so that they
+/// can be worked on further. For example, the first part holds the `k`
+/// smallest elements when sorting in ascending order. An `AgcError` of kind
+/// `OutOfBounds` is returned if `k` is not a valid index.
+/// 
+/// This works like quicksort, except that after each partition, only the
+/// side which contains index `k` is partitioned again. This takes O(n) time
+/// on average, but O(n^2) in the worst case, such as when the sequence is
+/// already sorted, as the last element of each segment is used as the pivot.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::quicksort::select_nth_in_place_by;
+///     let mut sequence = [9, 1, 8, 2, 7, 3, 6];
+///     let (smaller, median, larger) = select_nth_in_place_by(
+///         &mut sequence, 3, true, |a, b| a.cmp(b)
+///     ).unwrap();
+///     assert_eq!(*median, 6);
+///     assert!(smaller.iter().all(|x| *x <= 6));
+///     assert!(larger.iter().all(|x| *x >= 6));
+/// ```
+pub fn select_nth_in_place_by<F, S, T>(
+    sequence: &mut S,
+    k: usize,
+    ascending: bool,
+    compare: F
+) -> AgcResult<(&mut [T], &mut T, &mut [T])>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_mut();
+    let length = sequence.len();
+    if k >= length {
+        return Err(AgcError::new(
+            AgcErrorKind::OutOfBounds,
+            format!("k ({}) must be less than length ({})", k, length)
+        ));
+    }
+    // The `k`th element is always in `[left, right)`.
+    let mut left = 0;
+    let mut right = length;
+    while right - left > 1 {
+        let pivot = partition(sequence, left, right, ascending, compare)?;
+        if pivot == k {
+            break;
+        } else if k < pivot {
+            right = pivot;
+        } else {
+            left = pivot + 1;
+        }
+    }
+    let (before, rest) = sequence.split_at_mut(k);
+    let (nth, after) = rest.split_first_mut().unwrap();
+    Ok((before, nth, after))
 }
\ No newline at end of file
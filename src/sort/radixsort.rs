@@ -0,0 +1,90 @@
+//! Stores the radix sort functions.
+//! 
+//! Radix sort does not compare elements with each other. Instead, it sorts
+//! unsigned integers 1 byte at a time, starting from the least significant
+//! byte (LSD), with a stable counting pass for each byte. Since each pass is
+//! stable, elements with the same byte in the current pass stay in the order
+//! given to them by the less significant bytes. This takes O(n * k) time,
+//! where `k` is the number of bytes in each integer, and O(n) extra memory
+//! for a buffer which the elements are moved into during each pass.
+
+use std::convert::AsMut;
+use crate::error::AgcResult;
+
+/// Unsigned integers which can be sorted by `radixsort_lsd`.
+pub trait RadixKey: Copy {
+    /// The number of bytes in this type, which is the number of passes
+    /// `radixsort_lsd` makes.
+    const BYTES: usize;
+
+    /// Get the byte of this value at `index`, where byte 0 is the least
+    /// significant byte.
+    fn byte(self, index: usize) -> u8;
+}
+
+macro_rules! impl_radix_key {
+    ($($t: ty),*) => {
+        $(
+            impl RadixKey for $t {
+                const BYTES: usize = std::mem::size_of::<$t>();
+
+                fn byte(self, index: usize) -> u8 {
+                    (self >> (index * 8)) as u8
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_key!(u8, u16, u32, u64, usize);
+
+/// Sort a slice of unsigned integers with least significant digit radix
+/// sort, using each byte as a digit (base 256). Every pass counts how many
+/// elements have each value of the current byte, which tells it where each
+/// element goes in a buffer, before moving the elements there in their
+/// current order. After the last pass, the elements are copied back into
+/// `sequence`, in reverse if `ascending` is `false`.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::radixsort::radixsort_lsd;
+///     let mut array = [1000u32, 7, 65536, 256, 0];
+///     radixsort_lsd(&mut array[..], true).unwrap();
+///     assert_eq!(array, [0, 7, 256, 1000, 65536]);
+/// ```
+pub fn radixsort_lsd<S, T>(
+    sequence: &mut S,
+    ascending: bool
+) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    T: RadixKey
+{
+    let sequence = sequence.as_mut();
+    let mut current = sequence.to_vec();
+    let mut buffer = current.clone();
+    for index in 0..T::BYTES {
+        let mut starts = [0usize; 256];
+        for value in current.iter() {
+            starts[value.byte(index) as usize] += 1;
+        }
+        // Turn the counts into the index of the first element with each byte
+        let mut total = 0;
+        for start in starts.iter_mut() {
+            let count = *start;
+            *start = total;
+            total += count;
+        }
+        for value in current.iter() {
+            let start = &mut starts[value.byte(index) as usize];
+            buffer[*start] = *value;
+            *start += 1;
+        }
+        std::mem::swap(&mut current, &mut buffer);
+    }
+    if !ascending {
+        current.reverse();
+    }
+    sequence.copy_from_slice(&current);
+    Ok(sequence)
+}
\ No newline at end of file
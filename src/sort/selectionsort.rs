@@ -7,15 +7,17 @@ use std::{
 use crate::{
     alreadysorted,
     error::AgcResult,
-    utils::{priority, slice::transfer_element}
+    utils::{cmp::debug_check_swap, priority}
 };
 
 /// This function sorts a slice using the selection sort algorithm. In each
 /// iteration of this algorithm, the next smallest item is taken and
-/// transferred to the front of the slice, until all items have been checked
+/// swapped to the front of the slice, until all items have been checked
 /// and moved if necessary.
 /// 
-/// This algorithm's time complexity is O(n^2).
+/// This algorithm's time complexity is O(n^2), but at most n - 1 swaps are
+/// made. Swapping can move an element past another element equal to it, so
+/// selection sort is not stable.
 /// In the worst case scenario, (n^2 - n)/2 operations are made.
 /// 
 /// # Example
@@ -40,11 +42,13 @@ where
 
 /// This function sorts a slice using the selection sort algorithm. In each
 /// iteration of this algorithm, the next smallest item is taken and
-/// transferred to the front of the slice, until all items have been checked
+/// swapped to the front of the slice, until all items have been checked
 /// and moved if necessary. A function must be supplied to see whether one
 /// object is greater or smaller than the other.
 /// 
-/// This algorithm's time complexity is O(n^2).
+/// This algorithm's time complexity is O(n^2), but at most n - 1 swaps are
+/// made. Swapping can move an element past another element equal to it, so
+/// selection sort is not stable.
 /// In the worst case scenario, (n^2 - n)/2 operations are made.
 /// 
 /// # Example
@@ -61,28 +65,61 @@ pub fn selectionsort_by<F, T, S>(
     ascending: bool,
     compare: F
 ) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    selectionsort_counted_by(sequence, ascending, compare)
+        .map(|(_, sequence)| sequence)
+}
+
+/// Sort a slice with selection sort like `selectionsort_by`, but also return
+/// the number of swaps made. Selection sort swaps each element into place at
+/// most once, so this is never more than n - 1, and elements which are
+/// already in the right place are not swapped.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::selectionsort::selectionsort_counted_by;
+///     let mut array = [1, 2, 5, 4, 3];
+///     let (swaps, sorted) = selectionsort_counted_by(
+///         &mut array[..], true, |a, b| a.cmp(b)
+///     ).unwrap();
+///     assert_eq!(sorted, [1, 2, 3, 4, 5]);
+///     assert_eq!(swaps, 1);
+/// ```
+pub fn selectionsort_counted_by<F, T, S>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<(usize, &mut [T])>
 where
     S: AsMut<[T]> + ?Sized,
     F: Fn(&T, &T) -> Ordering + Copy
 {
     let sequence = sequence.as_mut();
     let length = sequence.len();
-    alreadysorted!(result length, return sequence);
+    let mut swaps = 0;
+    alreadysorted!(result length, return (swaps, sequence));
     for subsequence in 0..length {
         let mut extreme: usize = subsequence;
         for (index, element) in sequence.iter().enumerate() {
             if index <= subsequence {
                 continue;
             }
-            if ascending
-            && priority::is_lt(compare(element, &sequence[extreme])) {
-                extreme = index;
-            } else if !ascending
-            && priority::is_gt(compare(element, &sequence[extreme])) {
+            let ordering = compare(element, &sequence[extreme]);
+            if (priority::is_lt(ordering) && ascending)
+            || (priority::is_gt(ordering) && !ascending) {
                 extreme = index;
             }
         }
-        transfer_element(sequence, extreme, subsequence)?;
+        if extreme != subsequence {
+            sequence.swap(extreme, subsequence);
+            debug_check_swap(
+                sequence, subsequence, extreme, ascending, compare
+            );
+            swaps += 1;
+        }
     }
-    Ok(sequence)
+    Ok((swaps, sequence))
 }
\ No newline at end of file
@@ -0,0 +1,205 @@
+//! Defines shell sort functions.
+
+use std::{
+    cmp::{Ord, Ordering},
+    convert::AsMut
+};
+use crate::{
+    alreadysorted,
+    error::{AgcError, AgcErrorKind, AgcResult},
+    sort::insertionsort::gapped_insertion_pass
+};
+
+/// The gaps found by Marcin Ciura to work well for shell sort, in ascending
+/// order. `shellsort_by` extends this sequence by multiplying the last gap by
+/// 2.25 for slices longer than the largest gap here.
+pub const CIURA_GAPS: [usize; 8] = [1, 4, 10, 23, 57, 132, 301, 701];
+
+/// Shell sort is a generalisation of insertion sort. Instead of only
+/// comparing adjacent elements, shell sort first insertion sorts the elements
+/// which are far apart from each other, which lets elements move to where
+/// they belong in fewer swaps. The distance between these elements (the gap)
+/// is lowered after each pass until it becomes 1, at which point the last
+/// pass is an ordinary insertion sort over a slice which is almost sorted.
+/// You can choose whether to sort in ascending or descending order by
+/// toggling the `ascending` argument between `true` or `false`.
+/// 
+/// This function uses Ciura's gap sequence. See `shellsort_by` for more
+/// details. Shell sort is not stable.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::shellsort::shellsort;
+///     let mut array = [5, 4, 3, 2, 1];
+///     shellsort(&mut array[..], true).unwrap();
+///     assert_eq!(array, [1, 2, 3, 4, 5]);
+/// ```
+pub fn shellsort<S, T>(
+    sequence: &mut S,
+    ascending: bool
+) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    T: Ord
+{
+    shellsort_by(sequence, ascending, |a, b| a.cmp(b))
+}
+
+/// Shell sort is a generalisation of insertion sort. Instead of only
+/// comparing adjacent elements, shell sort first insertion sorts the elements
+/// which are far apart from each other, which lets elements move to where
+/// they belong in fewer swaps. The distance between these elements (the gap)
+/// is lowered after each pass until it becomes 1, at which point the last
+/// pass is an ordinary insertion sort over a slice which is almost sorted.
+/// This function requires another function to tell it the order whether 1
+/// element is larger or smaller than the other element.
+/// 
+/// The gaps used are Ciura's gap sequence (see `CIURA_GAPS`), which is
+/// extended by multiplying the last gap by 2.25 for longer slices. Only the
+/// gaps smaller than the length of the slice are used. No tight bound on the
+/// time complexity of this gap sequence is known, but it is one of the
+/// fastest in practice. Use `shellsort_with_gaps_by` to choose your own gap
+/// sequence.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::shellsort::shellsort_by;
+///     let mut array = [1, 5, 2, 4, 3];
+///     shellsort_by(&mut array[..], false, |a, b| a.cmp(b)).unwrap();
+///     assert_eq!(array, [5, 4, 3, 2, 1]);
+/// ```
+pub fn shellsort_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<&mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    shellsort_counted_by(sequence, ascending, compare)
+        .map(|(_, sequence)| sequence)
+}
+
+/// Sort a slice with shell sort like `shellsort_by`, but also return the
+/// number of swaps made by all of the passes together. The early passes with
+/// large gaps move elements a long way in a single swap, so the last pass
+/// has far fewer swaps to make than insertion sort would on its own.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::shellsort::shellsort_counted_by;
+///     let mut array = [5, 4, 3, 2, 1];
+///     let (swaps, sorted) = shellsort_counted_by(
+///         &mut array[..], true, |a, b| a.cmp(b)
+///     ).unwrap();
+///     assert_eq!(sorted, [1, 2, 3, 4, 5]);
+///     assert_eq!(swaps, 4);
+/// ```
+pub fn shellsort_counted_by<F, S, T>(
+    sequence: &mut S,
+    ascending: bool,
+    compare: F
+) -> AgcResult<(usize, &mut [T])>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_mut();
+    let length = sequence.len();
+    let mut gaps = CIURA_GAPS.to_vec();
+    let mut last = CIURA_GAPS[CIURA_GAPS.len()-1];
+    while last < length {
+        last = last * 9 / 4;
+        gaps.push(last);
+    }
+    gaps.retain(|gap| *gap < length);
+    gaps.reverse();
+    shellsort_with_gaps_counted_by(sequence, ascending, &gaps, compare)
+}
+
+/// Sort a slice using shell sort with your own sequence of gaps. `gaps` must
+/// be strictly decreasing and must not contain 0, otherwise an `AgcError` of
+/// kind `WrongOrder` is returned before `sequence` is changed. Gaps which are
+/// not smaller than the length of `sequence` are skipped.
+/// 
+/// The slice is only guaranteed to be sorted if the last gap is 1. If it is
+/// not, each pass only sorts the elements which are that many indices apart
+/// from each other, which can be useful when you want an h-sorted slice.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::shellsort::shellsort_with_gaps_by;
+///     let mut array = [9, 3, 7, 1, 8, 2, 6, 4, 5];
+///     let gaps = [5, 3, 1];
+///     shellsort_with_gaps_by(&mut array[..], true, &gaps, |a, b| a.cmp(b))
+///         .unwrap();
+///     assert_eq!(array, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+///     assert!(shellsort_with_gaps_by(&mut array[..], true, &[1, 3], |a, b| {
+///         a.cmp(b)
+///     }).is_err());
+/// ```
+pub fn shellsort_with_gaps_by<'a, F, S, T>(
+    sequence: &'a mut S,
+    ascending: bool,
+    gaps: &[usize],
+    compare: F
+) -> AgcResult<&'a mut [T]>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    shellsort_with_gaps_counted_by(sequence, ascending, gaps, compare)
+        .map(|(_, sequence)| sequence)
+}
+
+/// Sort a slice using shell sort with your own sequence of gaps like
+/// `shellsort_with_gaps_by`, but also return the number of swaps made. This
+/// can be used to compare how much work different gap sequences save.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::shellsort::shellsort_with_gaps_counted_by;
+///     let mut array = [4, 3, 2, 1];
+///     let (swaps, _) = shellsort_with_gaps_counted_by(
+///         &mut array[..], true, &[1], |a, b| a.cmp(b)
+///     ).unwrap();
+///     assert_eq!(swaps, 6);
+///     let mut array = [4, 3, 2, 1];
+///     let (swaps, _) = shellsort_with_gaps_counted_by(
+///         &mut array[..], true, &[2, 1], |a, b| a.cmp(b)
+///     ).unwrap();
+///     assert_eq!(swaps, 4);
+/// ```
+pub fn shellsort_with_gaps_counted_by<'a, F, S, T>(
+    sequence: &'a mut S,
+    ascending: bool,
+    gaps: &[usize],
+    compare: F
+) -> AgcResult<(usize, &'a mut [T])>
+where
+    S: AsMut<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let sequence = sequence.as_mut();
+    if gaps.contains(&0) {
+        return Err(AgcError::new(
+            AgcErrorKind::WrongOrder,
+            "gaps cannot contain 0"
+        ));
+    }
+    if gaps.windows(2).any(|pair| pair[0] <= pair[1]) {
+        return Err(AgcError::new(
+            AgcErrorKind::WrongOrder,
+            "gaps must be strictly decreasing"
+        ));
+    }
+    let length = sequence.len();
+    alreadysorted!(result length, return (0, sequence));
+    let swaps = gaps
+        .iter()
+        .filter(|gap| **gap < length)
+        .map(|gap| gapped_insertion_pass(sequence, *gap, ascending, compare))
+        .sum();
+    Ok((swaps, sequence))
+}
\ No newline at end of file
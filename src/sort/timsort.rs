@@ -6,8 +6,10 @@ use std::{
 };
 use crate::{
     alreadysorted,
+    binarysearch::exponential_search_by,
     error::AgcResult,
-    sort::{s_insert_if, merge}
+    sort::{s_insert_if, merge_buffered},
+    utils::priority
 };
 
 /// Timsort splits an array into slices of 32 elements (a run) each and sorts
@@ -17,6 +19,10 @@ use crate::{
 /// `run` in any of the timsort functions.
 pub const DEFAULT_RUN: usize = 32;
 
+/// The number of elements that `merge_galloping_by` must take from the same
+/// run in a row before it starts galloping.
+pub const MIN_GALLOP: usize = 7;
+
 /// Tim sort is a combination of merge sort and insertion sort. It is meant to
 /// work better than merge sort on data that is commonly seen in real-world
 /// contexts and was first implemented for sorting lists in Python by Tim
@@ -45,7 +51,7 @@ pub fn timsort<S, T>(
 ) -> AgcResult<&mut [T]>
 where
     S: AsMut<[T]> + ?Sized,
-    T: Ord
+    T: Ord + Clone
 {
     timsort_by(sequence, ascending, run, |a, b| a.cmp(b))
 }
@@ -65,7 +71,9 @@ where
 /// `DEFAULT_RUN`.
 /// 
 /// This function requires a `compare` function to compare 2 elements with
-/// each other.
+/// each other. The runs are merged with `merge_buffered`, which copies them
+/// into a buffer so that each merge takes O(n) time, so `T` must implement
+/// `Clone`.
 /// 
 /// # Example
 /// ```
@@ -85,6 +93,7 @@ pub fn timsort_by<F, S, T>(
 ) -> AgcResult<&mut [T]>
 where
     S: AsMut<[T]> + ?Sized,
+    T: Clone,
     F: Fn(&T, &T) -> Ordering + Copy
 {
     let sequence = sequence.as_mut();
@@ -114,9 +123,88 @@ where
             let middle = min(left+size-1, length-1);
             // The last element in the 2 sub-slices.
             let right = min(left+2*size-1, length-1);
-            merge(sequence, left, middle, right, ascending, compare)?;
+            merge_buffered(sequence, left, middle, right, ascending, compare)?;
         }
         size <<= 1;
     }
     Ok(sequence)
+}
+
+/// Merge 2 sorted runs, `left` and `right`, into a new `Vec`, galloping
+/// through long stretches of one run like timsort does. Both runs must be
+/// sorted in the order given by `ascending`. The merge is stable, so equal
+/// elements from `left` are put before the ones from `right`.
+/// 
+/// A normal merge compares the front elements of both runs to pick the next
+/// element, which takes 1 comparison per element. When one run has many
+/// elements in a row which come before the other run's front element, that
+/// is wasteful. Once `MIN_GALLOP` elements in a row have been taken from
+/// the same run, this function uses `exponential_search_by` to find how
+/// many more elements of that run come before the other run's front
+/// element, and copies all of them at once. A run of `k` such elements then
+/// only takes O(log k) comparisons.
+/// 
+/// # Example
+/// ```
+///     use algocol::sort::timsort::merge_galloping_by;
+///     let left = [1, 3, 5, 7];
+///     let right = [2, 4, 6, 8, 10, 12];
+///     let merged = merge_galloping_by(&left, &right, true, |a, b| a.cmp(b));
+///     assert_eq!(merged, [1, 2, 3, 4, 5, 6, 7, 8, 10, 12]);
+/// ```
+pub fn merge_galloping_by<F, T>(
+    left: &[T],
+    right: &[T],
+    ascending: bool,
+    compare: F
+) -> Vec<T>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let (mut i, mut j) = (0, 0);
+    let (mut left_wins, mut right_wins) = (0, 0);
+    // When galloping through `left`, equal elements from `left` must be
+    // taken too, so they are treated as coming before the element searched
+    // for.
+    let equal_before = |a: &T, b: &T| match compare(a, b) {
+        Ordering::Equal if ascending => Ordering::Greater,
+        Ordering::Equal => Ordering::Less,
+        ordering => ordering
+    };
+    while i < left.len() && j < right.len() {
+        let ordering = compare(&left[i], &right[j]);
+        if (priority::is_le(ordering) && ascending)
+        || (priority::is_ge(ordering) && !ascending) {
+            merged.push(left[i].clone());
+            i += 1;
+            left_wins += 1;
+            right_wins = 0;
+            if left_wins >= MIN_GALLOP {
+                let count = exponential_search_by(
+                    &left[i..], &right[j], ascending, equal_before
+                );
+                merged.extend_from_slice(&left[i..i+count]);
+                i += count;
+                left_wins = 0;
+            }
+        } else {
+            merged.push(right[j].clone());
+            j += 1;
+            right_wins += 1;
+            left_wins = 0;
+            if right_wins >= MIN_GALLOP {
+                let count = exponential_search_by(
+                    &right[j..], &left[i], ascending, compare
+                );
+                merged.extend_from_slice(&right[j..j+count]);
+                j += count;
+                right_wins = 0;
+            }
+        }
+    }
+    merged.extend_from_slice(&left[i..]);
+    merged.extend_from_slice(&right[j..]);
+    merged
 }
\ No newline at end of file
@@ -0,0 +1,53 @@
+//! Algorithms on strings, or any other sequence whose elements can be
+//! compared for equality.
+
+use std::convert::AsRef;
+
+/// Find the longest contiguous run of `sequence` which reads the same
+/// forwards and backwards. The start and end of the run are returned, with
+/// `start` being the index of its first element and `end` being the index
+/// after its last element, so the palindrome is `&sequence[start..end]`. If
+/// there are several palindromes with the same length, the one which starts
+/// first is returned. An empty sequence returns `(0, 0)`.
+/// 
+/// This uses the expand-around-centre technique. Every palindrome has a
+/// centre, which is either an element (if it has an odd length) or the gap
+/// between 2 elements (if it has an even length). There are `2n - 1`
+/// centres, and each of them is expanded outwards for as long as the
+/// elements on both sides are equal. This takes O(n^2) time and O(1) memory.
+/// Manacher's algorithm can do this in O(n) time by reusing the expansions
+/// done for earlier centres.
+/// 
+/// # Example
+/// ```
+///     use algocol::strings::longest_palindrome;
+///     let word = "babad".as_bytes();
+///     let (start, end) = longest_palindrome(word);
+///     assert_eq!(&word[start..end], "bab".as_bytes());
+/// ```
+pub fn longest_palindrome<S, T>(sequence: &S) -> (usize, usize)
+where
+    S: AsRef<[T]> + ?Sized,
+    T: Eq
+{
+    let sequence = sequence.as_ref();
+    let length = sequence.len();
+    let mut best = (0, 0);
+    // Centre `c` is the element at `c/2` if `c` is even, or the gap between
+    // `c/2` and `c/2 + 1` if `c` is odd.
+    for centre in 0..(2*length).saturating_sub(1) {
+        // `start` and `end` are the ends of the palindrome found so far, as
+        // a half-open range. It starts off as the single element at the
+        // centre, or as nothing if the centre is a gap.
+        let mut start = centre/2 + centre%2;
+        let mut end = centre/2 + 1;
+        while start > 0 && end < length && sequence[start-1] == sequence[end] {
+            start -= 1;
+            end += 1;
+        }
+        if end - start > best.1 - best.0 {
+            best = (start, end);
+        }
+    }
+    best
+}
\ No newline at end of file
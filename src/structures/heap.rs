@@ -0,0 +1,234 @@
+//! Functions for binary heaps stored in slices.
+//! 
+//! A binary heap is a complete binary tree stored level by level in a slice.
+//! The children of the element at index `i` are at indices `2i + 1` and
+//! `2i + 2`, and its parent is at index `(i - 1)/2`. In a max-heap, every
+//! element is greater than or equal to its children, so the largest element
+//! is at the front of the slice. In a min-heap, it is the other way around.
+
+use std::{
+    cmp::Ordering,
+    convert::AsRef
+};
+use crate::{
+    error::{AgcError, AgcErrorKind, AgcResult},
+    utils::priority
+};
+
+/// Check if `parent` is allowed to be the parent of `child`.
+fn dominates<F, T>(
+    parent: &T,
+    child: &T,
+    is_max_heap: bool,
+    compare: F
+) -> bool
+where
+    F: Fn(&T, &T) -> Ordering
+{
+    let ordering = compare(parent, child);
+    if is_max_heap {
+        priority::is_ge(ordering)
+    } else {
+        priority::is_le(ordering)
+    }
+}
+
+/// Check if `slice` is a valid binary heap. If `is_max_heap` is `true`, every
+/// element must be greater than or equal to its children, otherwise every
+/// element must be less than or equal to its children. `compare` is the
+/// function used to compare 2 elements. Empty slices and slices with 1
+/// element are always valid heaps.
+/// 
+/// This is meant to be used in tests for code which builds or changes heaps.
+/// 
+/// # Example
+/// ```
+///     use algocol::structures::heap::is_valid_heap_by;
+///     let heap = [9, 5, 8, 1, 4, 7];
+///     assert!(is_valid_heap_by(&heap[..], true, |a, b| a.cmp(b)));
+///     assert!(!is_valid_heap_by(&heap[..], false, |a, b| a.cmp(b)));
+/// ```
+pub fn is_valid_heap_by<F, S, T>(
+    slice: &S,
+    is_max_heap: bool,
+    compare: F
+) -> bool
+where
+    S: AsRef<[T]> + ?Sized,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let slice = slice.as_ref();
+    // Every element after the first has a parent, so it is enough to check
+    // each of them against its parent.
+    (1..slice.len()).all(|child| {
+        dominates(&slice[(child-1)/2], &slice[child], is_max_heap, compare)
+    })
+}
+
+/// Move the element at `index` up the heap in `slice`, swapping it with its
+/// parent until its parent dominates it (is greater than or equal to it in a
+/// max-heap, or less than or equal to it in a min-heap). This is used after
+/// pushing a new element onto the end of a heap. Every other element must
+/// already be in a valid position.
+/// 
+/// # Example
+/// ```
+///     use algocol::structures::heap::{is_valid_heap_by, sift_up_by};
+///     let mut heap = vec![9, 5, 8, 1];
+///     heap.push(7);
+///     sift_up_by(&mut heap[..], 4, true, |a, b| a.cmp(b));
+///     assert_eq!(heap, [9, 7, 8, 1, 5]);
+///     assert!(is_valid_heap_by(&heap[..], true, |a, b| a.cmp(b)));
+/// ```
+pub fn sift_up_by<F, T>(
+    slice: &mut [T],
+    mut index: usize,
+    is_max_heap: bool,
+    compare: F
+)
+where
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    while index > 0 {
+        let parent = (index-1)/2;
+        if dominates(&slice[parent], &slice[index], is_max_heap, compare) {
+            break;
+        }
+        slice.swap(parent, index);
+        index = parent;
+    }
+}
+
+/// Move the element at `index` down the heap in `slice`, swapping it with
+/// its more dominant child until it dominates both of its children. This is
+/// used after replacing the root of a heap. Both subtrees below `index` must
+/// already be valid heaps. The number of swaps made is returned.
+/// 
+/// # Example
+/// ```
+///     use algocol::structures::heap::{is_valid_heap_by, sift_down_by};
+///     let mut heap = [2, 7, 8, 1, 5];
+///     let swaps = sift_down_by(&mut heap[..], 0, true, |a, b| a.cmp(b));
+///     assert_eq!(heap, [8, 7, 2, 1, 5]);
+///     assert_eq!(swaps, 1);
+///     assert!(is_valid_heap_by(&heap[..], true, |a, b| a.cmp(b)));
+/// ```
+pub fn sift_down_by<F, T>(
+    slice: &mut [T],
+    mut index: usize,
+    is_max_heap: bool,
+    compare: F
+) -> usize
+where
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let length = slice.len();
+    let mut swaps = 0;
+    loop {
+        let mut top = index;
+        for child in [2*index + 1, 2*index + 2].iter() {
+            if *child < length
+            && !dominates(&slice[top], &slice[*child], is_max_heap, compare) {
+                top = *child;
+            }
+        }
+        if top == index {
+            break;
+        }
+        slice.swap(index, top);
+        swaps += 1;
+        index = top;
+    }
+    swaps
+}
+
+/// Rearrange `slice` into a valid binary heap, no matter what order its
+/// elements are in. This can be used to build a heap from scratch, or to fix
+/// a heap after some of its elements have been changed in place. If
+/// `is_max_heap` is `true`, a max-heap is built, otherwise a min-heap is
+/// built.
+/// 
+/// The heap is built from the bottom up: every element with children is
+/// sifted down, starting from the last one and working back to the root.
+/// When an element is sifted down, both of its subtrees are already valid
+/// heaps. Most elements are near the bottom of the tree and only move a
+/// short distance, so this takes O(n) time, which is faster than pushing the
+/// elements onto the heap one at a time. The number of swaps made is
+/// returned.
+/// 
+/// # Example
+/// ```
+///     use algocol::structures::heap::{is_valid_heap_by, repair_heap_by};
+///     let mut heap = [9, 5, 8, 1, 4, 7];
+///     heap[4] = 10;
+///     repair_heap_by(&mut heap[..], true, |a, b| a.cmp(b));
+///     assert_eq!(heap[0], 10);
+///     assert!(is_valid_heap_by(&heap[..], true, |a, b| a.cmp(b)));
+/// ```
+pub fn repair_heap_by<F, T>(
+    slice: &mut [T],
+    is_max_heap: bool,
+    compare: F
+) -> usize
+where
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    // Elements from `length/2` onwards have no children
+    (0..slice.len()/2)
+        .rev()
+        .map(|index| sift_down_by(slice, index, is_max_heap, compare))
+        .sum()
+}
+
+/// Find the `k`th largest item produced by `iter`, where the largest item is
+/// the 1st largest, according to `compare`. An `AgcError` of kind
+/// `OutOfBounds` is returned if `k` is 0 or `iter` produces fewer than `k`
+/// items.
+/// 
+/// Only the `k` largest items seen so far are kept, in a min-heap, so the
+/// smallest of them is at the root. Each new item which is larger than the
+/// root replaces it and is sifted down, and every other item is thrown away.
+/// At the end, the root is the `k`th largest item. This takes O(n log k)
+/// time and only O(k) memory, so `iter` can produce more items than would
+/// fit in memory.
+/// 
+/// # Example
+/// ```
+///     use algocol::structures::heap::kth_largest_streaming;
+///     let stream = vec![3, 9, 1, 7, 5, 8].into_iter();
+///     assert_eq!(kth_largest_streaming(stream, 2, |a, b| a.cmp(b)), Ok(8));
+/// ```
+pub fn kth_largest_streaming<F, I, T>(
+    iter: I,
+    k: usize,
+    compare: F
+) -> AgcResult<T>
+where
+    I: Iterator<Item = T>,
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    let mut heap: Vec<T> = Vec::with_capacity(k);
+    if k > 0 {
+        for item in iter {
+            if heap.len() < k {
+                heap.push(item);
+                let last = heap.len() - 1;
+                sift_up_by(&mut heap[..], last, false, compare);
+            } else if priority::is_gt(compare(&item, &heap[0])) {
+                heap[0] = item;
+                sift_down_by(&mut heap[..], 0, false, compare);
+            }
+        }
+    }
+    if k == 0 || heap.len() < k {
+        return Err(AgcError::new(
+            AgcErrorKind::OutOfBounds,
+            format!(
+                "k ({}) must be between 1 and the number of items ({})",
+                k,
+                heap.len()
+            )
+        ));
+    }
+    Ok(heap.swap_remove(0))
+}
\ No newline at end of file
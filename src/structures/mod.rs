@@ -0,0 +1,8 @@
+//! Data structures which organise their elements using the algorithms in
+//! this crate.
+
+pub mod heap;
+pub mod sorted_vec;
+pub mod treap;
+
+pub use self::{sorted_vec::SortedVec, treap::Treap};
\ No newline at end of file
@@ -0,0 +1,152 @@
+//! Defines `SortedVec`, a vector which keeps its elements sorted.
+//! 
+//! Every value is inserted at the position found by a binary search, so the
+//! elements are always in ascending order according to the comparison
+//! function given to the vector. This means that looking values up only
+//! takes O(log n) time, although each insertion or removal still has to
+//! shift the elements after it, which takes O(n) time.
+
+use std::cmp::{Ord, Ordering};
+use crate::binarysearch::binarysearch_unchecked_by;
+
+/// A vector whose elements are always sorted in ascending order according to
+/// `compare`. Values which compare as equal are kept in the order they were
+/// inserted in.
+/// 
+/// # Example
+/// ```
+///     use algocol::structures::SortedVec;
+///     let mut sorted = SortedVec::new();
+///     sorted.insert_many(vec![5, 1, 4, 2, 3]);
+///     assert_eq!(sorted.as_slice(), [1, 2, 3, 4, 5]);
+///     assert!(sorted.contains(&4));
+///     assert_eq!(sorted.remove_value(&4), Some(4));
+///     assert_eq!(sorted.range(&2, &5), [2, 3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SortedVec<T, F>
+where
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    elements: Vec<T>,
+    compare: F
+}
+
+impl<T: Ord> SortedVec<T, fn(&T, &T) -> Ordering> {
+    /// Create an empty `SortedVec` which sorts its elements by their natural
+    /// order.
+    pub fn new() -> Self {
+        Self::new_by(T::cmp)
+    }
+}
+
+impl<T: Ord> Default for SortedVec<T, fn(&T, &T) -> Ordering> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, F> SortedVec<T, F>
+where
+    F: Fn(&T, &T) -> Ordering + Copy
+{
+    /// Create an empty `SortedVec` which sorts its elements using `compare`.
+    pub fn new_by(compare: F) -> Self {
+        Self {elements: Vec::new(), compare}
+    }
+
+    /// The number of elements in the vector.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Check if the vector has no elements in it.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// The elements of the vector, in sorted order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.elements[..]
+    }
+
+    /// Take the sorted elements out of the vector.
+    pub fn into_vec(self) -> Vec<T> {
+        self.elements
+    }
+
+    /// The index of the first element which is not less than `value`.
+    fn lower_bound(&self, value: &T) -> usize {
+        let compare = self.compare;
+        binarysearch_unchecked_by(
+            &self.elements,
+            value,
+            true,
+            |item, element| match compare(item, element) {
+                Ordering::Equal => Ordering::Less,
+                ordering => ordering
+            }
+        )
+    }
+
+    /// The index of the first element which is greater than `value`.
+    fn upper_bound(&self, value: &T) -> usize {
+        let compare = self.compare;
+        binarysearch_unchecked_by(
+            &self.elements,
+            value,
+            true,
+            |item, element| match compare(item, element) {
+                Ordering::Equal => Ordering::Greater,
+                ordering => ordering
+            }
+        )
+    }
+
+    /// Insert `value` into the vector, after any elements equal to it, and
+    /// return the index it was inserted at.
+    pub fn insert(&mut self, value: T) -> usize {
+        let index = self.upper_bound(&value);
+        self.elements.insert(index, value);
+        index
+    }
+
+    /// Insert every value in `values` into the vector.
+    pub fn insert_many<I>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>
+    {
+        for value in values {
+            self.insert(value);
+        }
+        self
+    }
+
+    /// Check if an element equal to `value` is in the vector. This takes
+    /// O(log n) time.
+    pub fn contains(&self, value: &T) -> bool {
+        let index = self.lower_bound(value);
+        index < self.len()
+        && (self.compare)(&self.elements[index], value) == Ordering::Equal
+    }
+
+    /// Remove the first element equal to `value` from the vector and return
+    /// it, or `None` if there is no such element.
+    pub fn remove_value(&mut self, value: &T) -> Option<T> {
+        if self.contains(value) {
+            let index = self.lower_bound(value);
+            Some(self.elements.remove(index))
+        } else {
+            None
+        }
+    }
+
+    /// The elements which are greater than or equal to `lo` but less than
+    /// `hi`, in sorted order. If `hi` is not greater than `lo`, the slice is
+    /// empty.
+    pub fn range(&self, lo: &T, hi: &T) -> &[T] {
+        let start = self.lower_bound(lo);
+        let end = self.lower_bound(hi).max(start);
+        &self.elements[start..end]
+    }
+}
\ No newline at end of file
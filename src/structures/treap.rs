@@ -0,0 +1,380 @@
+//! Defines the treap, a randomised binary search tree.
+//! 
+//! A treap (**tr**ee + h**eap**) gives every value a random priority when it
+//! is inserted. The values are arranged like a binary search tree, so that
+//! smaller values are to the left of a node and larger values are to the
+//! right of it. At the same time, the priorities are arranged like a max-heap,
+//! so that every node has a higher priority than its children. As the
+//! priorities are random, the shape of the tree is the same as if the values
+//! had been inserted into a plain binary search tree in a random order, which
+//! keeps the expected height of the tree at O(log n) no matter what order
+//! the values are actually inserted in.
+
+use std::{
+    cmp::{Ord, Ordering},
+    mem::swap
+};
+use crate::{
+    error::{AgcError, AgcErrorKind, AgcResult},
+    sort::is_sorted,
+    utils::random::XorShift
+};
+
+type Link<T> = Option<Box<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    priority: u64,
+    left: Link<T>,
+    right: Link<T>
+}
+
+/// A set of values stored in a treap. See the module-level documentation for
+/// how a treap works.
+/// 
+/// The priorities are generated by a `XorShift` generator, so treaps created
+/// with the same seed and given the same operations will always have the
+/// same shape.
+/// 
+/// # Example
+/// ```
+///     use algocol::structures::Treap;
+///     let mut treap = Treap::new(42);
+///     for value in [5, 1, 4, 2, 3].iter() {
+///         treap.insert(*value);
+///     }
+///     assert!(treap.contains(&4));
+///     assert!(treap.remove(&4));
+///     assert!(!treap.contains(&4));
+///     assert_eq!(treap.iter().collect::<Vec<_>>(), [&1, &2, &3, &5]);
+/// ```
+pub struct Treap<T: Ord> {
+    root: Link<T>,
+    length: usize,
+    random: XorShift
+}
+
+impl<T: Ord> Treap<T> {
+    /// Create an empty `Treap` whose priorities are generated from `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self {root: None, length: 0, random: XorShift::new(seed)}
+    }
+
+    /// Build a height-balanced treap from a slice sorted in ascending order,
+    /// with priorities generated from `seed`. Duplicate values are only
+    /// inserted once.
+    /// 
+    /// Inserting values one by one takes O(n log n) time on average, and the
+    /// shape of the tree is left up to chance. Since `slice` is already
+    /// sorted, this function can instead pick the middle value as the root,
+    /// build the left and right subtrees from the 2 halves on either side of
+    /// it, and repeat. This takes O(n) time and gives a tree with the lowest
+    /// possible height, `ceil(log2(n + 1))`.
+    /// 
+    /// Every node is given an independent random priority, just like
+    /// `insert` does. The priorities are then heapified from the bottom up:
+    /// once both subtrees of a node are heaps, the node's priority is swapped
+    /// with its higher child's priority until it is higher than both of its
+    /// children. Only the priorities move, so the shape of the tree and the
+    /// order of the values are kept. Like building a binary heap, this takes
+    /// O(n) time in total.
+    /// 
+    /// An `AgcError` of kind `Unordered` is returned if `slice` is not sorted
+    /// in ascending order.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::structures::Treap;
+    ///     let values = (0..15).collect::<Vec<i32>>();
+    ///     let treap = Treap::from_sorted_slice(&values[..], 42).unwrap();
+    ///     assert_eq!(treap.len(), 15);
+    ///     assert_eq!(treap.height(), 4);
+    ///     assert!(Treap::from_sorted_slice(&[3, 1, 2][..], 42).is_err());
+    /// ```
+    pub fn from_sorted_slice(slice: &[T], seed: u64) -> AgcResult<Self>
+    where
+        T: Clone
+    {
+        if !is_sorted(slice, true) {
+            return Err(AgcError::new(
+                AgcErrorKind::Unordered,
+                "slice is not sorted in ascending order."
+            ));
+        }
+        let mut treap = Self::new(seed);
+        let mut values = slice.iter().collect::<Vec<&T>>();
+        values.dedup();
+        treap.length = values.len();
+        treap.root = build_balanced(&values, &mut treap.random);
+        Ok(treap)
+    }
+
+    /// The number of values in the treap.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Check if the treap has no values in it.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// The number of nodes on the longest path from the root to a leaf. An
+    /// empty treap has a height of 0.
+    pub fn height(&self) -> usize {
+        fn height_of<T>(link: &Link<T>) -> usize {
+            match link {
+                Some(node) => {
+                    1 + height_of(&node.left).max(height_of(&node.right))
+                },
+                None => 0
+            }
+        }
+        height_of(&self.root)
+    }
+
+    /// Check if `value` is in the treap.
+    pub fn contains(&self, value: &T) -> bool {
+        self.depth(value).is_some()
+    }
+
+    /// The number of edges between the root and the node containing
+    /// `value`, or `None` if `value` is not in the treap. The root has a
+    /// depth of 0.
+    pub fn depth(&self, value: &T) -> Option<usize> {
+        let mut current = &self.root;
+        let mut depth = 0;
+        while let Some(node) = current {
+            current = match value.cmp(&node.value) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return Some(depth)
+            };
+            depth += 1;
+        }
+        None
+    }
+
+    /// Insert `value` into the treap. If an equal value is already in the
+    /// treap, the treap is left unchanged and `false` is returned, otherwise
+    /// `true` is returned.
+    pub fn insert(&mut self, value: T) -> bool {
+        let priority = self.random.next_u64();
+        let inserted = insert_into(&mut self.root, value, priority);
+        if inserted {
+            self.length += 1;
+        }
+        inserted
+    }
+
+    /// Remove `value` from the treap. `true` is returned if `value` was in
+    /// the treap, otherwise `false` is returned.
+    pub fn remove(&mut self, value: &T) -> bool {
+        let removed = remove_from(&mut self.root, value);
+        if removed {
+            self.length -= 1;
+        }
+        removed
+    }
+
+    /// Find the lowest common ancestor of `a` and `b`, which is the deepest
+    /// node that has both `a` and `b` in its subtree. A node counts as being
+    /// in its own subtree, so if `a` is an ancestor of `b`, `a` is returned.
+    /// If either `a` or `b` is not in the treap, `None` is returned.
+    /// 
+    /// Since the treap is a binary search tree, this is found by starting
+    /// from the root and going left while both values are smaller than the
+    /// current node, or right while both values are larger than it. The
+    /// first node where `a` and `b` are not on the same side is the lowest
+    /// common ancestor.
+    /// 
+    /// # Example
+    /// ```
+    ///     use algocol::structures::Treap;
+    ///     let mut treap = Treap::new(7);
+    ///     for value in 0..10 {
+    ///         treap.insert(value);
+    ///     }
+    ///     let ancestor = *treap.lowest_common_ancestor(&2, &6).unwrap();
+    ///     assert!(2 <= ancestor && ancestor <= 6);
+    ///     assert_eq!(treap.lowest_common_ancestor(&2, &10), None);
+    /// ```
+    pub fn lowest_common_ancestor(&self, a: &T, b: &T) -> Option<&T> {
+        if !self.contains(a) || !self.contains(b) {
+            return None;
+        }
+        let mut current = self.root.as_ref();
+        while let Some(node) = current {
+            current = match (a.cmp(&node.value), b.cmp(&node.value)) {
+                (Ordering::Less, Ordering::Less) => node.left.as_ref(),
+                (Ordering::Greater, Ordering::Greater) => node.right.as_ref(),
+                _ => return Some(&node.value)
+            };
+        }
+        None
+    }
+
+    /// Iterate over the values in the treap in ascending order (an in-order
+    /// traversal).
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter {stack: Vec::new()};
+        iter.push_left(&self.root);
+        iter
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a Treap<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// In-order iterator over the values in a `Treap`, created by `Treap::iter`.
+pub struct Iter<'a, T> {
+    stack: Vec<&'a Node<T>>
+}
+
+impl<'a, T> Iter<'a, T> {
+    /// Push `link` and all of its left descendants onto the stack so that the
+    /// smallest value not yet visited is at the top.
+    fn push_left(&mut self, mut link: &'a Link<T>) {
+        while let Some(node) = link {
+            self.stack.push(node);
+            link = &node.left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        Some(&node.value)
+    }
+}
+
+/// Build a height-balanced tree from `values`, which must be sorted, and
+/// heapify the random priorities of its nodes.
+fn build_balanced<T: Clone>(values: &[&T], random: &mut XorShift) -> Link<T> {
+    if values.is_empty() {
+        return None;
+    }
+    let middle = values.len()/2;
+    let mut node = Box::new(Node {
+        value: values[middle].clone(),
+        priority: random.next_u64(),
+        left: build_balanced(&values[..middle], random),
+        right: build_balanced(&values[middle+1..], random)
+    });
+    sift_priority_down(&mut node);
+    Some(node)
+}
+
+/// Swap the priority of `node` with the higher priority of its children
+/// until it is higher than both of them. Both subtrees of `node` must
+/// already satisfy the heap property.
+fn sift_priority_down<T>(node: &mut Node<T>) {
+    let child = match (priority_of(&node.left), priority_of(&node.right)) {
+        (Some(left), Some(right)) if right > left => &mut node.right,
+        (Some(_), _) => &mut node.left,
+        (None, Some(_)) => &mut node.right,
+        (None, None) => return
+    };
+    let child = child.as_mut().unwrap();
+    if child.priority > node.priority {
+        swap(&mut child.priority, &mut node.priority);
+        sift_priority_down(child);
+    }
+}
+
+/// Make the left child of `link` its parent.
+fn rotate_right<T>(link: &mut Link<T>) {
+    let mut root = link.take().unwrap();
+    let mut left = root.left.take().unwrap();
+    root.left = left.right.take();
+    left.right = Some(root);
+    *link = Some(left);
+}
+
+/// Make the right child of `link` its parent.
+fn rotate_left<T>(link: &mut Link<T>) {
+    let mut root = link.take().unwrap();
+    let mut right = root.right.take().unwrap();
+    root.right = right.left.take();
+    right.left = Some(root);
+    *link = Some(right);
+}
+
+fn priority_of<T>(link: &Link<T>) -> Option<u64> {
+    link.as_ref().map(|node| node.priority)
+}
+
+fn insert_into<T: Ord>(link: &mut Link<T>, value: T, priority: u64) -> bool {
+    let node = match link {
+        Some(node) => node,
+        None => {
+            *link = Some(Box::new(
+                Node {value, priority, left: None, right: None}
+            ));
+            return true;
+        }
+    };
+    // Insert the value like in a binary search tree, then rotate it upwards
+    // while its priority is higher than its parent's.
+    match value.cmp(&node.value) {
+        Ordering::Equal => false,
+        Ordering::Less => {
+            let inserted = insert_into(&mut node.left, value, priority);
+            if priority_of(&node.left) > Some(node.priority) {
+                rotate_right(link);
+            }
+            inserted
+        },
+        Ordering::Greater => {
+            let inserted = insert_into(&mut node.right, value, priority);
+            if priority_of(&node.right) > Some(node.priority) {
+                rotate_left(link);
+            }
+            inserted
+        }
+    }
+}
+
+fn remove_from<T: Ord>(link: &mut Link<T>, value: &T) -> bool {
+    let node = match link {
+        Some(node) => node,
+        None => return false
+    };
+    match value.cmp(&node.value) {
+        Ordering::Less => remove_from(&mut node.left, value),
+        Ordering::Greater => remove_from(&mut node.right, value),
+        Ordering::Equal => {
+            remove_root(link);
+            true
+        }
+    }
+}
+
+/// Remove the node at `link` by rotating it down until it becomes a leaf,
+/// always promoting the child with the higher priority to keep the heap
+/// property intact.
+fn remove_root<T>(link: &mut Link<T>) {
+    let node = link.as_mut().unwrap();
+    match (priority_of(&node.left), priority_of(&node.right)) {
+        (None, None) => *link = None,
+        (Some(_), None) => *link = node.left.take(),
+        (None, Some(_)) => *link = node.right.take(),
+        (Some(left), Some(right)) => if left > right {
+            rotate_right(link);
+            remove_root(&mut link.as_mut().unwrap().right);
+        } else {
+            rotate_left(link);
+            remove_root(&mut link.as_mut().unwrap().left);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,119 @@
+//! Utilities for testing the algorithms in this crate empirically.
+//! 
+//! The documentation of each algorithm describes its properties, such as
+//! whether a sort is stable. The functions in this module let you check
+//! those claims for yourself by running the algorithms on generated data.
+
+use crate::{
+    sort::SortAlgorithm,
+    utils::{cmp::CountingComparator, random::XorShift}
+};
+
+/// The number of different inputs `is_algorithm_stable` tries for each seed.
+const STABILITY_ROUNDS: usize = 8;
+
+/// Check whether `algorithm` is a stable sort, where elements which compare
+/// as equal keep the order that they were in before sorting.
+/// 
+/// This sorts vectors of `(key, index)` pairs by `key` only, where `index` is
+/// the position of each pair before sorting. The keys are generated from
+/// `seed` and chosen from a small range, so that there are plenty of equal
+/// keys. After sorting, the indices of pairs with equal keys must still be in
+/// ascending order if the sort is stable. This is repeated on several inputs
+/// of different lengths, in both ascending and descending order, and `true`
+/// is only returned if the order of equal keys was kept every time.
+/// 
+/// An unstable sort may happen to keep equal keys in order on some inputs,
+/// so `true` is strong evidence that an algorithm is stable, not a proof.
+/// 
+/// # Example
+/// ```
+///     use algocol::{sort::SortAlgorithm, testing::is_algorithm_stable};
+///     assert!(is_algorithm_stable(SortAlgorithm::Insertion, 1));
+///     assert!(!is_algorithm_stable(SortAlgorithm::Quick, 1));
+/// ```
+pub fn is_algorithm_stable(algorithm: SortAlgorithm, seed: u64) -> bool {
+    let mut random = XorShift::new(seed);
+    for round in 0..STABILITY_ROUNDS {
+        let length = 16 + round * 16;
+        let pairs = (0..length)
+            .map(|index| (random.next_below(length/4), index))
+            .collect::<Vec<(usize, usize)>>();
+        for ascending in [true, false].iter() {
+            let mut sorted = pairs.clone();
+            let result = algorithm.sort_by(
+                &mut sorted[..],
+                *ascending,
+                |a, b| a.0.cmp(&b.0)
+            );
+            if result.is_err() {
+                return false;
+            }
+            let stable = sorted.windows(2).all(|pair| {
+                pair[0].0 != pair[1].0 || pair[0].1 < pair[1].1
+            });
+            if !stable {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Measurements taken while a sorting algorithm was running, returned by
+/// `compare_algorithms`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Metrics {
+    /// The number of times 2 elements were compared.
+    pub comparisons: usize,
+    /// The number of times 2 elements were swapped, or `None` if the
+    /// algorithm moves elements with merges or rotations instead of swaps.
+    pub swaps: Option<usize>
+}
+
+/// Sort a copy of `input` with every algorithm in `SortAlgorithm` and
+/// measure how much work each of them did. The results are returned in the
+/// same order as `SortAlgorithm::ALL`, so they can be printed as a table to
+/// see how the algorithms compare on the same data.
+/// 
+/// The comparisons are counted with a `CountingComparator`, and the swaps
+/// are counted with `SortAlgorithm::sort_counted_by`. Every algorithm which
+/// only moves elements by swapping them reports its swaps, while `Merge`,
+/// `MergeRecursive`, `Tim` and `BinaryInsertion` report `None`.
+/// 
+/// # Example
+/// ```
+///     use algocol::{sort::SortAlgorithm, testing::compare_algorithms};
+///     let input = (0..64).rev().collect::<Vec<i64>>();
+///     let results = compare_algorithms(&input, true);
+///     for (algorithm, metrics) in results.iter() {
+///         println!("{:?}: {} comparisons", algorithm, metrics.comparisons);
+///     }
+///     let selection = results
+///         .iter()
+///         .find(|(algorithm, _)| *algorithm == SortAlgorithm::Selection)
+///         .map(|(_, metrics)| metrics.swaps);
+///     assert_eq!(selection, Some(Some(32)));
+/// ```
+pub fn compare_algorithms(
+    input: &[i64],
+    ascending: bool
+) -> Vec<(SortAlgorithm, Metrics)> {
+    SortAlgorithm::ALL
+        .iter()
+        .map(|algorithm| {
+            let counter = CountingComparator::new(|a: &i64, b: &i64| a.cmp(b));
+            let mut sequence = input.to_vec();
+            // None of the algorithms fail when sorting a whole slice, so the
+            // swaps are only missing for the algorithms which do not swap.
+            let swaps = algorithm
+                .sort_counted_by(&mut sequence[..], ascending, |a, b| {
+                    counter.compare(a, b)
+                })
+                .ok()
+                .and_then(|(swaps, _)| swaps);
+            let metrics = Metrics {comparisons: counter.count(), swaps};
+            (*algorithm, metrics)
+        })
+        .collect()
+}
\ No newline at end of file
@@ -0,0 +1,209 @@
+//! Helpers for working with the `compare` functions passed to the `_by`
+//! functions in this crate.
+
+use std::{
+    cell::Cell,
+    cmp::Ordering,
+    fmt::Debug
+};
+use crate::{
+    error::{AgcError, AgcErrorKind, AgcResult},
+    utils::priority
+};
+
+/// Wraps a `compare` function and counts how many times it has been called.
+/// This is useful for checking how many comparisons an algorithm makes,
+/// which is a common way of measuring how fast a sorting or searching
+/// algorithm is without depending on how fast your computer is.
+/// 
+/// The count is stored in a `Cell`, so comparisons can be counted through a
+/// shared reference. Since the `_by` functions in this crate expect `compare`
+/// to be `Copy`, pass in a closure which borrows the `CountingComparator`,
+/// like `|a, b| counter.compare(a, b)`.
+/// 
+/// # Example
+/// 
+/// Comparing the number of comparisons made by binary search and linear
+/// search on the same data:
+/// 
+/// ```
+///     use algocol::{
+///         binarysearch::binarysearch_unchecked_by,
+///         utils::cmp::CountingComparator
+///     };
+///     use std::cmp::Ordering;
+///     let array = (0..1000).collect::<Vec<i32>>();
+///     let counter = CountingComparator::new(|a: &i32, b: &i32| a.cmp(b));
+/// 
+///     binarysearch_unchecked_by(&array[..], &900, true, |a, b| {
+///         counter.compare(a, b)
+///     });
+///     let binary = counter.count();
+/// 
+///     counter.reset();
+///     array.iter().position(|x| counter.compare(x, &900) == Ordering::Equal);
+///     let linear = counter.count();
+/// 
+///     assert_eq!(linear, 901);
+///     assert!(binary < 15);
+/// ```
+#[derive(Debug)]
+pub struct CountingComparator<F> {
+    compare: F,
+    count: Cell<usize>
+}
+
+impl<F> CountingComparator<F> {
+    /// Wrap `compare` in a new `CountingComparator` with a count of 0.
+    pub fn new(compare: F) -> Self {
+        Self {compare, count: Cell::new(0)}
+    }
+
+    /// Compare `a` and `b` with the wrapped function and add 1 to the count.
+    pub fn compare<T>(&self, a: &T, b: &T) -> Ordering
+    where
+        F: Fn(&T, &T) -> Ordering
+    {
+        self.count.set(self.count.get() + 1);
+        (self.compare)(a, b)
+    }
+
+    /// The number of comparisons made since this `CountingComparator` was
+    /// created or last reset.
+    pub fn count(&self) -> usize {
+        self.count.get()
+    }
+
+    /// Set the count back to 0.
+    pub fn reset(&self) {
+        self.count.set(0);
+    }
+}
+
+/// Check that `compare` orders the elements of `samples` consistently, as a
+/// total order. The sorting and searching functions in this crate assume
+/// that `compare` is consistent, and can give strange results if it is not.
+/// The following rules are checked:
+/// 
+/// 1. Every element is equal to itself.
+/// 2. Antisymmetry: if `a` comes before `b`, then `b` comes after `a`, and
+///    if they are equal, they are equal either way round.
+/// 3. Transitivity: if `a` comes before (or is equal to) `b` and `b` comes
+///    before (or is equal to) `c`, then `a` comes before `c` (or is equal to
+///    it if all of them are equal).
+/// 
+/// Every pair and every triple of elements in `samples` is checked, which
+/// takes O(n^3) time, so only a small number of samples should be used. If a
+/// rule is broken, an `AgcError` of kind `InconsistentOrder` describing the
+/// elements which broke it is returned.
+/// 
+/// # Example
+/// ```
+///     use algocol::utils::cmp::validate_comparator;
+///     use std::cmp::Ordering;
+///     let samples = [3, 1, 4, 1, 5];
+///     assert!(validate_comparator(&samples, |a, b| a.cmp(b)).is_ok());
+///     // Treating every element as smaller than every other one is invalid
+///     let result = validate_comparator(&samples, |_, _| Ordering::Less);
+///     assert!(result.is_err());
+/// ```
+pub fn validate_comparator<T, F>(samples: &[T], compare: F) -> AgcResult<()>
+where
+    T: Debug,
+    F: Fn(&T, &T) -> Ordering
+{
+    let inconsistent = |description: String| {
+        Err(AgcError::new(AgcErrorKind::InconsistentOrder, description))
+    };
+    for a in samples.iter() {
+        let ordering = compare(a, a);
+        if ordering != Ordering::Equal {
+            return inconsistent(format!(
+                "{:?} is not equal to itself (compared as {:?}).",
+                a,
+                ordering
+            ));
+        }
+    }
+    for a in samples.iter() {
+        for b in samples.iter() {
+            let (forwards, backwards) = (compare(a, b), compare(b, a));
+            if forwards != backwards.reverse() {
+                return inconsistent(format!(
+                    "{:?} compared to {:?} is {:?}, but {:?} compared to {:?} \
+                    is {:?}.",
+                    a, b, forwards, b, a, backwards
+                ));
+            }
+        }
+    }
+    for a in samples.iter() {
+        for b in samples.iter() {
+            let first = compare(a, b);
+            for c in samples.iter() {
+                let second = compare(b, c);
+                // If either step is `Equal`, `a` and `c` must be ordered the
+                // same way as the other step. Otherwise, both steps must go
+                // in the same direction for the order of `a` and `c` to be
+                // known.
+                let expected = match (first, second) {
+                    (Ordering::Equal, ordering) => ordering,
+                    (ordering, Ordering::Equal) => ordering,
+                    (x, y) if x == y => x,
+                    _ => continue
+                };
+                let actual = compare(a, c);
+                if actual != expected {
+                    return inconsistent(format!(
+                        "({:?}, {:?}, {:?}) is not transitive: a to b is \
+                        {:?} and b to c is {:?}, but a to c is {:?}.",
+                        a, b, c, first, second, actual
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Check that `compare` agrees with a swap that a sort has just made. A sort
+/// swaps the elements at `first` and `second` (where `first` comes before
+/// `second`) because `compare` said that they were in the wrong order, so
+/// once they have been swapped, `compare` should say that they are in the
+/// right order. If it does not, `compare` must have broken one of the rules
+/// in `validate_comparator`, and this function panics with the indices of
+/// the 2 elements.
+/// 
+/// This is a debugging aid for finding bugs in `compare` functions, not a
+/// guarantee that a sort is correct, since only the swapped pair is checked.
+/// It only does anything when the `strict-debug` feature is enabled and debug
+/// assertions are on, so it costs nothing in release builds.
+#[inline]
+pub(crate) fn debug_check_swap<T, F>(
+    slice: &[T],
+    first: usize,
+    second: usize,
+    ascending: bool,
+    compare: F
+)
+where
+    F: Fn(&T, &T) -> Ordering
+{
+    if cfg!(all(debug_assertions, feature = "strict-debug")) {
+        let ordering = compare(&slice[first], &slice[second]);
+        let in_order = if ascending {
+            priority::is_le(ordering)
+        } else {
+            priority::is_ge(ordering)
+        };
+        debug_assert!(
+            in_order,
+            "the compare function is inconsistent: the elements at indices {} \
+            and {} were swapped because they were out of order, but they are \
+            still out of order after the swap (compared as {:?})",
+            first,
+            second,
+            ordering
+        );
+    }
+}
\ No newline at end of file
@@ -1,4 +1,6 @@
 //! Utility functions for `algocol`.
 
+pub mod cmp;
 pub mod priority;
+pub mod random;
 pub mod slice;
\ No newline at end of file
@@ -0,0 +1,56 @@
+//! A small pseudo-random number generator for algorithms which make random
+//! choices.
+//! 
+//! The algorithms in this crate which rely on randomness take a seed so that
+//! their results can be reproduced, which makes them easier to study and to
+//! test. The generator here is not suitable for anything which needs to be
+//! unpredictable, such as cryptography.
+
+/// Seed used in place of 0, as xorshift would only ever produce 0 from it.
+const ZERO_SEED_REPLACEMENT: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// A xorshift64 pseudo-random number generator. The same seed always
+/// produces the same sequence of numbers.
+/// 
+/// # Example
+/// ```
+///     use algocol::utils::random::XorShift;
+///     let mut a = XorShift::new(42);
+///     let mut b = XorShift::new(42);
+///     assert_eq!(a.next_u64(), b.next_u64());
+///     assert!(a.next_below(10) < 10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct XorShift {
+    state: u64
+}
+
+impl XorShift {
+    /// Create a new `XorShift` generator from a seed.
+    pub fn new(seed: u64) -> Self {
+        let state = if seed == 0 {ZERO_SEED_REPLACEMENT} else {seed};
+        Self {state}
+    }
+
+    /// Get the next pseudo-random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// Get a pseudo-random `usize` in the range `0..bound`.
+    /// 
+    /// # Panics
+    /// This function panics if `bound` is 0 as the range would be empty.
+    pub fn next_below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "bound must be greater than 0.");
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// Get a pseudo-random `bool`, like the result of a coin flip.
+    pub fn next_bool(&mut self) -> bool {
+        self.next_u64() >> 63 == 1
+    }
+}
\ No newline at end of file
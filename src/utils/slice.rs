@@ -2,6 +2,10 @@
 
 use crate::error::{AgcError, AgcResult, AgcErrorKind};
 
+pub use apply_permutation as sl_permute;
+pub use group_by_key as sl_group_f;
+pub use invert_permutation as sl_invert;
+pub use rotate_sorted as sl_rotate;
 pub use transfer_element as sl_move;
 
 /// Move an element in a slice to another part of the slice.
@@ -9,7 +13,7 @@ pub use transfer_element as sl_move;
 /// on where the element came `from` to the left or right, making space for
 /// the target element to move `to`.
 /// This function returns an error if `from` or `to` are out of bounds.
-///
+/// 
 ///  # Example
 /// ```
 ///     use algocol::utils::slice::transfer_element;
@@ -39,4 +43,222 @@ pub fn transfer_element<T>(
         slice[to..=from].rotate_right(1);
     }
     Ok(())
+}
+
+/// Split a slice into groups of consecutive elements which have the same key,
+/// returning an iterator over each group as a subslice. `key_of` is the
+/// function used to get the key of each element. This is similar to
+/// `slice::chunk_by` in the standard library, except that elements are
+/// compared through their keys.
+/// 
+/// Only consecutive elements are grouped together, so if you want every
+/// element with the same key to be in the same group, `slice` should be
+/// sorted by that key first. Empty slices produce no groups.
+/// 
+/// # Example
+/// ```
+///     use algocol::utils::slice::group_by_key;
+///     let array = [1, 3, 2, 4, 6, 5];
+///     let groups = group_by_key(&array[..], |x| x % 2).collect::<Vec<_>>();
+///     assert_eq!(groups, [&[1, 3][..], &[2, 4, 6][..], &[5][..]]);
+/// ```
+pub fn group_by_key<T, K, G>(
+    slice: &[T],
+    key_of: G
+) -> impl Iterator<Item = &[T]>
+where
+    K: Eq,
+    G: Fn(&T) -> K
+{
+    let mut rest = slice;
+    std::iter::from_fn(move || {
+        let first = key_of(rest.first()?);
+        let end = rest
+            .iter()
+            .position(|element| key_of(element) != first)
+            .unwrap_or(rest.len());
+        let (group, remaining) = rest.split_at(end);
+        rest = remaining;
+        Some(group)
+    })
+}
+
+/// Check if an index in a permutation has been flipped by
+/// `apply_permutation` to mark it as visited. Valid indices are never greater
+/// than `isize::MAX`, so their complements always are.
+#[inline]
+fn is_flipped(index: usize) -> bool {
+    index > isize::MAX as usize
+}
+
+/// Reorder `slice` so that the element at index `i` becomes the element that
+/// was at index `permutation[i]`. For example, if `permutation` is the result
+/// of an argsort (the indices of the elements of `slice` in sorted order),
+/// `slice` ends up sorted. Applying the same permutation to several slices
+/// reorders them in the same way, which is handy for parallel arrays.
+/// 
+/// `permutation` must be a permutation of `0..slice.len()`, meaning it is as
+/// long as `slice` and contains every index exactly once. If it is not, an
+/// `AgcError` of kind `InvalidArgument` is returned and `slice` is left
+/// unchanged.
+/// 
+/// This function follows each cycle in the permutation and swaps the
+/// elements along it, which takes O(n) time. Instead of allocating memory to
+/// remember which indices have already been moved, `permutation` is used as
+/// scratch space by temporarily flipping the bits of its elements, so its
+/// contents are changed while this function runs. They are restored before
+/// this function returns.
+/// 
+/// # Example
+/// ```
+///     use algocol::utils::slice::apply_permutation;
+///     let mut array = ['a', 'b', 'c', 'd'];
+///     let mut permutation = [2, 0, 3, 1];
+///     apply_permutation(&mut array[..], &mut permutation[..]).unwrap();
+///     assert_eq!(array, ['c', 'a', 'd', 'b']);
+///     assert_eq!(permutation, [2, 0, 3, 1]);
+/// ```
+pub fn apply_permutation<T>(
+    slice: &mut [T],
+    permutation: &mut [usize]
+) -> AgcResult<()> {
+    let length = slice.len();
+    if permutation.len() != length {
+        return Err(AgcError::new(
+            AgcErrorKind::InvalidArgument,
+            format!(
+                "permutation has {} indices but the slice has {} elements.",
+                permutation.len(),
+                length
+            )
+        ));
+    }
+    if let Some(index) = permutation.iter().find(|index| **index >= length) {
+        return Err(AgcError::new(
+            AgcErrorKind::InvalidArgument,
+            format!("Index {} in permutation is out of bounds.", index)
+        ));
+    }
+    // Walk through every cycle once without moving anything, flipping each
+    // index as it is visited. In a valid permutation, every cycle leads back
+    // to where it started. If a cycle runs into an index which has already
+    // been visited instead, some index appears twice.
+    for start in 0..length {
+        if is_flipped(permutation[start]) {
+            continue;
+        }
+        let mut current = start;
+        loop {
+            let next = permutation[current];
+            permutation[current] = !next;
+            if next == start {
+                break;
+            }
+            if is_flipped(permutation[next]) {
+                for index in permutation.iter_mut() {
+                    if is_flipped(*index) {
+                        *index = !*index;
+                    }
+                }
+                return Err(AgcError::new(
+                    AgcErrorKind::InvalidArgument,
+                    format!("Index {} appears more than once.", next)
+                ));
+            }
+            current = next;
+        }
+    }
+    // Walk through every cycle again, this time swapping the elements along
+    // the cycle into place and flipping the indices back.
+    for start in 0..length {
+        if !is_flipped(permutation[start]) {
+            continue;
+        }
+        let mut current = start;
+        loop {
+            let next = !permutation[current];
+            permutation[current] = next;
+            if next == start {
+                break;
+            }
+            slice.swap(current, next);
+            current = next;
+        }
+    }
+    Ok(())
+}
+
+/// Get the inverse of `permutation`, which is the permutation that undoes it.
+/// If `permutation[i] == j`, then the inverse has `inverse[j] == i`. Passing
+/// the inverse to `apply_permutation` after `permutation` puts a slice back
+/// in its original order.
+/// 
+/// If `permutation` is not a permutation of `0..permutation.len()`, an
+/// `AgcError` of kind `InvalidArgument` is returned.
+/// 
+/// # Example
+/// ```
+///     use algocol::utils::slice::invert_permutation;
+///     let permutation = [2, 0, 3, 1];
+///     assert_eq!(invert_permutation(&permutation[..]), Ok(vec![1, 3, 0, 2]));
+/// ```
+pub fn invert_permutation(permutation: &[usize]) -> AgcResult<Vec<usize>> {
+    let length = permutation.len();
+    // `length` can never be in a valid permutation, so it is used to mark the
+    // indices which have not been filled in yet.
+    let mut inverse = vec![length; length];
+    for (index, target) in permutation.iter().enumerate() {
+        if *target >= length {
+            return Err(AgcError::new(
+                AgcErrorKind::InvalidArgument,
+                format!("Index {} in permutation is out of bounds.", target)
+            ));
+        } else if inverse[*target] != length {
+            return Err(AgcError::new(
+                AgcErrorKind::InvalidArgument,
+                format!("Index {} appears more than once.", target)
+            ));
+        }
+        inverse[*target] = index;
+    }
+    Ok(inverse)
+}
+
+/// Rotate a sorted `slice` to the left by `pivot` positions, so that the
+/// element at index `pivot` becomes the first element and the elements
+/// before it are moved to the back. The result is a "rotated sorted" slice,
+/// which is made up of 2 sorted runs, such as `[3, 4, 0, 1, 2]`. An
+/// `AgcError` of kind `OutOfBounds` is returned if `pivot` is greater than
+/// the length of the slice.
+/// 
+/// This function does not check that `slice` is sorted. It is meant for
+/// building inputs for `binarysearch::search_rotated_by`, which can find an
+/// element in a slice rotated by this function without knowing `pivot`.
+/// 
+/// # Example
+/// ```
+///     use algocol::{
+///         binarysearch::search_rotated_by,
+///         utils::slice::rotate_sorted
+///     };
+///     let mut array = [0, 1, 2, 3, 4];
+///     rotate_sorted(&mut array[..], 3).unwrap();
+///     assert_eq!(array, [3, 4, 0, 1, 2]);
+///     let compare = |a: &i32, b: &i32| a.cmp(b);
+///     assert_eq!(search_rotated_by(&array[..], &1, true, compare), Some(3));
+/// ```
+pub fn rotate_sorted<T>(slice: &mut [T], pivot: usize) -> AgcResult<()> {
+    let length = slice.len();
+    if pivot > length {
+        return Err(AgcError::new(
+            AgcErrorKind::OutOfBounds,
+            format!(
+                "Pivot ({}) must be less than or equal to length ({})",
+                pivot,
+                length
+            )
+        ));
+    }
+    slice.rotate_left(pivot);
+    Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,118 @@
+extern crate algocol;
+
+#[test]
+fn test_local_extrema() {
+    use algocol::arrays::{local_extrema, local_extrema_by};
+    let array = [1, 3, 2, 4, 1];
+    let (minima, maxima) = local_extrema(&array[..]);
+    println!("local_extrema minima: {:?}, maxima: {:?}", minima, maxima);
+    assert_eq!(minima, [2]);
+    assert_eq!(maxima, [1, 3]);
+    let (minima, maxima) = local_extrema_by(&array[..], |a, b| b.cmp(a));
+    assert_eq!(minima, [1, 3]);
+    assert_eq!(maxima, [2]);
+    // Plateaus and endpoints are not extrema
+    assert_eq!(local_extrema(&[1, 3, 3, 1][..]), (vec![], vec![]));
+    assert_eq!(local_extrema(&[5, 1][..]), (vec![], vec![]));
+    assert_eq!(local_extrema::<[i32], i32>(&[][..]), (vec![], vec![]));
+}
+
+#[test]
+fn test_reservoir_sample() {
+    use algocol::arrays::reservoir_sample;
+    for length in [0, 1, 4, 5, 6, 50].iter() {
+        let sample = reservoir_sample(0..*length, 5, 7);
+        println!("reservoir_sample of {}: {:?}", length, sample);
+        assert_eq!(sample.len(), 5.min(*length));
+        let mut unique = sample.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), sample.len());
+    }
+    assert_eq!(reservoir_sample(0..20, 3, 11), reservoir_sample(0..20, 3, 11));
+    // Over many seeds every element should turn up in a sample, and with
+    // roughly the same frequency
+    let mut counts = [0; 10];
+    for seed in 1..=2000 {
+        for item in reservoir_sample(0..10, 3, seed) {
+            counts[item] += 1;
+        }
+    }
+    println!("reservoir_sample counts: {:?}", counts);
+    for count in counts.iter() {
+        assert!(*count > 450 && *count < 750);
+    }
+    assert!(reservoir_sample(0..10, 0, 1).is_empty());
+}
+
+#[test]
+fn test_longest_consecutive() {
+    use algocol::arrays::longest_consecutive;
+    let array = [100, 4, 200, 1, 3, 2];
+    let longest = longest_consecutive(&array);
+    println!("longest_consecutive: {}", longest);
+    assert_eq!(longest, 4);
+    let empty: [i64; 0] = [];
+    assert_eq!(longest_consecutive(&empty), 0);
+    assert_eq!(longest_consecutive(&[7, 7, 7]), 1);
+    assert_eq!(longest_consecutive(&[0, -1, 1, 5, 3, 2, 9]), 5);
+    assert_eq!(longest_consecutive(&[i64::MAX, i64::MAX - 1, i64::MIN]), 2);
+}
+
+#[test]
+fn test_permutations() {
+    use algocol::{
+        AgcErrorKind,
+        arrays::{MAX_PERMUTATION_LENGTH, permutations}
+    };
+    use std::collections::HashSet;
+    for n in 0..=6 {
+        let items = (0..n).collect::<Vec<i32>>();
+        let all = permutations(&items).unwrap();
+        println!("permutations: {} of {}", all.len(), n);
+        assert_eq!(all.len(), (1..=n as usize).product());
+        assert_eq!(all[0], items);
+        let distinct = all.iter().collect::<HashSet<_>>();
+        assert_eq!(distinct.len(), all.len());
+        for permutation in all.iter() {
+            let mut sorted = permutation.clone();
+            sorted.sort();
+            assert_eq!(sorted, items);
+        }
+    }
+    let too_many = vec![0; MAX_PERMUTATION_LENGTH + 1];
+    let error = permutations(&too_many).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_next_permutation_by() {
+    use algocol::arrays::next_permutation_by;
+    let mut array = [1, 2, 3];
+    let mut seen = vec![array.to_vec()];
+    while next_permutation_by(&mut array, |a, b| a.cmp(b)) {
+        seen.push(array.to_vec());
+    }
+    println!("next_permutation_by: {:?}", seen);
+    assert_eq!(seen, [
+        [1, 2, 3], [1, 3, 2], [2, 1, 3], [2, 3, 1], [3, 1, 2], [3, 2, 1]
+    ]);
+    // The last permutation wraps around to the first
+    assert_eq!(array, [1, 2, 3]);
+    // Equal elements only give distinct permutations
+    let mut repeated = [1, 1, 2];
+    let mut count = 1;
+    while next_permutation_by(&mut repeated, |a, b| a.cmp(b)) {
+        count += 1;
+    }
+    assert_eq!(count, 3);
+    let mut empty: [i32; 0] = [];
+    assert!(!next_permutation_by(&mut empty, |a, b| a.cmp(b)));
+    // In reverse order, [3, 2, 1] comes first and [1, 2, 3] comes last
+    let mut reversed = [3, 1, 2];
+    assert!(next_permutation_by(&mut reversed, |a, b| b.cmp(a)));
+    assert_eq!(reversed, [2, 3, 1]);
+    let mut reversed = [1, 2, 3];
+    assert!(!next_permutation_by(&mut reversed, |a, b| b.cmp(a)));
+    assert_eq!(reversed, [3, 2, 1]);
+}
\ No newline at end of file
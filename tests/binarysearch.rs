@@ -2,9 +2,7 @@
 fn test_binarysearch_unchecked() {
     use algocol::binarysearch::binarysearch_unchecked;
     let array = [0, 2, 4, 6, 8];
-    let mut location: usize = 0;
-    if location == 0 {}
-    location = binarysearch_unchecked(&array[..], &5, true);
+    let mut location = binarysearch_unchecked(&array[..], &5, true);
     println!("Where 5 should be: {}", location);
     location = binarysearch_unchecked(&array[..], &-1, true);
     println!("Where -1 should be: {}", location);
@@ -18,4 +16,367 @@ fn test_binarysearch_unchecked() {
     println!("Where 1 should be: {}", location);
     location = binarysearch_unchecked(&array[..], &7, true);
     println!("Where 7 should be: {}", location);
+}
+
+#[test]
+fn test_binarysearch_exact_match() {
+    use algocol::binarysearch::{
+        binarysearch,
+        binarysearch_by,
+        binarysearch_unchecked_by
+    };
+    let ascending = [0, 2, 4, 6, 8];
+    let descending = [8, 6, 4, 2, 0];
+    for (array, direction) in [(ascending, true), (descending, false)].iter() {
+        for (index, item) in array.iter().enumerate() {
+            let location = binarysearch_unchecked_by(
+                &array[..], item, *direction, |a, b| a.cmp(b)
+            );
+            println!("Where {} is: {}", item, location);
+            assert_eq!(location, index);
+            assert_eq!(array[location], *item);
+            let result = binarysearch_by(
+                &array[..], item, *direction, |a, b| a.cmp(b)
+            );
+            assert_eq!(result, Ok(Ok(index)));
+            assert_eq!(binarysearch(&array[..], item, *direction), result);
+        }
+    }
+    // Past either end of the array
+    assert_eq!(binarysearch(&ascending[..], &9, true), Ok(Err(5)));
+    assert_eq!(binarysearch(&descending[..], &-1, false), Ok(Err(5)));
+    let empty: [i32; 0] = [];
+    assert_eq!(binarysearch(&empty[..], &1, true), Ok(Err(0)));
+}
+
+#[test]
+fn test_binarysearch_wrong_direction() {
+    use algocol::{AgcErrorKind, binarysearch::{binarysearch, binarysearch_by}};
+    let array = [8, 6, 4, 2, 0];
+    let result = binarysearch(&array[..], &4, true);
+    println!("binarysearch wrong direction: {:?}", result);
+    assert_eq!(result.unwrap_err().kind(), AgcErrorKind::WrongDirection);
+    let result = binarysearch_by(&array[..], &4, true, |a, b| a.cmp(b));
+    assert_eq!(result.unwrap_err().kind(), AgcErrorKind::WrongDirection);
+    assert!(binarysearch(&array[..], &4, false).is_ok());
+    let array = [0, 8, 2, 6, 4];
+    let result = binarysearch(&array[..], &4, true);
+    assert_eq!(result.unwrap_err().kind(), AgcErrorKind::Unordered);
+}
+
+#[test]
+fn test_binarysearch_detailed_by() {
+    use algocol::binarysearch::binarysearch_detailed_by;
+    let array = [1, 3, 3, 3, 5, 7, 9];
+    for (index, item) in array.iter().enumerate() {
+        let result = binarysearch_detailed_by(&array[..], item, true, |a, b| {
+            a.cmp(b)
+        }).unwrap();
+        println!("Searching for {}: {:?}", item, result);
+        assert!(result.is_found());
+        assert_eq!(array[result.index], *item);
+        assert!(result.index <= index);
+    }
+    let misses = [(0, 0), (2, 1), (4, 4), (6, 5), (8, 6), (10, 7)];
+    for (item, expected) in misses.iter() {
+        let result = binarysearch_detailed_by(&array[..], item, true, |a, b| {
+            a.cmp(b)
+        }).unwrap();
+        assert!(!result.found);
+        assert_eq!(result.insertion_index(), *expected);
+    }
+    let reversed = [9, 7, 5, 3, 3, 1];
+    let result = binarysearch_detailed_by(&reversed[..], &3, false, |a, b| {
+        a.cmp(b)
+    }).unwrap();
+    assert!(result.found);
+    assert_eq!(result.index, 3);
+    let result = binarysearch_detailed_by(&reversed[..], &4, false, |a, b| {
+        a.cmp(b)
+    }).unwrap();
+    assert!(!result.found);
+    assert_eq!(result.index, 3);
+    let empty: [i32; 0] = [];
+    let result = binarysearch_detailed_by(&empty[..], &4, true, |a, b| {
+        a.cmp(b)
+    }).unwrap();
+    assert_eq!((result.index, result.found), (0, false));
+}
+
+#[test]
+fn test_binary_search_answer() {
+    use std::cell::Cell;
+    use algocol::binarysearch::binary_search_answer;
+    let n: i64 = 1_000_000_000;
+    let calls = Cell::new(0);
+    let root = binary_search_answer(0, n, |x| {
+        calls.set(calls.get() + 1);
+        x*x >= n
+    });
+    println!("Smallest x where x*x >= {}: {:?}", n, root);
+    assert_eq!(root, Some(31623));
+    assert!(calls.get() <= 32);
+    // The integer square root is the number just before the smallest x where
+    // x*x > n
+    let root = binary_search_answer(0, n, |x| x*x > n).unwrap() - 1;
+    assert_eq!(root, 31622);
+    assert_eq!(binary_search_answer(5, 10, |_| true), Some(5));
+    assert_eq!(binary_search_answer(5, 10, |_| false), None);
+    assert_eq!(binary_search_answer(10, 5, |_| true), None);
+    let extreme = binary_search_answer(i64::MIN, i64::MAX, |x| x >= -3);
+    assert_eq!(extreme, Some(-3));
+}
+
+#[test]
+fn test_exponential_search_by() {
+    use algocol::binarysearch::exponential_search_by;
+    let compare = |a: &i32, b: &i32| a.cmp(b);
+    let array = (0..50).map(|x| x / 2 * 2).collect::<Vec<i32>>();
+    for item in -1..52 {
+        let location = exponential_search_by(&array[..], &item, true, compare);
+        let expected = array.iter().filter(|x| **x < item).count();
+        assert_eq!(location, expected);
+    }
+    let reversed = array.iter().rev().copied().collect::<Vec<i32>>();
+    for item in -1..52 {
+        let location = exponential_search_by(
+            &reversed[..], &item, false, compare
+        );
+        let expected = reversed.iter().filter(|x| **x > item).count();
+        assert_eq!(location, expected);
+    }
+    let empty: [i32; 0] = [];
+    assert_eq!(exponential_search_by(&empty[..], &3, true, compare), 0);
+}
+
+#[test]
+fn test_binarysearch_first_last() {
+    use algocol::{
+        AgcErrorKind,
+        binarysearch::{binarysearch_first_by, binarysearch_last_by}
+    };
+    let compare = |a: &i32, b: &i32| a.cmp(b);
+    let array = [1, 2, 2, 2, 3];
+    let first = binarysearch_first_by(&array[..], &2, true, compare);
+    let last = binarysearch_last_by(&array[..], &2, true, compare);
+    println!("binarysearch first: {:?}, last: {:?}", first, last);
+    assert_eq!(first, Ok(Ok(1)));
+    assert_eq!(last, Ok(Ok(3)));
+    let first = binarysearch_first_by(&array[..], &1, true, compare);
+    assert_eq!(first, Ok(Ok(0)));
+    let last = binarysearch_last_by(&array[..], &3, true, compare);
+    assert_eq!(last, Ok(Ok(4)));
+    // Missing items give the same insertion point either way
+    for (item, location) in [(0, 0), (4, 5)].iter() {
+        let first = binarysearch_first_by(&array[..], item, true, compare);
+        let last = binarysearch_last_by(&array[..], item, true, compare);
+        assert_eq!((first, last), (Ok(Err(*location)), Ok(Err(*location))));
+    }
+    let descending = [5, 4, 4, 1, 1, 1, 0];
+    let first = binarysearch_first_by(&descending[..], &1, false, compare);
+    let last = binarysearch_last_by(&descending[..], &1, false, compare);
+    assert_eq!((first, last), (Ok(Ok(3)), Ok(Ok(5))));
+    let missing = binarysearch_first_by(&descending[..], &3, false, compare);
+    assert_eq!(missing, Ok(Err(3)));
+    let error = binarysearch_last_by(&descending[..], &1, true, compare)
+        .unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::WrongDirection);
+}
+
+#[test]
+fn test_equal_range_by() {
+    use algocol::binarysearch::equal_range_by;
+    let compare = |a: &i32, b: &i32| a.cmp(b);
+    let array = [1, 2, 2, 2, 3];
+    let range = equal_range_by(&array[..], &2, true, compare).unwrap();
+    println!("equal_range_by: {:?}", range);
+    assert_eq!(range, 1..4);
+    assert_eq!(range.len(), 3);
+    let array = [0, 1, 2, 5, 6];
+    let range = equal_range_by(&array[..], &4, true, compare).unwrap();
+    assert_eq!(range, 3..3);
+    assert!(range.is_empty());
+    let descending = [9, 7, 7, 4];
+    assert_eq!(equal_range_by(&descending[..], &7, false, compare), Ok(1..3));
+    assert_eq!(equal_range_by(&descending[..], &1, false, compare), Ok(4..4));
+    assert!(equal_range_by(&descending[..], &7, true, compare).is_err());
+}
+
+#[test]
+fn test_binarysearch_by_key() {
+    use algocol::{AgcErrorKind, binarysearch::binarysearch_by_key};
+    let people = [
+        ("ann", 18), ("bob", 22), ("cat", 22), ("dan", 35), ("eve", 41)
+    ];
+    for (index, (name, age)) in people.iter().enumerate() {
+        let result = binarysearch_by_key(&people[..], age, true, |p| p.1);
+        println!("binarysearch_by_key for {}: {:?}", name, result);
+        let location = result.unwrap().unwrap();
+        assert_eq!(people[location].1, *age);
+        // "bob" and "cat" are both 22, and the first of them is found
+        let first = if *age == 22 {1} else {index};
+        assert_eq!(location, first);
+    }
+    let by_age = |person: &(&str, i32)| person.1;
+    let missing = binarysearch_by_key(&people[..], &30, true, by_age);
+    assert_eq!(missing, Ok(Err(3)));
+    let missing = binarysearch_by_key(&people[..], &50, true, by_age);
+    assert_eq!(missing, Ok(Err(5)));
+    let mut oldest_first = people;
+    oldest_first.reverse();
+    let result = binarysearch_by_key(&oldest_first[..], &35, false, |p| p.1);
+    assert_eq!(result, Ok(Ok(1)));
+    let error = binarysearch_by_key(&oldest_first[..], &35, true, |p| p.1)
+        .unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::WrongDirection);
+}
+
+#[test]
+fn test_interpolation_search() {
+    use algocol::{
+        binarysearch::{
+            binarysearch,
+            binarysearch_unchecked_by,
+            interpolation_search,
+            interpolation_search_counted
+        },
+        utils::cmp::CountingComparator
+    };
+    let array = (0..1000).collect::<Vec<i32>>();
+    let counter = CountingComparator::new(|a: &i32, b: &i32| a.cmp(b));
+    let mut probes = 0;
+    for item in array.iter() {
+        let (count, location) = interpolation_search_counted(
+            &array[..], item, true
+        ).unwrap();
+        assert_eq!(location, Ok(*item as usize));
+        probes += count;
+        binarysearch_unchecked_by(&array[..], item, true, |a, b| {
+            counter.compare(a, b)
+        });
+    }
+    println!(
+        "interpolation search probes: {}, binary search comparisons: {}",
+        probes,
+        counter.count()
+    );
+    assert_eq!(probes, 1000);
+    assert!(probes * 5 < counter.count());
+    // Uneven and repeated values give the same answers as binary search
+    let uneven = [1, 1, 1, 2, 3, 3, 50, 51, 900, 1000, 1000];
+    for item in -1..1002 {
+        let expected = binarysearch(&uneven[..], &item, true).unwrap();
+        let location = interpolation_search(&uneven[..], &item, true).unwrap();
+        assert_eq!(location.is_ok(), expected.is_ok(), "searching {}", item);
+        match location {
+            Ok(index) => assert_eq!(uneven[index], item),
+            Err(index) => assert_eq!(Err(index), expected)
+        }
+    }
+    let mut descending = uneven;
+    descending.reverse();
+    assert_eq!(interpolation_search(&descending[..], &50, false), Ok(Ok(4)));
+    assert_eq!(interpolation_search(&descending[..], &4, false), Ok(Err(5)));
+    assert!(interpolation_search(&descending[..], &4, true).is_err());
+    let same = [7, 7, 7, 7];
+    assert!(interpolation_search(&same[..], &7, true).unwrap().is_ok());
+    assert_eq!(interpolation_search(&same[..], &8, true), Ok(Err(4)));
+    let empty: [i32; 0] = [];
+    assert_eq!(interpolation_search(&empty[..], &1, true), Ok(Err(0)));
+    let extremes = [i64::MIN, 0, i64::MAX];
+    assert_eq!(interpolation_search(&extremes[..], &0, true), Ok(Ok(1)));
+}
+
+#[test]
+fn test_jump_search_by() {
+    use algocol::{
+        AgcErrorKind,
+        binarysearch::{binarysearch, jump_search_by},
+        utils::random::XorShift
+    };
+    let compare = |a: &i32, b: &i32| a.cmp(b);
+    let mut random = XorShift::new(83);
+    for length in 0..60 {
+        // Distinct values with gaps between them, so that every search has
+        // exactly one answer
+        let mut array = Vec::with_capacity(length);
+        let mut value = 0;
+        for _ in 0..length {
+            value += 1 + random.next_below(3) as i32;
+            array.push(value);
+        }
+        for item in -1..value + 2 {
+            let expected = binarysearch(&array[..], &item, true);
+            let location = jump_search_by(&array[..], &item, true, compare);
+            assert_eq!(location, expected, "{} in {:?}", item, array);
+        }
+        array.reverse();
+        for item in -1..value + 2 {
+            let expected = binarysearch(&array[..], &item, false);
+            let location = jump_search_by(&array[..], &item, false, compare);
+            assert_eq!(location, expected, "{} in {:?}", item, array);
+        }
+    }
+    let repeated = [1, 2, 2, 2, 2, 2, 2, 3];
+    let location = jump_search_by(&repeated[..], &2, true, compare);
+    println!("jump_search_by: {:?}", location);
+    assert_eq!(location, Ok(Ok(1)));
+    let error = jump_search_by(&repeated[..], &2, false, compare).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::WrongDirection);
+}
+
+#[test]
+fn test_count_occurrences_by() {
+    use algocol::{AgcErrorKind, binarysearch::count_occurrences_by};
+    let compare = |a: &i32, b: &i32| a.cmp(b);
+    let array = [1, 2, 2, 2, 3];
+    let count = count_occurrences_by(&array[..], &2, true, compare);
+    println!("count_occurrences_by: {:?}", count);
+    assert_eq!(count, Ok(3));
+    assert_eq!(count_occurrences_by(&array[..], &1, true, compare), Ok(1));
+    assert_eq!(count_occurrences_by(&array[..], &0, true, compare), Ok(0));
+    assert_eq!(count_occurrences_by(&array[..], &9, true, compare), Ok(0));
+    let empty: [i32; 0] = [];
+    assert_eq!(count_occurrences_by(&empty[..], &2, true, compare), Ok(0));
+    let descending = [9, 7, 7, 4];
+    let count = count_occurrences_by(&descending[..], &7, false, compare);
+    assert_eq!(count, Ok(2));
+    let error = count_occurrences_by(&[3, 1, 2][..], &2, true, compare)
+        .unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::Unordered);
+}
+
+#[test]
+fn test_saddleback_search() {
+    use algocol::{AgcErrorKind, binarysearch::saddleback_search};
+    let matrix: [&[i32]; 4] = [
+        &[1, 3, 5, 7],
+        &[2, 4, 6, 8],
+        &[3, 5, 7, 9],
+        &[4, 6, 8, 10]
+    ];
+    for (row, elements) in matrix.iter().enumerate() {
+        for (column, element) in elements.iter().enumerate() {
+            let (found_row, found_column) = saddleback_search(&matrix, element)
+                .unwrap()
+                .unwrap();
+            println!(
+                "saddleback_search for {} at ({}, {}): ({}, {})",
+                element, row, column, found_row, found_column
+            );
+            assert_eq!(matrix[found_row][found_column], *element);
+            assert!(found_row <= row);
+        }
+    }
+    // 5 is in rows 0 and 2, and the first match is the one in row 0
+    assert_eq!(saddleback_search(&matrix, &5), Ok(Some((0, 2))));
+    assert_eq!(saddleback_search(&matrix, &0), Ok(None));
+    assert_eq!(saddleback_search(&matrix, &11), Ok(None));
+    let repeated: [&[i32]; 2] = [&[1, 2, 2, 2], &[2, 2, 3, 3]];
+    assert_eq!(saddleback_search(&repeated, &2), Ok(Some((0, 1))));
+    let empty: [&[i32]; 0] = [];
+    assert_eq!(saddleback_search(&empty, &1), Ok(None));
+    let ragged: [&[i32]; 2] = [&[1, 2], &[3]];
+    let error = saddleback_search(&ragged, &3).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::InvalidArgument);
 }
\ No newline at end of file
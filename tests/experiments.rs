@@ -1,3 +1,5 @@
+#![cfg(feature = "internals")]
+
 extern crate algocol;
 
 fn generate_unsorted(from: i128, to: i128, ascending: bool) -> Vec<i128> {
@@ -0,0 +1,65 @@
+extern crate algocol;
+
+#[test]
+fn test_closest_pair() {
+    use algocol::{geometry::closest_pair, utils::random::XorShift};
+
+    fn brute_force(points: &[(f64, f64)]) -> f64 {
+        let mut best = f64::INFINITY;
+        for i in 0..points.len() {
+            for j in i+1..points.len() {
+                let dx = points[i].0 - points[j].0;
+                let dy = points[i].1 - points[j].1;
+                best = best.min((dx*dx + dy*dy).sqrt());
+            }
+        }
+        best
+    }
+
+    let mut random = XorShift::new(2021);
+    for length in 2..60 {
+        let points = (0..length)
+            .map(|_| (
+                random.next_below(1000) as f64 / 10.0,
+                random.next_below(1000) as f64 / 10.0
+            ))
+            .collect::<Vec<(f64, f64)>>();
+        let ((a, b), distance) = closest_pair(&points).unwrap();
+        assert!(a < b);
+        let (dx, dy) = (points[a].0 - points[b].0, points[a].1 - points[b].1);
+        assert!(((dx*dx + dy*dy).sqrt() - distance).abs() < 1e-9);
+        assert!((brute_force(&points) - distance).abs() < 1e-9);
+    }
+    // Points on a vertical line all have the same x coordinate
+    let line = (0..10).map(|y| (1.0, (y*y) as f64)).collect::<Vec<_>>();
+    let result = closest_pair(&line);
+    println!("closest_pair on a line: {:?}", result);
+    assert_eq!(result, Some(((0, 1), 1.0)));
+    assert_eq!(closest_pair(&[(0.0, 0.0)]), None);
+    assert_eq!(closest_pair(&[]), None);
+}
+
+#[test]
+fn test_convex_hull() {
+    use algocol::geometry::convex_hull;
+    let square = [
+        (1.0, 1.0), (0.0, 0.0), (0.0, 2.0), (2.0, 2.0), (2.0, 0.0),
+        (1.0, 0.5)
+    ];
+    let hull = convex_hull(&square);
+    println!("convex_hull of square: {:?}", hull);
+    assert_eq!(hull, [1, 4, 3, 2]);
+    // Points on the edges are not corners, and duplicates are only used once
+    let edges = [
+        (0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (2.0, 1.0), (2.0, 2.0),
+        (0.0, 2.0), (0.0, 1.0), (2.0, 2.0), (0.0, 0.0)
+    ];
+    assert_eq!(convex_hull(&edges), [0, 2, 4, 5]);
+    // Collinear points only give the 2 ends
+    let line = [(1.0, 1.0), (3.0, 3.0), (0.0, 0.0), (2.0, 2.0)];
+    assert_eq!(convex_hull(&line), [2, 1]);
+    let vertical = [(0.0, 2.0), (0.0, 0.0), (0.0, 1.0)];
+    assert_eq!(convex_hull(&vertical), [1, 0]);
+    assert_eq!(convex_hull(&[(1.0, 1.0), (1.0, 1.0)]), [0]);
+    assert_eq!(convex_hull(&[]), Vec::<usize>::new());
+}
\ No newline at end of file
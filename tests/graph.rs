@@ -0,0 +1,1045 @@
+extern crate algocol;
+
+use algocol::graph::{AdjacencyMatrix, Edge, EdgeKind};
+
+#[test]
+fn test_edge_list_round_trip() {
+    let mut matrix = AdjacencyMatrix::<String, i32>::new();
+    let edges = [
+        ("a", "b", 5, EdgeKind::ToRight),
+        ("b", "c", 2, EdgeKind::Bidirectional),
+        ("d", "a", 7, EdgeKind::ToLeft)
+    ];
+    for (left, right, cost, edge_kind) in edges.iter() {
+        matrix.push(Edge::new(
+            left.to_string(),
+            right.to_string(),
+            *cost,
+            *edge_kind
+        )).unwrap();
+    }
+    matrix.register_node(&"e".to_string());
+    let text = matrix.to_edge_list_string();
+    println!("edge list:\n{}", text);
+    let loaded = AdjacencyMatrix::<String, i32>::from_edge_list_str(&text)
+        .unwrap();
+    let mut nodes = loaded.nodes().cloned().collect::<Vec<String>>();
+    nodes.sort();
+    let mut expected = matrix.nodes().cloned().collect::<Vec<String>>();
+    expected.sort();
+    assert_eq!(nodes, expected);
+    for node in matrix.nodes() {
+        assert_eq!(loaded.get_adjacent(node), matrix.get_adjacent(node));
+    }
+}
+
+#[test]
+fn test_edge_list_malformed_line() {
+    use algocol::AgcErrorKind;
+    let result = AdjacencyMatrix::<i32, i32>::from_edge_list_str(
+        "0 1 5 ToRight\n1 2 x ToRight"
+    );
+    let error = result.err().unwrap();
+    println!("malformed edge list: {}", error);
+    assert_eq!(error.kind(), AgcErrorKind::InvalidArgument);
+    assert!(error.to_string().contains("line 2"));
+    let result = AdjacencyMatrix::<i32, i32>::from_edge_list_str(
+        "0 1 5 Sideways"
+    );
+    assert_eq!(result.err().unwrap().kind(), AgcErrorKind::InvalidArgument);
+}
+
+fn directed_graph<K>(edges: &[(K, K, i32)]) -> AdjacencyMatrix<K, i32>
+where
+    K: algocol::traits::AgcHashable + Clone
+{
+    let mut matrix = AdjacencyMatrix::new();
+    for (left, right, cost) in edges.iter() {
+        matrix.push(Edge::new(
+            left.clone(),
+            right.clone(),
+            *cost,
+            EdgeKind::ToRight
+        )).unwrap();
+    }
+    matrix
+}
+
+#[test]
+fn test_max_flow() {
+    use algocol::AgcErrorKind;
+    // The flow network from CLRS (figure 26.1), whose maximum flow is 23
+    let matrix = directed_graph(&[
+        ("s", "v1", 16), ("s", "v2", 13), ("v2", "v1", 4), ("v1", "v3", 12),
+        ("v3", "v2", 9), ("v2", "v4", 14), ("v4", "v3", 7), ("v3", "t", 20),
+        ("v4", "t", 4)
+    ]);
+    let flow = matrix.max_flow(&"s", &"t");
+    println!("max_flow: {:?}", flow);
+    assert_eq!(flow, Ok(23));
+    // The original graph is unchanged
+    assert_eq!(matrix.get_edge(&"s", &"v1"), Some(&16));
+    assert_eq!(matrix.get_edge(&"v1", &"s"), None);
+    assert_eq!(matrix.max_flow(&"t", &"s"), Ok(0));
+    let error = matrix.max_flow(&"s", &"x").unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::NotFound);
+    let error = matrix.max_flow(&"s", &"s").unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::SameNode);
+}
+
+#[test]
+fn test_multi_adjacency_matrix() {
+    use algocol::graph::MultiAdjacencyMatrix;
+    let mut matrix = MultiAdjacencyMatrix::<&str, i32>::new();
+    matrix.push(Edge::new("a", "b", 7, EdgeKind::ToRight)).unwrap();
+    matrix.push(Edge::new("a", "b", 4, EdgeKind::ToRight)).unwrap();
+    matrix.push(Edge::new("b", "a", 9, EdgeKind::Bidirectional)).unwrap();
+    println!("parallel edges: {:?}", matrix.get_edges(&"a", &"b"));
+    assert_eq!(matrix.get_edges(&"a", &"b"), Some(&[7, 4, 9][..]));
+    assert_eq!(matrix.get_edges(&"b", &"a"), Some(&[9][..]));
+    assert_eq!(matrix.get_edges(&"b", &"c"), None);
+    let self_loop = Edge {
+        left: "a",
+        right: "a",
+        cost: 1,
+        edge_kind: EdgeKind::ToRight
+    };
+    assert!(matrix.push(self_loop).is_err());
+    let simple = matrix.to_adjacency_matrix();
+    assert_eq!(simple.get_edge(&"a", &"b"), Some(&4));
+    assert_eq!(simple.get_edge(&"b", &"a"), Some(&9));
+}
+
+#[test]
+fn test_map_nodes() {
+    use algocol::AgcErrorKind;
+    let matrix = directed_graph(&[(0, 1, 4), (1, 2, 6), (2, 0, 1), (0, 3, 2)]);
+    let named = matrix.map_nodes(|node| format!("n{}", node)).unwrap();
+    let mut nodes = named.all_nodes().into_iter().cloned().collect::<Vec<_>>();
+    nodes.sort();
+    println!("map_nodes: {:?}", nodes);
+    assert_eq!(nodes, ["n0", "n1", "n2", "n3"]);
+    for from in matrix.nodes() {
+        for (to, cost) in matrix.get_adjacent(from).unwrap().iter() {
+            let (from, to) = (format!("n{}", from), format!("n{}", to));
+            assert_eq!(named.get_edge(&from, &to), Some(cost));
+        }
+    }
+    assert_eq!(named.get_adjacent(&"n0".to_string()).unwrap().len(), 2);
+    let error = matrix.map_nodes(|node| node % 2).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::AlreadyExists);
+}
+
+#[test]
+fn test_is_tree() {
+    //     a
+    //    / \
+    //   b   c
+    //  / \
+    // d   e
+    let mut matrix = directed_graph(&[
+        ("a", "b", 1), ("a", "c", 1), ("d", "b", 1), ("b", "e", 1)
+    ]);
+    assert!(matrix.is_tree());
+    // An extra edge creates a cycle
+    let mut cyclic = matrix.clone();
+    cyclic.push(Edge::new("c", "e", 1, EdgeKind::ToRight)).unwrap();
+    assert!(!cyclic.is_tree());
+    // Edges in both directions only count once
+    matrix.push(Edge::new("c", "a", 1, EdgeKind::ToRight)).unwrap();
+    assert!(matrix.is_tree());
+    // A forest is not connected
+    let forest = directed_graph(&[("a", "b", 1), ("c", "d", 1), ("c", "e", 1)]);
+    assert!(!forest.is_tree());
+    let mut single = AdjacencyMatrix::<&str, i32>::new();
+    assert!(!single.is_tree());
+    single.register_node(&"a");
+    assert!(single.is_tree());
+}
+
+#[test]
+fn test_transitive_closure() {
+    use std::collections::HashSet;
+    let mut matrix = directed_graph(&[("a", "b", 1), ("b", "c", 1)]);
+    matrix.register_node(&"d");
+    let closure = matrix.transitive_closure();
+    println!("transitive_closure: {:?}", closure);
+    assert_eq!(matrix.get_edge(&"a", &"c"), None);
+    assert!(closure[&"a"].contains(&"c"));
+    assert_eq!(closure[&"a"], ["b", "c"].iter().copied().collect());
+    assert_eq!(closure[&"b"], ["c"].iter().copied().collect());
+    assert_eq!(closure[&"c"], HashSet::new());
+    assert_eq!(closure[&"d"], HashSet::new());
+    // A cycle lets every node on it reach every other node, and itself
+    let cycle = directed_graph(&[(1, 2, 1), (2, 3, 1), (3, 1, 1)]);
+    let closure = cycle.transitive_closure();
+    for node in 1..=3 {
+        assert_eq!(closure[&node], (1..=3).collect());
+    }
+}
+
+#[test]
+fn test_shortest_path_count() {
+    use algocol::AgcErrorKind;
+    // A 3x3 grid where every edge goes right or down. Reaching the opposite
+    // corner takes 4 steps, 2 of which go right, so there are C(4, 2) = 6
+    // shortest paths.
+    let mut edges = Vec::new();
+    for row in 0..3 {
+        for column in 0..3 {
+            if column < 2 {
+                edges.push(((row, column), (row, column+1), 1));
+            }
+            if row < 2 {
+                edges.push(((row, column), (row+1, column), 1));
+            }
+        }
+    }
+    let mut grid = directed_graph(&edges);
+    let count = grid.shortest_path_count(&(0, 0), &(2, 2));
+    println!("shortest_path_count on a 3x3 grid: {:?}", count);
+    assert_eq!(count, Ok(6));
+    assert_eq!(grid.shortest_path_count(&(0, 0), &(1, 2)), Ok(3));
+    assert_eq!(grid.shortest_path_count(&(1, 1), &(1, 1)), Ok(1));
+    assert_eq!(grid.shortest_path_count(&(2, 2), &(0, 0)), Ok(0));
+    // A shortcut makes the other paths longer
+    grid.push(Edge::new((0, 0), (2, 2), 100, EdgeKind::ToRight)).unwrap();
+    assert_eq!(grid.shortest_path_count(&(0, 0), &(2, 2)), Ok(1));
+    let error = grid.shortest_path_count(&(0, 0), &(3, 3)).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::NotFound);
+}
+
+#[test]
+fn test_graph_center() {
+    use algocol::AgcErrorKind;
+    // 0 - 1 - 2 - 3 - 4, where 2 is at most 2 edges away from every node
+    let mut path = AdjacencyMatrix::<i32, i32>::new();
+    for node in 0..4 {
+        path.push(Edge::new(node, node+1, 1, EdgeKind::Bidirectional))
+            .unwrap();
+    }
+    let center = path.graph_center();
+    println!("graph_center of a path with 5 nodes: {:?}", center);
+    assert_eq!(center, Ok(vec![2]));
+    // A path with an even number of nodes has 2 nodes in its center
+    path.push(Edge::new(4, 5, 1, EdgeKind::Bidirectional)).unwrap();
+    let mut center = path.graph_center().unwrap();
+    center.sort();
+    assert_eq!(center, vec![2, 3]);
+    // 5 cannot go back to 4 any more
+    path.get_mut_adjacent(&5).unwrap().clear();
+    let error = path.graph_center().unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::Disconnected);
+    assert_eq!(AdjacencyMatrix::<i32, i32>::new().graph_center(), Ok(vec![]));
+}
+
+#[test]
+fn test_is_isomorphic() {
+    use algocol::AgcErrorKind;
+    // A square with a diagonal, and the same graph with its nodes renamed
+    let original = directed_graph(&[
+        (0, 1, 1), (1, 2, 2), (2, 3, 3), (3, 0, 4), (0, 2, 5)
+    ]);
+    let renamed = directed_graph(&[
+        ('c', 'a', 1), ('a', 'd', 2), ('d', 'b', 3), ('b', 'c', 4),
+        ('c', 'd', 5)
+    ]).map_nodes(|node| *node as i32).unwrap();
+    let result = original.is_isomorphic(&renamed);
+    println!("is_isomorphic on a renamed graph: {:?}", result);
+    assert_eq!(result, Ok(true));
+    assert_eq!(renamed.is_isomorphic(&original), Ok(true));
+    // Costs have to match too
+    let mut cheaper = renamed.clone();
+    *cheaper.get_mut_edge(&('c' as i32), &('d' as i32)).unwrap() = 1;
+    assert_eq!(original.is_isomorphic(&cheaper), Ok(false));
+    // A path and a star both have 3 edges and the same number of nodes
+    // with no incoming edges, but the path has no node with 3 outgoing edges
+    let path = directed_graph(&[(0, 1, 1), (1, 2, 1), (2, 3, 1)]);
+    let star = directed_graph(&[(0, 1, 1), (0, 2, 1), (0, 3, 1)]);
+    assert_eq!(path.is_isomorphic(&star), Ok(false));
+    // 2 cycles of 3 nodes vs 1 cycle of 6 nodes: every node has 1 incoming
+    // and 1 outgoing edge in both, so only the search can tell them apart
+    let triangles = directed_graph(&[
+        (0, 1, 1), (1, 2, 1), (2, 0, 1), (3, 4, 1), (4, 5, 1), (5, 3, 1)
+    ]);
+    let hexagon = directed_graph(&[
+        (0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 4, 1), (4, 5, 1), (5, 0, 1)
+    ]);
+    assert_eq!(triangles.is_isomorphic(&hexagon), Ok(false));
+    assert_eq!(hexagon.is_isomorphic(&hexagon.clone()), Ok(true));
+    // Too many nodes to search through, so an error is returned
+    let cycle = |length: i32| directed_graph(
+        &(0..length).map(|node| (node, (node + 1) % length, 1))
+            .collect::<Vec<(i32, i32, i32)>>()
+    );
+    let error = cycle(11).is_isomorphic(&cycle(11)).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::InvalidArgument);
+    // The quick checks do not need the search
+    assert_eq!(cycle(11).is_isomorphic(&cycle(12)), Ok(false));
+}
+
+#[test]
+fn test_find_negative_cycle() {
+    // b -> c -> d -> b costs 2 - 4 + 1 = -1, and e hangs off the cycle
+    let mut matrix = directed_graph(&[
+        ("a", "b", 1), ("b", "c", 2), ("c", "d", -4), ("d", "b", 1),
+        ("d", "e", 3), ("a", "e", 10)
+    ]);
+    let cycle = matrix.find_negative_cycle().unwrap().unwrap();
+    println!("find_negative_cycle: {:?}", cycle);
+    assert_eq!(cycle.len(), 3);
+    let mut total = 0;
+    for (index, from) in cycle.iter().enumerate() {
+        let to = &cycle[(index + 1) % cycle.len()];
+        total += matrix.get_edge(from, to).unwrap();
+    }
+    assert!(total < 0);
+    // Making the cycle cost 0 removes it, even with negative edges left
+    *matrix.get_mut_edge(&"d", &"b").unwrap() = 2;
+    assert_eq!(matrix.find_negative_cycle(), Ok(None));
+    let empty = AdjacencyMatrix::<&str, i32>::new();
+    assert_eq!(empty.find_negative_cycle(), Ok(None));
+}
+
+#[test]
+fn test_contract_edge() {
+    use std::collections::HashSet;
+    use algocol::AgcErrorKind;
+    //   c       e
+    //    \     /
+    //     a - b
+    //    /     \
+    //   d       f
+    let mut matrix = AdjacencyMatrix::<&str, i32>::new();
+    for (left, right, cost) in [
+        ("a", "b", 1), ("a", "c", 2), ("a", "d", 3), ("b", "e", 4),
+        ("b", "f", 5), ("c", "b", 6)
+    ].iter() {
+        matrix.push(Edge::new(*left, *right, *cost, EdgeKind::Bidirectional))
+            .unwrap();
+    }
+    matrix.contract_edge(&"a", &"b", "ab").unwrap();
+    let neighbours = matrix.get_adjacent(&"ab").unwrap();
+    println!("contract_edge: {:?}", neighbours);
+    assert_eq!(
+        neighbours.keys().copied().collect::<HashSet<&str>>(),
+        ["c", "d", "e", "f"].iter().copied().collect()
+    );
+    // c was joined to both a (cost 2) and b (cost 6), so the cheaper edge is
+    // kept in both directions
+    assert_eq!(matrix.get_edge(&"ab", &"c"), Some(&2));
+    assert_eq!(matrix.get_edge(&"c", &"ab"), Some(&2));
+    assert_eq!(matrix.get_edge(&"f", &"ab"), Some(&5));
+    assert_eq!(matrix.get_edge(&"ab", &"ab"), None);
+    assert!(!matrix.registered(&"a") && !matrix.registered(&"b"));
+    assert!(matrix.get_adjacent(&"c").unwrap().get(&"b").is_none());
+    let error = matrix.contract_edge(&"c", &"d", "cd").unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::NotFound);
+    let error = matrix.contract_edge(&"ab", &"c", "d").unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::AlreadyExists);
+    // Keeping one of the labels
+    matrix.contract_edge(&"ab", &"c", "c").unwrap();
+    assert_eq!(matrix.get_edge(&"c", &"d"), Some(&3));
+}
+
+#[test]
+fn test_karger_min_cut() {
+    use algocol::AgcErrorKind;
+    // 2 complete graphs of 5 nodes joined by 2 edges, so the minimum cut is 2
+    let mut matrix = AdjacencyMatrix::<i32, i32>::new();
+    for offset in [0, 5].iter() {
+        for left in 0..5 {
+            for right in left+1..5 {
+                matrix.push(Edge::new(
+                    left + offset, right + offset, 1, EdgeKind::Bidirectional
+                )).unwrap();
+            }
+        }
+    }
+    matrix.push(Edge::new(0, 5, 1, EdgeKind::ToRight)).unwrap();
+    matrix.push(Edge::new(8, 3, 1, EdgeKind::ToRight)).unwrap();
+    let cut = matrix.karger_min_cut(300, 2021);
+    println!("karger_min_cut: {:?}", cut);
+    assert_eq!(cut, Ok(2));
+    // A single run can only overestimate the minimum cut
+    assert!(matrix.karger_min_cut(1, 7).unwrap() >= 2);
+    matrix.register_node(&10);
+    assert_eq!(matrix.karger_min_cut(1, 7), Ok(0));
+    let error = matrix.karger_min_cut(0, 7).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_karger_min_cut_repeatable() {
+    // A ring of 8 nodes with a few chords, where a single run finds cuts of
+    // different sizes depending on the order the edges are contracted in
+    let build = || {
+        let mut matrix = AdjacencyMatrix::<i32, i32>::new();
+        for node in 0..8 {
+            matrix.push(Edge::new(node, (node + 1) % 8, 1, EdgeKind::ToRight))
+                .unwrap();
+        }
+        for (left, right) in [(0, 4), (1, 5), (2, 6)].iter() {
+            matrix.push(Edge::new(*left, *right, 1, EdgeKind::ToRight))
+                .unwrap();
+        }
+        matrix
+    };
+    let cuts = (0..20)
+        .map(|seed| build().karger_min_cut(1, seed).unwrap())
+        .collect::<Vec<usize>>();
+    println!("karger_min_cut_repeatable: {:?}", cuts);
+    for (seed, cut) in cuts.iter().enumerate() {
+        assert_eq!(build().karger_min_cut(1, seed as u64), Ok(*cut));
+    }
+}
+
+#[test]
+fn test_topological_levels() {
+    use std::collections::HashSet;
+    use algocol::AgcErrorKind;
+    //     b
+    //   /   \
+    // a       d - e
+    //   \   /
+    //     c
+    let mut matrix = directed_graph(&[
+        ("a", "b", 1), ("a", "c", 1), ("b", "d", 1), ("c", "d", 1),
+        ("d", "e", 1)
+    ]);
+    let levels = matrix.topological_levels().unwrap();
+    println!("topological_levels: {:?}", levels);
+    let levels = levels
+        .into_iter()
+        .map(|level| level.into_iter().collect::<HashSet<&str>>())
+        .collect::<Vec<HashSet<&str>>>();
+    let expected = [&["a"][..], &["b", "c"], &["d"], &["e"]]
+        .iter()
+        .map(|level| level.iter().copied().collect::<HashSet<&str>>())
+        .collect::<Vec<HashSet<&str>>>();
+    assert_eq!(levels, expected);
+    // Nodes without any edges have no dependencies
+    matrix.register_node(&"f");
+    assert!(matrix.topological_levels().unwrap()[0].contains(&"f"));
+    matrix.push(Edge::new("e", "b", 1, EdgeKind::ToRight)).unwrap();
+    let error = matrix.topological_levels().unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::CyclicGraph);
+    let empty = AdjacencyMatrix::<&str, i32>::new();
+    assert_eq!(empty.topological_levels(), Ok(vec![]));
+}
+
+#[test]
+fn test_graph_metrics() {
+    use algocol::AgcErrorKind;
+    let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    let edges = [('a', 'b', 2), ('b', 'c', 1), ('c', 'd', 4), ('b', 'd', 3)];
+    for (left, right, cost) in edges.iter() {
+        let kind = EdgeKind::Bidirectional;
+        matrix.push(Edge::new(*left, *right, *cost, kind)).unwrap();
+    }
+    // Cheapest paths: a-b 2, a-c 3, a-d 5, b-c 1, b-d 3, c-d 4
+    let metrics = matrix.graph_metrics().unwrap();
+    println!("graph_metrics: {:?}", metrics);
+    assert_eq!(metrics.eccentricity(&'a'), Some(5));
+    assert_eq!(metrics.eccentricity(&'b'), Some(3));
+    assert_eq!(metrics.eccentricity(&'c'), Some(4));
+    assert_eq!(metrics.eccentricity(&'d'), Some(5));
+    assert_eq!(metrics.eccentricity(&'e'), None);
+    assert_eq!(metrics.radius(), 3);
+    assert_eq!(metrics.diameter(), 5);
+    assert_eq!(metrics.center(), ['b']);
+    let mut periphery = metrics.periphery().to_vec();
+    periphery.sort();
+    assert_eq!(periphery, vec!['a', 'd']);
+    let mut one_way = matrix.clone();
+    one_way.get_mut_adjacent(&'d').unwrap().clear();
+    let error = one_way.graph_metrics().unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::Disconnected);
+    *matrix.get_mut_edge(&'c', &'d').unwrap() = -5;
+    let error = matrix.graph_metrics().unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::NegativeCycle);
+    let empty = AdjacencyMatrix::<char, i32>::new();
+    let error = empty.graph_metrics().unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_merge_sorted_edge_lists() {
+    use algocol::graph::edgelist::merge_sorted_edge_lists;
+    let first = vec![
+        Edge::new('a', 'b', 1, EdgeKind::ToRight),
+        Edge::new('b', 'c', 3, EdgeKind::Bidirectional),
+        Edge::new('c', 'd', 6, EdgeKind::ToRight)
+    ];
+    // b-c overlaps with the edge in `first` the other way round, and the
+    // edge to `b` from `a` is the same as the first edge in `first`
+    let second = vec![
+        Edge::new('c', 'b', 2, EdgeKind::Bidirectional),
+        Edge::new('b', 'a', 4, EdgeKind::ToLeft),
+        Edge::new('a', 'b', 5, EdgeKind::ToLeft)
+    ];
+    let lists = [first, second, vec![]];
+    let describe = |edges: &[Edge<char, i32>]| edges
+        .iter()
+        .map(|edge| (edge.left, edge.right, edge.cost))
+        .collect::<Vec<_>>();
+    let merged = merge_sorted_edge_lists(&lists, false);
+    println!("merge_sorted_edge_lists: {:?}", describe(&merged));
+    assert_eq!(describe(&merged), [
+        ('a', 'b', 1), ('c', 'b', 2), ('b', 'c', 3),
+        ('b', 'a', 4), ('a', 'b', 5), ('c', 'd', 6)
+    ]);
+    let deduped = merge_sorted_edge_lists(&lists, true);
+    println!("merge_sorted_edge_lists deduped: {:?}", describe(&deduped));
+    assert_eq!(describe(&deduped), [
+        ('a', 'b', 1), ('c', 'b', 2), ('a', 'b', 5), ('c', 'd', 6)
+    ]);
+    let empty: [Vec<Edge<char, i32>>; 0] = [];
+    assert!(merge_sorted_edge_lists(&empty, true).is_empty());
+}
+
+#[test]
+fn test_greedy_coloring() {
+    use algocol::AgcErrorKind;
+    use std::collections::HashMap;
+    let colour_count = |colours: &HashMap<i32, usize>| {
+        colours.values().max().map_or(0, |colour| colour + 1)
+    };
+    // A path 0 - 1 - 2 - ... - 9 with a few branches, which is still a tree
+    let mut matrix = AdjacencyMatrix::<i32, i32>::new();
+    for node in 0..9 {
+        matrix.push(Edge::new(node, node + 1, 1, EdgeKind::ToRight)).unwrap();
+    }
+    for (left, right) in [(2, 10), (5, 11), (11, 12)].iter() {
+        matrix.push(Edge::new(*left, *right, 1, EdgeKind::ToLeft)).unwrap();
+    }
+    let is_proper = |colours: &HashMap<i32, usize>| {
+        matrix.nodes().all(|from| {
+            matrix.get_adjacent(from).unwrap().keys().all(|to| {
+                colours[from] != colours[to]
+            })
+        })
+    };
+    // Colouring the even nodes on the path first, then every other node
+    // from the far end, forces extra colours
+    let arbitrary = [0, 2, 4, 6, 8, 12, 10, 9, 7, 5, 3, 1, 11];
+    let colours = matrix.greedy_coloring(&arbitrary).unwrap();
+    println!("greedy_coloring arbitrary: {:?}", colours);
+    assert!(is_proper(&colours));
+    assert!(colour_count(&colours) >= 3);
+    let smallest_last = matrix.smallest_last_ordering();
+    println!("smallest_last_ordering: {:?}", smallest_last);
+    assert_eq!(smallest_last.len(), 13);
+    let colours = matrix.greedy_coloring(&smallest_last).unwrap();
+    assert!(is_proper(&colours));
+    assert_eq!(colour_count(&colours), 2);
+    let error = matrix.greedy_coloring(&arbitrary[1..]).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::InvalidArgument);
+    let error = matrix.greedy_coloring(&[0, 0]).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::InvalidArgument);
+    let error = matrix.greedy_coloring(&[99]).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::NotFound);
+}
+
+#[test]
+fn test_paths_within_cost() {
+    use algocol::AgcErrorKind;
+    let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    let edges = [
+        ('s', 'a', 1), ('s', 'b', 4), ('a', 'b', 2), ('a', 't', 6),
+        ('b', 't', 1), ('b', 'a', 1), ('s', 't', 9)
+    ];
+    for (left, right, cost) in edges.iter() {
+        matrix.push(Edge::new(*left, *right, *cost, EdgeKind::ToRight))
+            .unwrap();
+    }
+    // Every simple path from s to t: s-a-b-t 4, s-b-t 5, s-a-t 7,
+    // s-b-a-t 11 and s-t 9
+    let paths = matrix.paths_within_cost(&'s', &'t', 7).unwrap();
+    println!("paths_within_cost: {:?}", paths);
+    assert_eq!(paths, vec![
+        (vec!['s', 'a', 'b', 't'], 4),
+        (vec!['s', 'b', 't'], 5),
+        (vec!['s', 'a', 't'], 7)
+    ]);
+    let all = matrix.paths_within_cost(&'s', &'t', 100).unwrap();
+    let costs = all.iter().map(|(_, cost)| *cost).collect::<Vec<i32>>();
+    assert_eq!(costs, vec![4, 5, 7, 9, 11]);
+    assert!(matrix.paths_within_cost(&'s', &'t', 3).unwrap().is_empty());
+    assert!(matrix.paths_within_cost(&'t', &'s', 100).unwrap().is_empty());
+    assert_eq!(
+        matrix.paths_within_cost(&'s', &'s', 0).unwrap(),
+        vec![(vec!['s'], 0)]
+    );
+    // A negative edge means paths over budget can still come back under it
+    *matrix.get_mut_edge(&'a', &'t').unwrap() = -10;
+    let paths = matrix.paths_within_cost(&'s', &'t', 0).unwrap();
+    assert_eq!(paths, vec![
+        (vec!['s', 'a', 't'], -9),
+        (vec!['s', 'b', 'a', 't'], -5)
+    ]);
+    let error = matrix.paths_within_cost(&'s', &'z', 10).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::NotFound);
+}
+
+#[test]
+fn test_dijkstra() {
+    use std::collections::HashMap;
+    use algocol::{AgcErrorKind, graph};
+    let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    let edges = [
+        ('s', 'a', 7), ('s', 'b', 2), ('b', 'a', 3), ('a', 'c', 1),
+        ('b', 'c', 8), ('c', 't', 2), ('b', 't', 9), ('x', 's', 1)
+    ];
+    for (left, right, cost) in edges.iter() {
+        matrix.push(Edge::new(*left, *right, *cost, EdgeKind::ToRight))
+            .unwrap();
+    }
+    let costs = matrix.dijkstra(&'s').unwrap();
+    println!("dijkstra: {:?}", costs);
+    let expected = [('s', 0), ('a', 5), ('b', 2), ('c', 6), ('t', 8)]
+        .iter()
+        .copied()
+        .collect::<HashMap<char, i32>>();
+    assert_eq!(costs, expected);
+    assert_eq!(graph::dijkstra(&matrix, &'s'), Ok(expected));
+    let path = matrix.dijkstra_path(&'s', &'t').unwrap();
+    println!("dijkstra_path: {:?}", path);
+    assert_eq!(path, Some((8, vec!['s', 'b', 'a', 'c', 't'])));
+    assert_eq!(graph::dijkstra_path(&matrix, &'s', &'t'), Ok(path));
+    let path = matrix.dijkstra_path(&'s', &'s').unwrap();
+    assert_eq!(path, Some((0, vec!['s'])));
+    assert_eq!(matrix.dijkstra_path(&'t', &'s').unwrap(), None);
+    assert_eq!(matrix.dijkstra(&'t').unwrap().len(), 1);
+    let error = matrix.dijkstra(&'z').unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::NotFound);
+    let error = matrix.dijkstra_path(&'s', &'z').unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::NotFound);
+    *matrix.get_mut_edge(&'x', &'s').unwrap() = -1;
+    let error = matrix.dijkstra(&'s').unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_bfs_dfs() {
+    use algocol::{AgcErrorKind, graph};
+    let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    let edges = [
+        ('a', 'b'), ('a', 'c'), ('b', 'd'), ('c', 'd'), ('d', 'e'),
+        ('x', 'a')
+    ];
+    for (left, right) in edges.iter() {
+        matrix.push(Edge::new(*left, *right, 1, EdgeKind::ToRight)).unwrap();
+    }
+    let order = matrix.bfs(&'a').unwrap();
+    println!("bfs: {:?}", order);
+    assert_eq!(order.len(), 5);
+    assert_eq!(order[0], 'a');
+    let mut second_level = order[1..3].to_vec();
+    second_level.sort();
+    assert_eq!(second_level, ['b', 'c']);
+    assert_eq!(order[3..], ['d', 'e']);
+    assert_eq!(graph::bfs(&matrix, &'a'), Ok(order));
+    assert_eq!(matrix.bfs(&'e').unwrap(), vec!['e']);
+    // Both searches go down one branch to the end before trying the other
+    let order = matrix.dfs(&'a').unwrap();
+    println!("dfs: {:?}", order);
+    assert_eq!(order, matrix.dfs_recursive(&'a').unwrap());
+    assert_eq!(graph::dfs(&matrix, &'a').unwrap(), order);
+    assert_eq!(graph::dfs_recursive(&matrix, &'a').unwrap(), order);
+    assert_eq!(order[0], 'a');
+    if order[1] == 'b' {
+        assert_eq!(order[1..], ['b', 'd', 'e', 'c']);
+    } else {
+        assert_eq!(order[1..], ['c', 'd', 'e', 'b']);
+    }
+    let mut cycle = AdjacencyMatrix::<char, i32>::new();
+    let edges = [('a', 'b'), ('b', 'c'), ('c', 'a'), ('c', 'd'), ('d', 'b')];
+    for (left, right) in edges.iter() {
+        cycle.push(Edge::new(*left, *right, 1, EdgeKind::ToRight)).unwrap();
+    }
+    assert_eq!(cycle.dfs(&'a').unwrap(), vec!['a', 'b', 'c', 'd']);
+    assert_eq!(cycle.dfs_recursive(&'c').unwrap().len(), 4);
+    assert_eq!(cycle.bfs(&'d').unwrap(), vec!['d', 'b', 'c', 'a']);
+    for error in [
+        matrix.bfs(&'z').unwrap_err(),
+        matrix.dfs(&'z').unwrap_err(),
+        matrix.dfs_recursive(&'z').unwrap_err()
+    ].iter() {
+        assert_eq!(error.kind(), AgcErrorKind::NotFound);
+    }
+}
+
+#[test]
+fn test_topological_sort() {
+    use std::collections::HashMap;
+    use algocol::{AgcErrorKind, graph};
+    let mut matrix = AdjacencyMatrix::<&str, i32>::new();
+    let edges = [
+        ("shirt", "tie", EdgeKind::ToRight),
+        ("tie", "jacket", EdgeKind::ToRight),
+        ("trousers", "shirt", EdgeKind::ToLeft),
+        ("trousers", "shoes", EdgeKind::ToRight),
+        ("socks", "shoes", EdgeKind::ToRight),
+        ("belt", "trousers", EdgeKind::ToLeft),
+        ("belt", "jacket", EdgeKind::ToRight)
+    ];
+    for (left, right, kind) in edges.iter() {
+        matrix.push(Edge::new(*left, *right, 1, *kind)).unwrap();
+    }
+    let order = matrix.topological_sort().unwrap();
+    println!("topological_sort: {:?}", order);
+    assert_eq!(order.len(), 7);
+    assert_eq!(graph::topological_sort(&matrix).unwrap().len(), 7);
+    let positions = order
+        .iter()
+        .enumerate()
+        .map(|(position, node)| (*node, position))
+        .collect::<HashMap<&str, usize>>();
+    for from in matrix.nodes() {
+        for to in matrix.get_adjacent(from).unwrap().keys() {
+            assert!(positions[from] < positions[to]);
+        }
+    }
+    assert!(positions["shirt"] < positions["trousers"]);
+    assert!(positions["trousers"] < positions["belt"]);
+    // A bidirectional edge goes both ways, so it is a cycle on its own
+    let mut two_way = matrix.clone();
+    two_way.push(Edge::new("hat", "scarf", 1, EdgeKind::Bidirectional))
+        .unwrap();
+    let error = two_way.topological_sort().unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::CyclicGraph);
+    matrix.push(Edge::new("jacket", "shirt", 1, EdgeKind::ToRight)).unwrap();
+    let error = matrix.topological_sort().unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::CyclicGraph);
+    let error = graph::topological_sort(&matrix).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::CyclicGraph);
+    let empty = AdjacencyMatrix::<char, i32>::new();
+    assert!(empty.topological_sort().unwrap().is_empty());
+}
+
+#[test]
+fn test_edges() {
+    let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    matrix.push(Edge::new('a', 'b', 1, EdgeKind::ToRight)).unwrap();
+    matrix.push(Edge::new('b', 'c', 2, EdgeKind::Bidirectional)).unwrap();
+    matrix.push(Edge::new('d', 'a', 3, EdgeKind::ToLeft)).unwrap();
+    let mut edges = matrix
+        .edges()
+        .map(|edge| (edge.left, edge.right, edge.cost, edge.edge_kind))
+        .collect::<Vec<(char, char, i32, EdgeKind)>>();
+    edges.sort_by_key(|edge| (edge.0, edge.1));
+    println!("edges: {:?}", edges);
+    assert_eq!(edges, vec![
+        ('a', 'b', 1, EdgeKind::ToRight),
+        ('a', 'd', 3, EdgeKind::ToRight),
+        ('b', 'c', 2, EdgeKind::ToRight),
+        ('c', 'b', 2, EdgeKind::ToRight)
+    ]);
+    assert_eq!(AdjacencyMatrix::<char, i32>::new().edges().count(), 0);
+}
+
+#[test]
+fn test_bellman_ford() {
+    use std::collections::HashMap;
+    use algocol::{AgcErrorKind, graph};
+    let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    let edges = [
+        ('s', 'a', 4), ('s', 'b', 5), ('a', 'c', 3), ('b', 'a', -3),
+        ('c', 't', 2), ('b', 't', 9), ('x', 's', 1)
+    ];
+    for (left, right, cost) in edges.iter() {
+        matrix.push(Edge::new(*left, *right, *cost, EdgeKind::ToRight))
+            .unwrap();
+    }
+    let costs = matrix.bellman_ford(&'s').unwrap();
+    println!("bellman_ford: {:?}", costs);
+    let expected = [('s', 0), ('a', 2), ('b', 5), ('c', 5), ('t', 7)]
+        .iter()
+        .copied()
+        .collect::<HashMap<char, i32>>();
+    assert_eq!(costs, expected);
+    assert_eq!(graph::bellman_ford(&matrix, &'s'), Ok(expected));
+    // Dijkstra's algorithm refuses to run on a negative edge
+    assert!(matrix.dijkstra(&'s').is_err());
+    let error = matrix.bellman_ford(&'z').unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::NotFound);
+    // a -> c -> b -> a costs 3 - 4 - 3 = -4
+    matrix.push(Edge::new('c', 'b', -4, EdgeKind::ToRight)).unwrap();
+    let error = matrix.bellman_ford(&'s').unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::NegativeCycle);
+    // The cycle cannot be reached from `t`
+    let costs = matrix.bellman_ford(&'t').unwrap();
+    assert_eq!(costs.len(), 1);
+}
+
+#[test]
+fn test_astar() {
+    use algocol::{AgcErrorKind, graph};
+    // A 6x6 grid where moving right costs 1 and moving down costs 2
+    let mut matrix = AdjacencyMatrix::<(i32, i32), i32>::new();
+    for x in 0..6 {
+        for y in 0..6 {
+            let kind = EdgeKind::Bidirectional;
+            if x < 5 {
+                matrix.push(Edge::new((x, y), (x + 1, y), 1, kind)).unwrap();
+            }
+            if y < 5 {
+                matrix.push(Edge::new((x, y), (x, y + 1), 2, kind)).unwrap();
+            }
+        }
+    }
+    let goal = (5, 5);
+    let manhattan = |node: &(i32, i32)| {
+        (goal.0 - node.0).abs() + 2 * (goal.1 - node.1).abs()
+    };
+    let (blind, blind_path) = matrix.astar_counted(&(0, 0), &goal, |_| 0)
+        .unwrap();
+    let (guided, guided_path) = matrix
+        .astar_counted(&(0, 0), &goal, manhattan)
+        .unwrap();
+    println!("astar expanded {} nodes, dijkstra expanded {}", guided, blind);
+    assert!(guided < blind);
+    let (cost, path) = guided_path.unwrap();
+    assert_eq!(cost, 15);
+    assert_eq!(blind_path.unwrap().0, 15);
+    assert_eq!(path.len(), 11);
+    assert_eq!(path[0], (0, 0));
+    assert_eq!(path[10], goal);
+    for pair in path.windows(2) {
+        assert!(matrix.get_edge(&pair[0], &pair[1]).is_some());
+    }
+    let dijkstra = matrix.dijkstra_path(&(0, 0), &goal).unwrap().unwrap();
+    assert_eq!(dijkstra.0, cost);
+    let wrapped = graph::astar(&matrix, &(0, 0), &goal, manhattan).unwrap();
+    assert_eq!(wrapped.unwrap().0, cost);
+    let path = matrix.astar(&goal, &goal, manhattan).unwrap();
+    assert_eq!(path, Some((0, vec![goal])));
+    let mut one_way = AdjacencyMatrix::<char, i32>::new();
+    one_way.push(Edge::new('a', 'b', 1, EdgeKind::ToRight)).unwrap();
+    assert_eq!(one_way.astar(&'b', &'a', |_| 0).unwrap(), None);
+    let error = one_way.astar(&'a', &'z', |_| 0).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::NotFound);
+}
+
+#[test]
+fn test_astar_tie_break() {
+    // A 7x7 grid with a wall along x = 3 from y = 0 to y = 4, so the
+    // manhattan distance to the goal is a poor guess near the wall
+    let walls = (0..5).map(|y| (3, y)).collect::<Vec<(i32, i32)>>();
+    let build = || {
+        let mut matrix = AdjacencyMatrix::<(i32, i32), i32>::new();
+        let kind = EdgeKind::Bidirectional;
+        for x in 0..7 {
+            for y in 0..7 {
+                if walls.contains(&(x, y)) {
+                    continue;
+                }
+                if x < 6 && !walls.contains(&(x + 1, y)) {
+                    matrix.push(Edge::new((x, y), (x + 1, y), 1, kind))
+                        .unwrap();
+                }
+                if y < 6 && !walls.contains(&(x, y + 1)) {
+                    matrix.push(Edge::new((x, y), (x, y + 1), 1, kind))
+                        .unwrap();
+                }
+            }
+        }
+        matrix
+    };
+    let goal = (6, 0);
+    let manhattan = |node: &(i32, i32)| {
+        (goal.0 - node.0).abs() + (goal.1 - node.1).abs()
+    };
+    // Many nodes share the same cost plus heuristic, and preferring the
+    // ones furthest from the start expands the same number of nodes no
+    // matter what order the edges are stored in
+    for _ in 0..20 {
+        let (expanded, path) = build()
+            .astar_counted(&(0, 0), &goal, manhattan)
+            .unwrap();
+        println!("astar expanded {} nodes around a wall", expanded);
+        assert_eq!(expanded, 25);
+        assert_eq!(path.unwrap().0, 16);
+    }
+    let (blind, _) = build().astar_counted(&(0, 0), &goal, |_| 0).unwrap();
+    assert!(blind > 25);
+}
+
+#[test]
+fn test_prim_mst() {
+    use algocol::{AgcErrorKind, graph};
+    let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    let edges = [
+        ('a', 'b', 4), ('a', 'h', 8), ('b', 'c', 8), ('b', 'h', 11),
+        ('c', 'd', 7), ('c', 'f', 4), ('c', 'i', 2), ('d', 'e', 9),
+        ('d', 'f', 14), ('e', 'f', 10), ('f', 'g', 2), ('g', 'h', 1),
+        ('g', 'i', 6), ('h', 'i', 7)
+    ];
+    for (left, right, cost) in edges.iter() {
+        let kind = EdgeKind::Bidirectional;
+        matrix.push(Edge::new(*left, *right, *cost, kind)).unwrap();
+    }
+    for start in matrix.all_nodes() {
+        let tree = matrix.prim_mst(start).unwrap();
+        let total = tree.iter().map(|edge| edge.cost).sum::<i32>();
+        println!("prim_mst from {}: {} {:?}", start, total, tree);
+        assert_eq!(tree.len(), 8);
+        assert_eq!(total, 37);
+        assert!(tree.iter().all(|edge| {
+            edge.edge_kind == EdgeKind::Bidirectional
+                && matrix.get_edge(&edge.left, &edge.right) == Some(&edge.cost)
+        }));
+    }
+    // Direction is ignored, and the cheaper way round is used
+    let mut directed = AdjacencyMatrix::<char, i32>::new();
+    directed.push(Edge::new('a', 'b', 5, EdgeKind::ToRight)).unwrap();
+    directed.push(Edge::new('b', 'a', 2, EdgeKind::ToRight)).unwrap();
+    directed.push(Edge::new('c', 'b', 1, EdgeKind::ToRight)).unwrap();
+    let tree = directed.prim_mst(&'a').unwrap();
+    assert_eq!(tree.iter().map(|edge| edge.cost).sum::<i32>(), 3);
+    let tree = graph::prim_mst(&directed, &'c').unwrap();
+    assert_eq!(tree.iter().map(|edge| edge.cost).sum::<i32>(), 3);
+    directed.push(Edge::new('x', 'y', 1, EdgeKind::ToRight)).unwrap();
+    let error = directed.prim_mst(&'a').unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::Disconnected);
+    let error = directed.prim_mst(&'z').unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::NotFound);
+}
+
+#[test]
+fn test_disjoint_set() {
+    use algocol::{AgcErrorKind, graph::DisjointSet};
+    let mut sets = DisjointSet::new();
+    assert!(sets.is_empty());
+    for item in 0..10 {
+        assert!(sets.make_set(item));
+    }
+    assert!(!sets.make_set(3));
+    assert_eq!(sets.len(), 10);
+    assert_eq!(sets.set_count(), 10);
+    // Evens and odds end up in 2 sets
+    for item in 2..10 {
+        assert!(sets.union(&item, &(item - 2)).unwrap());
+    }
+    println!("disjoint_set: {:?}", sets);
+    assert_eq!(sets.set_count(), 2);
+    assert!(!sets.union(&0, &8).unwrap());
+    for a in 0..10 {
+        for b in 0..10 {
+            assert_eq!(sets.connected(&a, &b), a % 2 == b % 2);
+        }
+    }
+    let even = *sets.find(&4).unwrap();
+    assert_eq!(even % 2, 0);
+    assert_eq!(sets.find(&0), Some(&even));
+    assert_ne!(sets.find(&1), Some(&even));
+    assert!(sets.union(&7, &4).unwrap());
+    assert_eq!(sets.set_count(), 1);
+    assert!(sets.connected(&1, &8));
+    assert_eq!(sets.find(&42), None);
+    assert!(!sets.connected(&1, &42));
+    let error = sets.union(&1, &42).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::NotFound);
+}
+
+#[test]
+fn test_kruskal_mst() {
+    use algocol::{AgcErrorKind, graph};
+    let mut matrix = AdjacencyMatrix::<char, i32>::new();
+    let edges = [
+        ('a', 'b', 4), ('a', 'h', 8), ('b', 'c', 8), ('b', 'h', 11),
+        ('c', 'd', 7), ('c', 'f', 4), ('c', 'i', 2), ('d', 'e', 9),
+        ('d', 'f', 14), ('e', 'f', 10), ('f', 'g', 2), ('g', 'h', 1),
+        ('g', 'i', 6), ('h', 'i', 7)
+    ];
+    for (left, right, cost) in edges.iter() {
+        let kind = EdgeKind::Bidirectional;
+        matrix.push(Edge::new(*left, *right, *cost, kind)).unwrap();
+    }
+    let tree = matrix.kruskal_mst().unwrap();
+    println!("kruskal_mst: {:?}", tree);
+    let costs = tree.iter().map(|edge| edge.cost).collect::<Vec<i32>>();
+    assert_eq!(costs, [1, 2, 2, 4, 4, 7, 8, 9]);
+    let prim = matrix.prim_mst(&'a').unwrap();
+    let prim_total = prim.iter().map(|edge| edge.cost).sum::<i32>();
+    assert_eq!(costs.iter().sum::<i32>(), prim_total);
+    let wrapped = graph::kruskal_mst(&matrix).unwrap();
+    assert_eq!(wrapped.iter().map(|edge| edge.cost).sum::<i32>(), prim_total);
+    let mut directed = AdjacencyMatrix::<char, i32>::new();
+    directed.push(Edge::new('a', 'b', 5, EdgeKind::ToRight)).unwrap();
+    directed.push(Edge::new('b', 'a', 2, EdgeKind::ToRight)).unwrap();
+    let tree = directed.kruskal_mst().unwrap();
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree[0].cost, 2);
+    directed.push(Edge::new('x', 'y', 1, EdgeKind::ToRight)).unwrap();
+    let error = directed.kruskal_mst().unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::Disconnected);
+    let empty = AdjacencyMatrix::<char, i32>::new();
+    assert!(empty.kruskal_mst().unwrap().is_empty());
+}
+
+#[test]
+fn test_connected_components() {
+    use algocol::graph;
+    let mut matrix = AdjacencyMatrix::<i32, i32>::new();
+    let edges = [
+        (1, 2, EdgeKind::ToRight), (3, 2, EdgeKind::ToRight),
+        (3, 4, EdgeKind::ToLeft), (10, 11, EdgeKind::Bidirectional),
+        (12, 11, EdgeKind::ToRight)
+    ];
+    for (left, right, kind) in edges.iter() {
+        matrix.push(Edge::new(*left, *right, 1, *kind)).unwrap();
+    }
+    matrix.register_node(&99);
+    let mut components = matrix.connected_components();
+    for component in components.iter_mut() {
+        component.sort();
+    }
+    components.sort();
+    println!("connected_components: {:?}", components);
+    assert_eq!(components, vec![vec![1, 2, 3, 4], vec![10, 11, 12], vec![99]]);
+    assert_eq!(matrix.component_count(), 3);
+    assert_eq!(graph::connected_components(&matrix).unwrap().len(), 3);
+    matrix.push(Edge::new(4, 12, 1, EdgeKind::ToRight)).unwrap();
+    assert_eq!(matrix.component_count(), 2);
+    assert_eq!(graph::component_count(&matrix), Ok(2));
+    let empty = AdjacencyMatrix::<i32, i32>::new();
+    assert!(empty.connected_components().is_empty());
+    assert_eq!(empty.component_count(), 0);
+}
+
+#[test]
+fn test_find_cycle_directed() {
+    use algocol::graph;
+    let mut matrix = AdjacencyMatrix::<i32, i32>::new();
+    let edges = [(1, 2), (1, 3), (2, 4), (3, 4), (4, 5), (6, 5)];
+    for (left, right) in edges.iter() {
+        matrix.push(Edge::new(*left, *right, 1, EdgeKind::ToRight)).unwrap();
+    }
+    assert!(!matrix.has_cycle_directed());
+    assert_eq!(matrix.find_cycle_directed(), None);
+    assert!(!graph::has_cycle_directed(&matrix));
+    assert_eq!(graph::find_cycle_directed(&matrix), None);
+    assert!(matrix.topological_sort().is_ok());
+    // 5 -> 7 -> 8 -> 5 is a cycle of 3 nodes
+    matrix.push(Edge::new(5, 7, 1, EdgeKind::ToRight)).unwrap();
+    matrix.push(Edge::new(7, 8, 1, EdgeKind::ToRight)).unwrap();
+    matrix.push(Edge::new(8, 5, 1, EdgeKind::ToRight)).unwrap();
+    assert!(matrix.has_cycle_directed());
+    assert!(graph::has_cycle_directed(&matrix));
+    assert_eq!(graph::find_cycle_directed(&matrix).unwrap().len(), 3);
+    let mut cycle = matrix.find_cycle_directed().unwrap();
+    println!("find_cycle_directed: {:?}", cycle);
+    while cycle[0] != 5 {
+        cycle.rotate_left(1);
+    }
+    assert_eq!(cycle, vec![5, 7, 8]);
+    assert!(matrix.topological_sort().is_err());
+    let mut two_way = AdjacencyMatrix::<char, i32>::new();
+    two_way.push(Edge::new('a', 'b', 1, EdgeKind::Bidirectional)).unwrap();
+    let mut cycle = two_way.find_cycle_directed().unwrap();
+    cycle.sort();
+    assert_eq!(cycle, vec!['a', 'b']);
+    assert!(!AdjacencyMatrix::<char, i32>::new().has_cycle_directed());
+}
\ No newline at end of file
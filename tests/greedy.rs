@@ -0,0 +1,33 @@
+extern crate algocol;
+
+#[test]
+fn test_knapsack_fractional() {
+    use algocol::{AgcErrorKind, greedy::knapsack_fractional};
+    let weights = [10.0, 20.0, 30.0];
+    let values = [60.0, 100.0, 120.0];
+    let capacity = 50.0;
+    let fractional = knapsack_fractional(&weights, &values, capacity)
+        .unwrap();
+    // The best 0/1 solution, found by trying every subset of the items
+    let mut whole = 0.0f64;
+    for subset in 0..(1 << weights.len()) {
+        let chosen = (0..weights.len())
+            .filter(|item| subset & (1 << item) != 0);
+        let weight = chosen.clone().map(|item| weights[item]).sum::<f64>();
+        if weight <= capacity {
+            whole = whole.max(chosen.map(|item| values[item]).sum::<f64>());
+        }
+    }
+    println!("fractional: {}, 0/1: {}", fractional, whole);
+    assert!((fractional - 240.0).abs() < 1e-9);
+    assert_eq!(whole, 220.0);
+    assert!(fractional > whole);
+    // Everything fits
+    let everything = knapsack_fractional(&weights, &values, 100.0).unwrap();
+    assert!((everything - 280.0).abs() < 1e-9);
+    assert_eq!(knapsack_fractional(&[], &[], 10.0), Ok(0.0));
+    let error = knapsack_fractional(&weights, &values[..2], 50.0).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::InvalidArgument);
+    let error = knapsack_fractional(&[0.0], &[1.0], 50.0).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::InvalidArgument);
+}
\ No newline at end of file
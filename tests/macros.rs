@@ -1,5 +1,3 @@
-use algocol::alreadysorted;
-
 #[test]
 fn test_alreadysorted() {
     use algocol::alreadysorted;
@@ -0,0 +1,23 @@
+extern crate algocol;
+
+#[test]
+fn test_pairwise_sum() {
+    use algocol::numeric::pairwise_sum;
+    // Every tiny value is lost when it is added to 1 on its own
+    let mut values = vec![1.0];
+    values.extend(vec![1e-16; 1 << 20]);
+    let expected = 1.0 + (1 << 20) as f64 * 1e-16;
+    let naive = values.iter().fold(0.0, |sum, x| sum + x);
+    let pairwise = pairwise_sum(&values);
+    println!("naive: {:e}, pairwise: {:e}", naive - 1.0, pairwise - 1.0);
+    assert_eq!(naive, 1.0);
+    assert!((pairwise - expected).abs() < 1e-15);
+    let tenths = vec![0.1; 1_000_000];
+    let naive = tenths.iter().fold(0.0, |sum, x| sum + x);
+    let pairwise = pairwise_sum(&tenths);
+    println!("naive: {}, pairwise: {}", naive, pairwise);
+    assert!((pairwise - 100_000.0).abs() < 1e-8);
+    assert!((naive - 100_000.0).abs() > 1e-8);
+    assert_eq!(pairwise_sum(&[]), 0.0);
+    assert_eq!(pairwise_sum(&[1.5, 2.5]), 4.0);
+}
\ No newline at end of file
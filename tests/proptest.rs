@@ -0,0 +1,159 @@
+#![cfg(feature = "proptest")]
+
+extern crate algocol;
+
+use std::fmt::Debug;
+use algocol::{
+    sort::{countingsort, radixsort_lsd, stable_adapter_by, SortAlgorithm},
+    AgcResult
+};
+use proptest::prelude::*;
+
+/// Vectors of arbitrary `i64`s, including empty vectors. Small values are
+/// mixed in so that there are plenty of duplicates, and proptest shrinks
+/// failing vectors down to a minimal example.
+fn sequences() -> impl Strategy<Value = Vec<i64>> {
+    prop::collection::vec(
+        prop_oneof![any::<i64>(), -8i64..8],
+        0..200
+    )
+}
+
+/// Sort `input` with `sort` in both directions and check that the result is
+/// the same as `slice::sort`, which also means that the result is a
+/// permutation of `input`.
+fn check_sort<F, T>(sort: F, input: &[T]) -> Result<(), TestCaseError>
+where
+    F: Fn(&mut [T], bool) -> AgcResult<&mut [T]>,
+    T: Ord + Clone + Debug
+{
+    let mut expected = input.to_vec();
+    expected.sort();
+    let mut sorted = input.to_vec();
+    prop_assert!(sort(&mut sorted[..], true).is_ok());
+    prop_assert_eq!(&sorted, &expected);
+    expected.reverse();
+    let mut sorted = input.to_vec();
+    prop_assert!(sort(&mut sorted[..], false).is_ok());
+    prop_assert_eq!(&sorted, &expected);
+    Ok(())
+}
+
+/// Check `algorithm` with `check_sort`.
+fn check_algorithm(
+    algorithm: SortAlgorithm,
+    input: &[i64]
+) -> Result<(), TestCaseError> {
+    check_sort(
+        |sequence, ascending| {
+            algorithm.sort_by(sequence, ascending, |a, b| a.cmp(b))
+        },
+        input
+    )
+}
+
+proptest! {
+    #[test]
+    fn bubblesort_matches_std(input in sequences()) {
+        check_algorithm(SortAlgorithm::Bubble, &input)?;
+    }
+
+    #[test]
+    fn selectionsort_matches_std(input in sequences()) {
+        check_algorithm(SortAlgorithm::Selection, &input)?;
+    }
+
+    #[test]
+    fn insertionsort_matches_std(input in sequences()) {
+        check_algorithm(SortAlgorithm::Insertion, &input)?;
+    }
+
+    #[test]
+    fn mergesort_matches_std(input in sequences()) {
+        check_algorithm(SortAlgorithm::Merge, &input)?;
+    }
+
+    #[test]
+    fn mergesort_recursively_matches_std(input in sequences()) {
+        check_algorithm(SortAlgorithm::MergeRecursive, &input)?;
+    }
+
+    #[test]
+    fn timsort_matches_std(input in sequences()) {
+        check_algorithm(SortAlgorithm::Tim, &input)?;
+    }
+
+    #[test]
+    fn quicksort_matches_std(input in sequences()) {
+        check_algorithm(SortAlgorithm::Quick, &input)?;
+    }
+
+    #[test]
+    fn quicksort_recursively_matches_std(input in sequences()) {
+        check_algorithm(SortAlgorithm::QuickRecursive, &input)?;
+    }
+
+    #[test]
+    fn shellsort_matches_std(input in sequences()) {
+        check_algorithm(SortAlgorithm::Shell, &input)?;
+    }
+
+    #[test]
+    fn cocktailsort_matches_std(input in sequences()) {
+        check_algorithm(SortAlgorithm::Cocktail, &input)?;
+    }
+
+    #[test]
+    fn combsort_matches_std(input in sequences()) {
+        check_algorithm(SortAlgorithm::Comb, &input)?;
+    }
+
+    #[test]
+    fn heapsort_matches_std(input in sequences()) {
+        check_algorithm(SortAlgorithm::Heap, &input)?;
+    }
+
+    #[test]
+    fn introsort_matches_std(input in sequences()) {
+        check_algorithm(SortAlgorithm::Intro, &input)?;
+    }
+
+    #[test]
+    fn binary_insertionsort_matches_std(input in sequences()) {
+        check_algorithm(SortAlgorithm::BinaryInsertion, &input)?;
+    }
+
+    #[test]
+    fn countingsort_matches_std(
+        input in prop::collection::vec(any::<u16>(), 0..200)
+    ) {
+        check_sort(countingsort::<[u16], u16>, &input)?;
+    }
+
+    #[test]
+    fn radixsort_lsd_matches_std(
+        input in prop::collection::vec(any::<u64>(), 0..200)
+    ) {
+        check_sort(radixsort_lsd::<[u64], u64>, &input)?;
+    }
+
+    #[test]
+    fn stable_adapter_matches_std_stable_sort(
+        keys in prop::collection::vec(0u8..8, 0..100)
+    ) {
+        let pairs = keys
+            .iter()
+            .enumerate()
+            .map(|(index, key)| (*key, index))
+            .collect::<Vec<(u8, usize)>>();
+        let mut expected = pairs.clone();
+        expected.sort_by_key(|pair| pair.0);
+        for algorithm in SortAlgorithm::ALL.iter() {
+            let mut sorted = pairs.clone();
+            prop_assert!(stable_adapter_by(
+                &mut sorted[..], *algorithm, true, |a, b| a.0.cmp(&b.0)
+            ).is_ok());
+            prop_assert_eq!(&sorted, &expected);
+        }
+    }
+}
\ No newline at end of file
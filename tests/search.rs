@@ -0,0 +1,23 @@
+extern crate algocol;
+
+#[test]
+fn test_linearsearch() {
+    use algocol::search::{linearsearch, linearsearch_all_by, linearsearch_by};
+    let array = [7, 3, 9, 3, 0, 3];
+    let location = linearsearch(&array[..], &3);
+    println!("linearsearch: {:?}", location);
+    assert_eq!(location, Some(1));
+    assert_eq!(linearsearch(&array[..], &7), Some(0));
+    assert_eq!(linearsearch(&array[..], &4), None);
+    assert_eq!(linearsearch_by(&array[..], |x| *x > 8), Some(2));
+    assert_eq!(linearsearch_by(&array[..], |x| *x < 0), None);
+    let all = linearsearch_all_by(&array[..], |x| *x == 3);
+    println!("linearsearch_all_by: {:?}", all);
+    assert_eq!(all, [1, 3, 5]);
+    let empty: [i32; 0] = [];
+    assert_eq!(linearsearch(&empty[..], &1), None);
+    assert!(linearsearch_all_by(&empty[..], |_| true).is_empty());
+    // Works on types which can only be compared for equality
+    let words = vec!["pear", "fig", "plum"];
+    assert_eq!(linearsearch(&words, &"plum"), Some(2));
+}
\ No newline at end of file
@@ -41,6 +41,7 @@ fn test_mergesort() {
 }
 
 #[test]
+#[cfg(feature = "internals")]
 fn test_merge() {
     use algocol::sort::mergesort::merge;
     let mut array = [7, 6, 1, 3, 6, 2, 4, 5, 8, 20];
@@ -99,12 +100,1268 @@ fn test_quicksort_recursive() {
 }
 
 #[test]
+fn test_quicksort_random() {
+    use algocol::{
+        sort::{
+            is_sorted,
+            quicksort::{quicksort, quicksort_recursively}
+        },
+        utils::random::XorShift
+    };
+    // Short segments are where the old bookkeeping went wrong, so most of
+    // the lengths here are small
+    let mut random = XorShift::new(65);
+    for _ in 0..1000 {
+        let length = random.next_below(40);
+        let sequence = (0..length)
+            .map(|_| random.next_below(20) as i32)
+            .collect::<Vec<i32>>();
+        for ascending in [true, false].iter().copied() {
+            let mut iterative = sequence.clone();
+            quicksort(&mut iterative[..], ascending).unwrap();
+            assert!(
+                is_sorted(&iterative, ascending),
+                "quicksort failed on {:?}", sequence
+            );
+            let mut recursive = sequence.clone();
+            quicksort_recursively(&mut recursive[..], ascending).unwrap();
+            assert!(
+                is_sorted(&recursive, ascending),
+                "quicksort_recursively failed on {:?}", sequence
+            );
+        }
+    }
+    let mut pair = [2, 1];
+    quicksort(&mut pair, true).unwrap();
+    assert_eq!(pair, [1, 2]);
+}
+
+#[test]
+fn test_quicksort_randomized() {
+    use algocol::{
+        sort::quicksort::quicksort_randomized_by,
+        utils::cmp::CountingComparator
+    };
+    // Always picking the last element as the pivot would take about 50
+    // million comparisons on a sorted slice this long
+    let ascending = (0..10000).collect::<Vec<i32>>();
+    for direction in [true, false].iter().copied() {
+        let counter = CountingComparator::new(|a: &i32, b: &i32| a.cmp(b));
+        let mut sequence = ascending.clone();
+        quicksort_randomized_by(&mut sequence[..], direction, 67, |a, b| {
+            counter.compare(a, b)
+        }).unwrap();
+        println!("quicksort_randomized comparisons: {}", counter.count());
+        let mut expected = ascending.clone();
+        if !direction {
+            expected.reverse();
+        }
+        assert_eq!(sequence, expected);
+        assert!(counter.count() < 1_000_000);
+    }
+}
+
+#[test]
+fn test_quicksort_median3() {
+    use algocol::{
+        sort::quicksort::{quicksort_by, quicksort_median3_by},
+        utils::cmp::CountingComparator
+    };
+    let sorted = (0..5000).collect::<Vec<i32>>();
+    let reversed = (0..5000).rev().collect::<Vec<i32>>();
+    for input in [&sorted, &reversed].iter() {
+        let lomuto = CountingComparator::new(|a: &i32, b: &i32| a.cmp(b));
+        let mut sequence = input.to_vec();
+        quicksort_by(&mut sequence[..], true, |a, b| lomuto.compare(a, b))
+            .unwrap();
+        assert_eq!(sequence, sorted);
+        let median3 = CountingComparator::new(|a: &i32, b: &i32| a.cmp(b));
+        let mut sequence = input.to_vec();
+        quicksort_median3_by(&mut sequence[..], true, |a, b| {
+            median3.compare(a, b)
+        }).unwrap();
+        assert_eq!(sequence, sorted);
+        println!(
+            "quicksort comparisons: {}, quicksort_median3 comparisons: {}",
+            lomuto.count(),
+            median3.count()
+        );
+        assert!(median3.count() < lomuto.count());
+        assert!(median3.count() < 200_000);
+    }
+    let mut sequence = vec![3, 1, 2, 3, 1, 2, 2, 0];
+    quicksort_median3_by(&mut sequence[..], false, |a, b| a.cmp(b)).unwrap();
+    assert_eq!(sequence, [3, 3, 2, 2, 2, 1, 1, 0]);
+}
+
+#[test]
+#[cfg(feature = "internals")]
+fn test_partition_randomized() {
+    use algocol::{
+        AgcErrorKind,
+        sort::quicksort::{partition_randomized, verify_partition_by}
+    };
+    let mut sequence = [5, 3, 8, 1, 9, 2, 7];
+    let pivot = partition_randomized(
+        &mut sequence, 1, 6, true, |a, b| a.cmp(b), || 2
+    ).unwrap();
+    println!("partition_randomized: {:?}", sequence);
+    assert_eq!(sequence[pivot], 8);
+    assert_eq!((sequence[0], sequence[6]), (5, 7));
+    assert!(verify_partition_by(&sequence[1..6], pivot - 1, true, |a, b| {
+        a.cmp(b)
+    }));
+    let error = partition_randomized(
+        &mut sequence, 1, 6, true, |a, b| a.cmp(b), || 6
+    ).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::OutOfBounds);
+}
+
+#[test]
+#[cfg(feature = "internals")]
 fn test_partition() {
-    use algocol::sort::quicksort::partition;
+    use algocol::sort::quicksort::{partition, verify_partition_by};
     let mut sequence = [10, 80, 30, 90, 40, 50, 70];
     let result = partition(&mut sequence, 0, 7, true, |a, b| a.cmp(b));
     println!("quicksort result: {:?}", result);
     println!("quicksort: {:?}", sequence);
     assert_eq!(sequence, [10, 30, 40, 50, 70, 90, 80]);
     assert!(matches!(result, Ok(4)));
+    assert!(verify_partition_by(&sequence, 4, true, |a, b| a.cmp(b)));
+    let mut sequence = [10, 80, 30, 90, 40, 50, 70];
+    let pivot = partition(&mut sequence, 0, 7, false, |a, b| a.cmp(b))
+        .unwrap();
+    println!("descending partition: {:?}", sequence);
+    assert!(verify_partition_by(&sequence, pivot, false, |a, b| a.cmp(b)));
+}
+
+#[test]
+fn test_verify_partition() {
+    use algocol::sort::quicksort::verify_partition_by;
+    let compare = |a: &i32, b: &i32| a.cmp(b);
+    assert!(verify_partition_by(&[1, 2, 0, 5, 9, 7], 3, true, compare));
+    assert!(verify_partition_by(&[9, 7, 5, 1, 2, 0], 2, false, compare));
+    assert!(verify_partition_by(&[5, 5, 5], 1, true, compare));
+    // 6 is on the wrong side of the pivot
+    assert!(!verify_partition_by(&[1, 6, 0, 5, 9, 7], 3, true, compare));
+    assert!(!verify_partition_by(&[1, 2, 0, 5, 9, 7], 3, false, compare));
+    assert!(!verify_partition_by(&[1, 2, 3], 3, true, compare));
+}
+
+#[test]
+fn test_count_significant_inversions() {
+    use algocol::sort::mergesort::count_significant_inversions;
+
+    fn brute_force(sequence: &[i64]) -> u64 {
+        let mut count = 0;
+        for i in 0..sequence.len() {
+            for j in i+1..sequence.len() {
+                if sequence[i] as i128 > 2 * sequence[j] as i128 {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    // xorshift64, so that the "random" inputs are the same on every run
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    assert_eq!(count_significant_inversions(&[][..]), 0);
+    assert_eq!(count_significant_inversions(&[i64::MAX, i64::MIN][..]), 1);
+    for _ in 0..200 {
+        let length = (next() % 40) as usize;
+        let sequence = (0..length)
+            .map(|_| (next() % 201) as i64 - 100)
+            .collect::<Vec<i64>>();
+        let result = count_significant_inversions(&sequence[..]);
+        assert_eq!(result, brute_force(&sequence[..]), "{:?}", sequence);
+    }
+}
+
+#[test]
+fn test_stable_adapter() {
+    use algocol::sort::{
+        quicksort::quicksort_by,
+        stable_adapter_by,
+        SortAlgorithm
+    };
+    let pairs = [(3, 0), (1, 1), (3, 2), (2, 3), (1, 4), (3, 5), (2, 6)];
+    // Quicksort on its own does not keep equal keys in their original order
+    let mut unstable = pairs;
+    quicksort_by(&mut unstable[..], true, |a, b| a.0.cmp(&b.0)).unwrap();
+    println!("quicksort: {:?}", unstable);
+    assert_ne!(
+        unstable,
+        [(1, 1), (1, 4), (2, 3), (2, 6), (3, 0), (3, 2), (3, 5)]
+    );
+    for algorithm in SortAlgorithm::ALL.iter() {
+        let mut ascending = pairs;
+        stable_adapter_by(
+            &mut ascending[..], *algorithm, true, |a, b| a.0.cmp(&b.0)
+        ).unwrap();
+        println!("stable {:?}: {:?}", algorithm, ascending);
+        assert_eq!(
+            ascending,
+            [(1, 1), (1, 4), (2, 3), (2, 6), (3, 0), (3, 2), (3, 5)]
+        );
+        let mut descending = pairs;
+        stable_adapter_by(
+            &mut descending[..], *algorithm, false, |a, b| a.0.cmp(&b.0)
+        ).unwrap();
+        assert_eq!(
+            descending,
+            [(3, 0), (3, 2), (3, 5), (2, 3), (2, 6), (1, 1), (1, 4)]
+        );
+    }
+}
+
+#[test]
+fn test_is_algorithm_stable() {
+    use algocol::{sort::SortAlgorithm, testing::is_algorithm_stable};
+    for algorithm in SortAlgorithm::ALL.iter() {
+        println!(
+            "{:?} stable: {}",
+            algorithm,
+            is_algorithm_stable(*algorithm, 2021)
+        );
+    }
+    for seed in 1..4 {
+        assert!(is_algorithm_stable(SortAlgorithm::Merge, seed));
+        assert!(is_algorithm_stable(SortAlgorithm::MergeRecursive, seed));
+        assert!(is_algorithm_stable(SortAlgorithm::Tim, seed));
+        assert!(!is_algorithm_stable(SortAlgorithm::Quick, seed));
+        assert!(!is_algorithm_stable(SortAlgorithm::QuickRecursive, seed));
+    }
+}
+
+#[test]
+fn test_sort_with() {
+    use algocol::{
+        sort::{sort_with, SortAlgorithm},
+        testing::is_algorithm_stable
+    };
+    let pairs = [(3, 0), (1, 1), (3, 2), (2, 3), (1, 4), (3, 5), (2, 6)];
+    let compare = |a: &(i32, i32), b: &(i32, i32)| a.0.cmp(&b.0);
+    for algorithm in SortAlgorithm::ALL.iter() {
+        if algorithm.is_stable() {
+            assert!(is_algorithm_stable(*algorithm, 2021));
+        }
+        let mut ascending = pairs;
+        sort_with(&mut ascending[..], *algorithm, true, true, compare)
+            .unwrap();
+        println!("sort_with stable {:?}: {:?}", algorithm, ascending);
+        assert_eq!(
+            ascending,
+            [(1, 1), (1, 4), (2, 3), (2, 6), (3, 0), (3, 2), (3, 5)]
+        );
+        let mut descending = pairs;
+        sort_with(&mut descending[..], *algorithm, false, true, compare)
+            .unwrap();
+        assert_eq!(
+            descending,
+            [(3, 0), (3, 2), (3, 5), (2, 3), (2, 6), (1, 1), (1, 4)]
+        );
+        // Without the flag, the algorithm is used as it is
+        let mut unchecked = pairs;
+        let mut direct = pairs;
+        sort_with(&mut unchecked[..], *algorithm, true, false, compare)
+            .unwrap();
+        algorithm.sort_by(&mut direct[..], true, compare).unwrap();
+        assert_eq!(unchecked, direct);
+    }
+    // Selection sort swaps (1, 0) past (1, 1) unless it has to be stable
+    let pairs = [(1, 0), (1, 1), (0, 2)];
+    let selection = SortAlgorithm::Selection;
+    assert!(!selection.is_stable());
+    let mut unstable = pairs;
+    sort_with(&mut unstable[..], selection, true, false, compare).unwrap();
+    assert_eq!(unstable, [(0, 2), (1, 1), (1, 0)]);
+    let mut stable = pairs;
+    sort_with(&mut stable[..], selection, true, true, compare).unwrap();
+    assert_eq!(stable, [(0, 2), (1, 0), (1, 1)]);
+}
+
+#[test]
+fn test_dutch_flag_sort() {
+    use std::cell::Cell;
+    use algocol::{
+        sort::dutchflag::{dutch_flag_sort_by, dutch_flag_sort_counted_by},
+        utils::random::XorShift
+    };
+    let mut sequence = [2, 1, 0, 0, 2, 1, 1, 2, 0, 1, 2, 0, 0, 1];
+    let classified = Cell::new(0);
+    let (swaps, bands) = dutch_flag_sort_counted_by(&mut sequence[..], |x| {
+        classified.set(classified.get() + 1);
+        x.cmp(&1)
+    });
+    println!("dutch_flag_sort_by: {:?} {:?} {}", sequence, bands, swaps);
+    assert_eq!(sequence, [0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 2, 2, 2, 2]);
+    assert_eq!(bands, (5, 10));
+    // Every element is only looked at once, and moved at most once
+    assert_eq!(classified.get(), sequence.len());
+    assert!(swaps <= sequence.len());
+    let mut random = XorShift::new(9);
+    for length in [1, 2, 10, 100, 1000].iter() {
+        let mut sequence = (0..*length)
+            .map(|_| random.next_below(3))
+            .collect::<Vec<usize>>();
+        let (swaps, bands) = dutch_flag_sort_counted_by(
+            &mut sequence[..], |x| x.cmp(&1)
+        );
+        let mut expected = sequence.clone();
+        expected.sort();
+        assert_eq!(sequence, expected);
+        assert_eq!(bands.0, sequence.iter().filter(|x| **x < 1).count());
+        assert!(swaps <= *length);
+    }
+    let mut empty: [i32; 0] = [];
+    assert_eq!(dutch_flag_sort_by(&mut empty[..], |x| x.cmp(&1)), (0, 0));
+}
+
+#[test]
+fn test_ranks_by() {
+    use algocol::sort::{argsort_by, ranks_by, RankMethod::*};
+    let array = [30, 10, 20, 10];
+    let compare = |a: &i32, b: &i32| a.cmp(b);
+    assert_eq!(argsort_by(&array[..], true, compare), [1, 3, 2, 0]);
+    assert_eq!(argsort_by(&array[..], false, compare), [0, 2, 1, 3]);
+    let competition = ranks_by(&array[..], true, Competition, compare);
+    let dense = ranks_by(&array[..], true, Dense, compare);
+    println!("ranks_by competition: {:?}, dense: {:?}", competition, dense);
+    assert_eq!(competition, [4, 1, 3, 1]);
+    assert_eq!(dense, [3, 1, 2, 1]);
+    let competition = ranks_by(&array[..], false, Competition, compare);
+    let dense = ranks_by(&array[..], false, Dense, compare);
+    assert_eq!(competition, [1, 3, 2, 3]);
+    assert_eq!(dense, [1, 3, 2, 3]);
+    let empty: [i32; 0] = [];
+    assert!(ranks_by(&empty[..], true, Dense, compare).is_empty());
+}
+
+#[test]
+fn test_compare_algorithms() {
+    use std::collections::HashMap;
+    use algocol::{
+        sort::SortAlgorithm::{self, *},
+        testing::compare_algorithms
+    };
+    let length = 128;
+    let input = (0..length as i64).rev().collect::<Vec<i64>>();
+    let results = compare_algorithms(&input, true);
+    for (algorithm, metrics) in results.iter() {
+        println!("{:?}: {} comparisons", algorithm, metrics.comparisons);
+    }
+    assert_eq!(results.len(), SortAlgorithm::ALL.len());
+    let comparisons = results
+        .iter()
+        .map(|(algorithm, metrics)| (*algorithm, metrics.comparisons))
+        .collect::<HashMap<_, _>>();
+    // The O(n^2) sorts compare every pair of elements in a reversed array
+    let quadratic = length * (length - 1) / 2;
+    assert!(comparisons[&Bubble] >= quadratic);
+    assert!(comparisons[&Selection] >= quadratic);
+    assert!(comparisons[&Insertion] >= quadratic);
+    // Mergesort only needs O(n log n) comparisons
+    for algorithm in [Merge, MergeRecursive].iter() {
+        assert!(comparisons[algorithm] <= length * 7);
+        assert!(comparisons[algorithm] < comparisons[&Insertion]);
+    }
+    // Timsort insertion sorts small runs before merging them
+    assert!(comparisons[&Merge] <= comparisons[&Tim]);
+    assert!(comparisons[&Tim] < comparisons[&Insertion]);
+    // Every pair is out of order, and selection sort swaps the ends inwards
+    let swaps = results
+        .iter()
+        .map(|(algorithm, metrics)| (*algorithm, metrics.swaps))
+        .collect::<HashMap<_, _>>();
+    assert_eq!(swaps[&Bubble], Some(quadratic));
+    assert_eq!(swaps[&Insertion], Some(quadratic));
+    assert_eq!(swaps[&Cocktail], Some(quadratic));
+    assert_eq!(swaps[&Selection], Some(length / 2));
+    // Swapping elements which are far apart fixes many pairs at once
+    for algorithm in [Shell, Comb, Heap, Quick, QuickRecursive, Intro].iter() {
+        assert!(swaps[algorithm].unwrap() < quadratic, "{:?}", algorithm);
+    }
+    assert_eq!(swaps[&Quick], swaps[&QuickRecursive]);
+    for algorithm in [Merge, MergeRecursive, Tim, BinaryInsertion].iter() {
+        assert_eq!(swaps[algorithm], None);
+    }
+    // An already sorted slice needs no swaps, except in heap sort, which
+    // has to turn it into a heap first. The last element is the worst pivot
+    // for a sorted slice, so introsort falls back to heap sort too.
+    let sorted = (0..length as i64).collect::<Vec<i64>>();
+    for (algorithm, metrics) in compare_algorithms(&sorted, true).iter() {
+        match algorithm {
+            Heap | Intro => assert!(metrics.swaps.unwrap() > 0),
+            _ => assert!(
+                matches!(metrics.swaps, None | Some(0)),
+                "{:?}", algorithm
+            )
+        }
+    }
+}
+
+#[test]
+fn test_merge_galloping_by() {
+    use algocol::{
+        sort::{mergesort_by, timsort::merge_galloping_by},
+        utils::{cmp::CountingComparator, random::XorShift}
+    };
+    // Every element of `left` comes before every element of `right`, so
+    // after a few comparisons the rest of `left` is copied at once.
+    let left = (0..1000).collect::<Vec<i32>>();
+    let right = (1000..2000).collect::<Vec<i32>>();
+    let counter = CountingComparator::new(|a: &i32, b: &i32| a.cmp(b));
+    let merged = merge_galloping_by(&left, &right, true, |a, b| {
+        counter.compare(a, b)
+    });
+    println!("merge_galloping_by comparisons: {}", counter.count());
+    assert_eq!(merged, (0..2000).collect::<Vec<i32>>());
+    assert!(counter.count() < 30);
+    counter.reset();
+    let merged = merge_galloping_by(&right, &left, true, |a, b| {
+        counter.compare(a, b)
+    });
+    assert_eq!(merged, (0..2000).collect::<Vec<i32>>());
+    assert!(counter.count() < 30);
+    // The merge must be stable on random runs with plenty of equal keys
+    let mut random = XorShift::new(99);
+    for ascending in [true, false].iter() {
+        let mut make_run = |tag: usize| {
+            let mut run = (0..200)
+                .map(|index| (random.next_below(20), tag, index))
+                .collect::<Vec<(usize, usize, usize)>>();
+            mergesort_by(&mut run[..], *ascending, |a, b| a.0.cmp(&b.0))
+                .unwrap();
+            run
+        };
+        let (left, right) = (make_run(0), make_run(1));
+        let merged = merge_galloping_by(&left, &right, *ascending, |a, b| {
+            a.0.cmp(&b.0)
+        });
+        let mut expected = left.iter().chain(right.iter()).copied()
+            .collect::<Vec<_>>();
+        mergesort_by(&mut expected[..], *ascending, |a, b| a.0.cmp(&b.0))
+            .unwrap();
+        assert_eq!(merged, expected);
+    }
+}
+
+#[test]
+fn test_select_nth_in_place_by() {
+    use algocol::{
+        AgcErrorKind,
+        sort::quicksort::select_nth_in_place_by,
+        utils::random::XorShift
+    };
+    let mut random = XorShift::new(5);
+    let original = (0..60)
+        .map(|_| random.next_below(40) as i32)
+        .collect::<Vec<i32>>();
+    let mut sorted = original.clone();
+    sorted.sort();
+    for k in 0..original.len() {
+        for ascending in [true, false].iter() {
+            let mut sequence = original.clone();
+            let (before, nth, after) = select_nth_in_place_by(
+                &mut sequence, k, *ascending, |a, b| a.cmp(b)
+            ).unwrap();
+            let nth = *nth;
+            if *ascending {
+                assert_eq!(nth, sorted[k]);
+                assert!(before.iter().all(|x| *x <= nth));
+                assert!(after.iter().all(|x| *x >= nth));
+            } else {
+                assert_eq!(nth, sorted[sorted.len() - 1 - k]);
+                assert!(before.iter().all(|x| *x >= nth));
+                assert!(after.iter().all(|x| *x <= nth));
+            }
+            assert_eq!(before.len(), k);
+            sequence.sort();
+            assert_eq!(sequence, sorted);
+        }
+    }
+    let mut sequence = [3, 1, 2];
+    let (_, median, _) = select_nth_in_place_by(
+        &mut sequence, 1, true, |a, b| a.cmp(b)
+    ).unwrap();
+    println!("select_nth_in_place_by median: {}", median);
+    assert_eq!(*median, 2);
+    let error = select_nth_in_place_by(&mut sequence, 3, true, |a, b| a.cmp(b))
+        .unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::OutOfBounds);
+}
+
+#[test]
+fn test_sort_unique_by() {
+    use algocol::{sort::{is_sorted, sort_unique_by}, utils::random::XorShift};
+    let mut random = XorShift::new(17);
+    let sequence = (0..500)
+        .map(|_| random.next_below(25))
+        .collect::<Vec<usize>>();
+    let unique = sort_unique_by(&sequence, true, |a, b| a.cmp(b)).unwrap();
+    println!("sort_unique_by: {:?}", unique);
+    assert!(is_sorted(&unique, true));
+    assert!(unique.windows(2).all(|pair| pair[0] != pair[1]));
+    let mut expected = sequence.clone();
+    expected.sort();
+    expected.dedup();
+    assert_eq!(unique, expected);
+    let unique = sort_unique_by(&sequence, false, |a, b| a.cmp(b)).unwrap();
+    expected.reverse();
+    assert_eq!(unique, expected);
+    // The first of each group of equal elements is kept
+    let words = ["b", "A", "a", "B", "c"];
+    let unique = sort_unique_by(&words[..], true, |a, b| {
+        a.to_lowercase().cmp(&b.to_lowercase())
+    }).unwrap();
+    assert_eq!(unique, ["A", "b", "c"]);
+}
+
+#[test]
+fn test_mergesort_linked_by() {
+    use algocol::{
+        AgcErrorKind,
+        sort::mergesort::mergesort_linked_by,
+        utils::random::XorShift
+    };
+
+    fn walk(nodes: &[((i32, usize), Option<usize>)], head: Option<usize>)
+        -> Vec<(i32, usize)>
+    {
+        let mut values = Vec::new();
+        let mut current = head;
+        while let Some(index) = current {
+            values.push(nodes[index].0);
+            current = nodes[index].1;
+        }
+        values
+    }
+
+    let mut random = XorShift::new(8);
+    for length in [0, 1, 2, 3, 7, 8, 33, 100].iter() {
+        // The list visits the nodes in a shuffled order, and every value is
+        // tagged with its position in the list to check stability.
+        let mut order = (0..*length).collect::<Vec<usize>>();
+        for i in (1..order.len()).rev() {
+            order.swap(i, random.next_below(i + 1));
+        }
+        let mut nodes = vec![((0, 0), None); *length];
+        for (position, index) in order.iter().enumerate() {
+            let value = random.next_below(10) as i32;
+            let next = order.get(position + 1).copied();
+            nodes[*index] = ((value, position), next);
+        }
+        let head = order.first().copied();
+        let mut expected = walk(&nodes, head);
+        expected.sort_by_key(|value| value.0);
+        let head = mergesort_linked_by(&mut nodes, head, true, |a, b| {
+            a.0.cmp(&b.0)
+        }).unwrap();
+        let sorted = walk(&nodes, head);
+        println!("mergesort_linked_by: {:?}", sorted);
+        assert_eq!(sorted, expected);
+        let head = mergesort_linked_by(&mut nodes, head, false, |a, b| {
+            a.0.cmp(&b.0)
+        }).unwrap();
+        let descending = walk(&nodes, head);
+        assert!(descending.windows(2).all(|pair| pair[0].0 >= pair[1].0));
+        assert_eq!(descending.len(), *length);
+    }
+    let mut cycle = vec![(1, Some(1)), (2, Some(0))];
+    let error = mergesort_linked_by(&mut cycle, Some(0), true, |a, b| a.cmp(b))
+        .unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::InvalidArgument);
+    let mut dangling = vec![(1, Some(5))];
+    let result = mergesort_linked_by(&mut dangling, Some(0), true, |a, b| {
+        a.cmp(b)
+    });
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_sort_each_by() {
+    use algocol::sort::sort_each_by;
+    let mut matrix = vec![
+        vec![3, 1, 2],
+        vec![],
+        vec![5],
+        vec![9, 7, 8, 7],
+        vec![1, 2, 3]
+    ];
+    let mut rows = matrix
+        .iter_mut()
+        .map(|row| &mut row[..])
+        .collect::<Vec<&mut [i32]>>();
+    let results = sort_each_by(&mut rows, false, |a, b| a.cmp(b));
+    println!("sort_each_by results: {:?}", results);
+    assert_eq!(results.len(), 5);
+    assert!(results.iter().all(|result| result.is_ok()));
+    assert_eq!(matrix, vec![
+        vec![3, 2, 1],
+        vec![],
+        vec![5],
+        vec![9, 8, 7, 7],
+        vec![3, 2, 1]
+    ]);
+}
+
+#[test]
+fn test_sort_columns_by() {
+    use algocol::{AgcErrorKind, sort::sort_columns_by};
+    let mut table = vec![
+        vec!["ann", "red", "1"],
+        vec!["bob", "blue", "2"],
+        vec!["cat", "red", "3"],
+        vec!["dan", "blue", "4"],
+        vec!["ann", "blue", "5"]
+    ];
+    // Column 1 ascending, then column 0 descending
+    sort_columns_by(&mut table, &[(1, true), (0, false)]).unwrap();
+    println!("sort_columns_by: {:?}", table);
+    let ids = table.iter().map(|row| row[2]).collect::<Vec<&str>>();
+    assert_eq!(ids, ["4", "2", "5", "3", "1"]);
+    // Rows which tie on every listed column keep their order
+    let mut table = vec![vec![1, 9], vec![0, 8], vec![1, 7], vec![0, 6]];
+    sort_columns_by(&mut table, &[(0, false)]).unwrap();
+    assert_eq!(table, [[1, 9], [1, 7], [0, 8], [0, 6]]);
+    sort_columns_by(&mut table, &[]).unwrap();
+    assert_eq!(table, [[1, 9], [1, 7], [0, 8], [0, 6]]);
+    let error = sort_columns_by(&mut table, &[(0, true), (2, true)])
+        .unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::OutOfBounds);
+    assert_eq!(table, [[1, 9], [1, 7], [0, 8], [0, 6]]);
+    let mut ragged = vec![vec![2, 1], vec![1]];
+    let error = sort_columns_by(&mut ragged, &[(1, true)]).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::OutOfBounds);
+    let mut empty: Vec<Vec<i32>> = Vec::new();
+    assert!(sort_columns_by(&mut empty, &[(3, true)]).is_ok());
+}
+
+#[test]
+fn test_min_swaps_to_sort() {
+    use algocol::{sort::min_swaps_to_sort, utils::random::XorShift};
+
+    // Count the swaps made by selection sort, which makes the fewest swaps
+    // possible when every element is different.
+    fn selection_swaps(sequence: &[usize]) -> usize {
+        let mut sequence = sequence.to_vec();
+        let mut swaps = 0;
+        for i in 0..sequence.len() {
+            let smallest = (i..sequence.len())
+                .min_by_key(|j| sequence[*j])
+                .unwrap();
+            if smallest != i {
+                sequence.swap(i, smallest);
+                swaps += 1;
+            }
+        }
+        swaps
+    }
+
+    assert_eq!(min_swaps_to_sort(&[4, 3, 2, 1][..]), 2);
+    assert_eq!(min_swaps_to_sort(&[1, 2, 3, 4, 5][..]), 0);
+    assert_eq!(min_swaps_to_sort(&[2, 1][..]), 1);
+    assert_eq!(min_swaps_to_sort::<[i32], i32>(&[][..]), 0);
+    let mut random = XorShift::new(12);
+    for length in 1..40 {
+        let mut sequence = (0..length).collect::<Vec<usize>>();
+        for i in (1..length).rev() {
+            sequence.swap(i, random.next_below(i + 1));
+        }
+        let swaps = min_swaps_to_sort(&sequence);
+        assert_eq!(swaps, selection_swaps(&sequence));
+        println!("min_swaps_to_sort {:?}: {}", sequence, swaps);
+    }
+}
+
+#[test]
+fn test_shellsort() {
+    use algocol::{
+        AgcErrorKind,
+        sort::shellsort::{shellsort, shellsort_with_gaps_by}
+    };
+    // Long enough to use gaps past the end of Ciura's sequence
+    let mut sequence = (0..2000)
+        .map(|x: i64| (x * 7919) % 2003 - 1000)
+        .collect::<Vec<i64>>();
+    let mut expected = sequence.clone();
+    expected.sort();
+    let result = shellsort(&mut sequence[..], true);
+    println!("shellsort result: {:?}", result.is_ok());
+    assert_eq!(sequence, expected);
+    shellsort(&mut sequence[..], false).unwrap();
+    expected.reverse();
+    assert_eq!(sequence, expected);
+    let mut sequence = [4, 1, 3, 2];
+    for gaps in [&[3, 3, 1][..], &[2, 0], &[1, 2]].iter() {
+        let error = shellsort_with_gaps_by(
+            &mut sequence[..], true, gaps, |a, b| a.cmp(b)
+        ).unwrap_err();
+        assert_eq!(error.kind(), AgcErrorKind::WrongOrder);
+    }
+    assert_eq!(sequence, [4, 1, 3, 2]);
+    // A gap larger than the slice is skipped
+    shellsort_with_gaps_by(&mut sequence[..], true, &[10, 2, 1], |a, b| {
+        a.cmp(b)
+    }).unwrap();
+    assert_eq!(sequence, [1, 2, 3, 4]);
+}
+
+#[test]
+fn test_merge_auto_by() {
+    use algocol::{AgcErrorKind, sort::mergesort::merge_auto_by};
+    // Every split of runs of up to 12 elements, merged in-place (the limit
+    // is never reached) and with a buffer (the limit is always exceeded).
+    // The second field records the original index to check stability.
+    for total in 1..=12 {
+        for left_size in 1..=total {
+            let mut original = (0..total)
+                .map(|i| ((i * 5) % 4, i))
+                .collect::<Vec<(usize, usize)>>();
+            original[..left_size].sort();
+            original[left_size..].sort();
+            let mut expected = original.clone();
+            expected.sort_by_key(|pair| pair.0);
+            for max_stack_alloc in [usize::MAX, 0].iter() {
+                let mut sequence = original.clone();
+                merge_auto_by(
+                    &mut sequence[..], 0, left_size-1, total-1, true,
+                    *max_stack_alloc, |a, b| a.0.cmp(&b.0)
+                ).unwrap();
+                assert_eq!(sequence, expected);
+            }
+        }
+    }
+    let mut sequence = [6, 4, 2, 5, 3, 1, 0];
+    for max_stack_alloc in [usize::MAX, 0].iter() {
+        let mut copy = sequence;
+        let result = merge_auto_by(
+            &mut copy[..], 0, 2, 5, false, *max_stack_alloc, |a, b| a.cmp(b)
+        );
+        println!("merge_auto_by result: {:?}", result);
+        assert_eq!(copy, [6, 5, 4, 3, 2, 1, 0]);
+    }
+    let error = merge_auto_by(
+        &mut sequence[..], 3, 2, 5, true, 0, |a, b| a.cmp(b)
+    ).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::WrongOrder);
+    let error = merge_auto_by(
+        &mut sequence[..], 0, 2, 7, true, 0, |a, b| a.cmp(b)
+    ).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::OutOfBounds);
+}
+
+#[test]
+fn test_cocktailsort() {
+    use algocol::{
+        sort::cocktailsort::{cocktailsort, cocktailsort_by},
+        utils::random::XorShift
+    };
+    // Only 10 different values, so there are plenty of duplicates
+    let mut random = XorShift::new(5);
+    let mut sequence = (0..200)
+        .map(|_| random.next_below(10))
+        .collect::<Vec<usize>>();
+    let mut expected = sequence.clone();
+    expected.sort();
+    let result = cocktailsort(&mut sequence[..], true);
+    println!("cocktailsort result: {:?}", result);
+    assert_eq!(sequence, expected);
+    cocktailsort(&mut sequence[..], false).unwrap();
+    expected.reverse();
+    assert_eq!(sequence, expected);
+    // Equal elements stay in the same order
+    let mut pairs = [(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')];
+    cocktailsort_by(&mut pairs[..], true, |a, b| a.0.cmp(&b.0)).unwrap();
+    assert_eq!(pairs, [(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]);
+}
+
+#[test]
+fn test_combsort() {
+    use algocol::{sort::combsort::combsort, utils::random::XorShift};
+    // Negative and positive values from the whole range of `i64`
+    let mut random = XorShift::new(11);
+    let mut sequence = (0..500)
+        .map(|_| random.next_u64() as i64)
+        .collect::<Vec<i64>>();
+    let mut expected = sequence.clone();
+    expected.sort();
+    let result = combsort(&mut sequence[..], true);
+    println!("combsort result: {:?}", result);
+    assert_eq!(sequence, expected);
+    combsort(&mut sequence[..], false).unwrap();
+    expected.reverse();
+    assert_eq!(sequence, expected);
+    // A turtle at the end of the slice
+    let mut sequence = [2, 3, 4, 5, 6, 7, 8, 9, 1];
+    combsort(&mut sequence[..], true).unwrap();
+    assert_eq!(sequence, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+}
+
+#[test]
+fn test_sort_with_moved_mask_by() {
+    use algocol::sort::sort_with_moved_mask_by;
+    // 7 and 2 are out of place, and 2 has to go past 3 to 6
+    let mut sequence = [0, 1, 7, 3, 4, 5, 6, 2, 8, 9];
+    let (mask, sorted) = sort_with_moved_mask_by(
+        &mut sequence, true, |a, b| a.cmp(b)
+    ).unwrap();
+    println!("sort_with_moved_mask_by: {:?}", mask);
+    assert_eq!(sorted, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    let moved = (0..mask.len())
+        .filter(|index| mask[*index])
+        .collect::<Vec<usize>>();
+    assert_eq!(moved, vec![2, 7]);
+    // Nothing moves when the slice is already sorted
+    let (mask, _) = sort_with_moved_mask_by(
+        &mut sequence, true, |a, b| a.cmp(b)
+    ).unwrap();
+    assert!(mask.iter().all(|moved| !moved));
+}
+
+#[test]
+fn test_countingsort() {
+    use algocol::{
+        AgcErrorKind,
+        sort::countingsort::{countingsort, MAX_COUNTING_RANGE}
+    };
+    let mut sequence = (0..1000)
+        .map(|x: u32| 500 + (x * 7) % 97)
+        .collect::<Vec<u32>>();
+    let mut expected = sequence.clone();
+    expected.sort();
+    let result = countingsort(&mut sequence[..], true);
+    println!("countingsort result: {:?}", result.is_ok());
+    assert_eq!(sequence, expected);
+    countingsort(&mut sequence[..], false).unwrap();
+    expected.reverse();
+    assert_eq!(sequence, expected);
+    let mut empty: [u8; 0] = [];
+    assert!(countingsort(&mut empty[..], true).is_ok());
+    let mut bytes = [255u8, 0, 128, 0];
+    countingsort(&mut bytes[..], true).unwrap();
+    assert_eq!(bytes, [0, 0, 128, 255]);
+    // Too many values between the smallest and the largest
+    let mut wide = [0usize, MAX_COUNTING_RANGE, 1];
+    let error = countingsort(&mut wide[..], true).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::OutOfBounds);
+    assert_eq!(wide, [0, MAX_COUNTING_RANGE, 1]);
+}
+
+#[test]
+fn test_radixsort_lsd() {
+    use algocol::{sort::radixsort::radixsort_lsd, utils::random::XorShift};
+    let mut sequence = (0..10000u32).collect::<Vec<u32>>();
+    let mut random = XorShift::new(7);
+    for index in (1..sequence.len()).rev() {
+        sequence.swap(index, random.next_below(index + 1));
+    }
+    let result = radixsort_lsd(&mut sequence[..], true);
+    println!("radixsort_lsd result: {:?}", result.is_ok());
+    assert_eq!(sequence, (0..10000u32).collect::<Vec<u32>>());
+    radixsort_lsd(&mut sequence[..], false).unwrap();
+    assert_eq!(sequence, (0..10000u32).rev().collect::<Vec<u32>>());
+    let mut wide = [u64::MAX, 1 << 40, 0, 1 << 8, 3];
+    radixsort_lsd(&mut wide[..], true).unwrap();
+    assert_eq!(wide, [0, 3, 1 << 8, 1 << 40, u64::MAX]);
+}
+
+#[test]
+fn test_is_sorted_counted_by() {
+    use algocol::sort::{is_sorted_counted_by, verification_comparisons};
+    let sorted = (0..50).collect::<Vec<i32>>();
+    let result = is_sorted_counted_by(&sorted[..], true, |a, b| a.cmp(b));
+    println!("is_sorted_counted_by on a sorted slice: {:?}", result);
+    assert_eq!(result, (true, verification_comparisons(sorted.len())));
+    assert_eq!(result.1, 49);
+    // The check stops at the first pair which is out of order
+    let mut unsorted = sorted.clone();
+    unsorted.swap(10, 11);
+    let (is_sorted, count) = is_sorted_counted_by(
+        &unsorted[..], true, |a, b| a.cmp(b)
+    );
+    assert!(!is_sorted);
+    assert_eq!(count, 11);
+    assert!(count < verification_comparisons(unsorted.len()));
+    let empty: [i32; 0] = [];
+    assert_eq!(is_sorted_counted_by(&empty, true, |a, b| a.cmp(b)), (true, 0));
+}
+
+#[test]
+fn test_bucketsort_by() {
+    use algocol::{AgcErrorKind, sort::bucketsort::bucketsort_by};
+    let mut sequence = (0..200)
+        .map(|x: u32| (x * 7919) % 1000)
+        .collect::<Vec<u32>>();
+    let mut expected = sequence.clone();
+    expected.sort();
+    let key_fn = |x: &u32| *x as f64 / 1000.0;
+    let result = bucketsort_by(
+        &mut sequence[..], true, 20, key_fn, |a, b| a.cmp(b)
+    );
+    println!("bucketsort_by result: {:?}", result.is_ok());
+    assert_eq!(sequence, expected);
+    bucketsort_by(&mut sequence[..], false, 7, key_fn, |a, b| a.cmp(b))
+        .unwrap();
+    expected.reverse();
+    assert_eq!(sequence, expected);
+    let mut sequence = [3, 1, 2];
+    let error = bucketsort_by(
+        &mut sequence[..], true, 4, |x| *x as f64 / 3.0, |a, b| a.cmp(b)
+    ).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::OutOfBounds);
+    assert_eq!(sequence, [3, 1, 2]);
+    let error = bucketsort_by(
+        &mut sequence[..], true, 0, |x| *x as f64 / 4.0, |a, b| a.cmp(b)
+    ).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_heapsort() {
+    use algocol::{sort::heapsort::heapsort, utils::random::XorShift};
+    let mut random = XorShift::new(17);
+    let shuffled = (0..300)
+        .map(|_| random.next_below(50) as i32 - 25)
+        .collect::<Vec<i32>>();
+    let ascending = (0..300).collect::<Vec<i32>>();
+    let descending = (0..300).rev().collect::<Vec<i32>>();
+    let same = vec![4; 300];
+    let odd = vec![3, 1, 2];
+    for input in [&shuffled, &ascending, &descending, &same, &odd].iter() {
+        let mut sequence = input.to_vec();
+        let mut expected = sequence.clone();
+        expected.sort();
+        let result = heapsort(&mut sequence[..], true);
+        println!("heapsort result: {:?}", result.is_ok());
+        assert_eq!(sequence, expected);
+        let mut sequence = input.to_vec();
+        heapsort(&mut sequence[..], false).unwrap();
+        expected.reverse();
+        assert_eq!(sequence, expected);
+    }
+}
+
+#[test]
+fn test_introsort() {
+    use algocol::{
+        sort::introsort::introsort_by,
+        utils::cmp::CountingComparator
+    };
+    // Sorted and reverse-sorted slices make quicksort pick the worst pivot
+    // every time, which would take about 50 million comparisons here
+    let ascending = (0..10000).collect::<Vec<i32>>();
+    let descending = (0..10000).rev().collect::<Vec<i32>>();
+    for input in [&ascending, &descending].iter() {
+        for direction in [true, false].iter() {
+            let counter = CountingComparator::new(|a: &i32, b: &i32| a.cmp(b));
+            let mut sequence = input.to_vec();
+            introsort_by(&mut sequence[..], *direction, |a, b| {
+                counter.compare(a, b)
+            }).unwrap();
+            println!("introsort comparisons: {}", counter.count());
+            let expected = if *direction {&ascending} else {&descending};
+            assert_eq!(&sequence, expected);
+            assert!(counter.count() < 1_000_000);
+        }
+    }
+    // Every element is the same
+    let mut same = vec![7; 1000];
+    introsort_by(&mut same[..], true, |a, b| a.cmp(b)).unwrap();
+    assert_eq!(same, vec![7; 1000]);
+}
+
+#[test]
+fn test_binary_insertionsort_by() {
+    use algocol::sort::insertionsort::binary_insertionsort_by;
+    // (key, original index), with only 5 different keys so there are plenty
+    // of ties
+    let original = (0..100)
+        .map(|index: usize| ((index * 7) % 5, index))
+        .collect::<Vec<(usize, usize)>>();
+    let mut sequence = original.clone();
+    let result = binary_insertionsort_by(
+        &mut sequence[..], true, |a, b| a.0.cmp(&b.0)
+    );
+    println!("binary_insertionsort_by result: {:?}", result.is_ok());
+    let mut expected = original.clone();
+    expected.sort_by_key(|pair| pair.0);
+    assert_eq!(sequence, expected);
+    let mut sequence = original.clone();
+    binary_insertionsort_by(&mut sequence[..], false, |a, b| a.0.cmp(&b.0))
+        .unwrap();
+    expected.sort_by_key(|pair| std::cmp::Reverse(pair.0));
+    assert_eq!(sequence, expected);
+}
+
+#[test]
+fn test_sort_budgeted_by() {
+    use algocol::{
+        sort::insertionsort::sort_budgeted_by,
+        utils::random::XorShift
+    };
+    let compare = |a: &u64, b: &u64| a.cmp(b);
+    let mut random = XorShift::new(1028);
+    let original = (0..50)
+        .map(|_| random.next_below(100) as u64)
+        .collect::<Vec<u64>>();
+    let mut expected = original.clone();
+    expected.sort();
+    let mut sequence = original.clone();
+    let (done, partial) = sort_budgeted_by(&mut sequence, true, 40, compare)
+        .unwrap();
+    println!("sort_budgeted_by with 40 comparisons: {:?}", partial);
+    assert!(!done);
+    // Nothing is lost or duplicated when the budget runs out
+    let mut leftover = sequence.clone();
+    leftover.sort();
+    assert_eq!(leftover, expected);
+    let (done, sorted) = sort_budgeted_by(&mut sequence, true, 5000, compare)
+        .unwrap();
+    assert!(done);
+    assert_eq!(sorted, &expected[..]);
+    // A sorted slice of length n only needs n - 1 comparisons
+    let (done, _) = sort_budgeted_by(&mut sequence, true, 49, compare)
+        .unwrap();
+    assert!(done);
+    let (done, _) = sort_budgeted_by(&mut sequence, true, 48, compare)
+        .unwrap();
+    assert!(!done);
+    let (done, sorted) = sort_budgeted_by(&mut sequence, false, 5000, compare)
+        .unwrap();
+    assert!(done);
+    expected.reverse();
+    assert_eq!(sorted, &expected[..]);
+    let mut single = [1];
+    assert!(sort_budgeted_by(&mut single, true, 0, compare).unwrap().0);
+}
+
+#[test]
+fn test_sort_small_by() {
+    use algocol::{arrays::permutations, sort::network::sort_small_by};
+    for n in 2..=8 {
+        let sorted = (0..n).collect::<Vec<usize>>();
+        let reversed = (0..n).rev().collect::<Vec<usize>>();
+        let all = permutations(&sorted).unwrap();
+        assert_eq!(all.len(), (1..=n).product());
+        for permutation in all.iter() {
+            let mut sequence = permutation.clone();
+            sort_small_by(&mut sequence[..], true, |a, b| a.cmp(b)).unwrap();
+            assert_eq!(sequence, sorted, "sorting {:?}", permutation);
+            sort_small_by(&mut sequence[..], false, |a, b| a.cmp(b)).unwrap();
+            assert_eq!(sequence, reversed, "sorting {:?}", permutation);
+        }
+        println!("sort_small_by: {} permutations of {}", all.len(), n);
+    }
+    // Longer slices fall back to insertion sort
+    let mut sequence = (0..20).rev().collect::<Vec<i32>>();
+    sort_small_by(&mut sequence[..], true, |a, b| a.cmp(b)).unwrap();
+    assert_eq!(sequence, (0..20).collect::<Vec<i32>>());
+}
+
+#[test]
+fn test_buffered_merge_speed() {
+    use std::time::Instant;
+    use algocol::sort::{
+        mergesort::{mergesort_by, merge_auto_by},
+        timsort::{timsort_by, DEFAULT_RUN}
+    };
+    let original = (0..100_000)
+        .map(|x: i64| (x * 7919) % 100_003)
+        .collect::<Vec<i64>>();
+    let mut expected = original.clone();
+    expected.sort();
+    let mut sequence = original.clone();
+    let start = Instant::now();
+    mergesort_by(&mut sequence[..], true, |a, b| a.cmp(b)).unwrap();
+    println!("mergesort_by on 100k elements: {:?}", start.elapsed());
+    assert_eq!(sequence, expected);
+    let mut sequence = original.clone();
+    let start = Instant::now();
+    timsort_by(&mut sequence[..], true, DEFAULT_RUN, |a, b| a.cmp(b)).unwrap();
+    println!("timsort_by on 100k elements: {:?}", start.elapsed());
+    assert_eq!(sequence, expected);
+    // A single merge of 2 interleaved runs, where the in-place merge has to
+    // rotate almost every element of the right run into place
+    let runs = (0..20_000)
+        .map(|x| 2 * x)
+        .chain((0..20_000).map(|x| 2 * x + 1))
+        .collect::<Vec<i64>>();
+    let merged = (0..40_000).collect::<Vec<i64>>();
+    for (name, max_stack_alloc) in [("in-place", usize::MAX), ("buffered", 0)]
+        .iter()
+    {
+        let mut sequence = runs.clone();
+        let start = Instant::now();
+        merge_auto_by(
+            &mut sequence[..], 0, 19_999, 39_999, true, *max_stack_alloc,
+            |a, b| a.cmp(b)
+        ).unwrap();
+        println!("{} merge of 40k elements: {:?}", name, start.elapsed());
+        assert_eq!(sequence, merged);
+    }
+}
+
+#[test]
+fn test_selectionsort_counted_by() {
+    use algocol::sort::selectionsort::selectionsort_counted_by;
+    for length in 0..50 {
+        let mut sequence = (0..length)
+            .map(|x: usize| (x * 17) % 23)
+            .collect::<Vec<usize>>();
+        let mut expected = sequence.clone();
+        expected.sort();
+        let (swaps, _) = selectionsort_counted_by(
+            &mut sequence[..], true, |a, b| a.cmp(b)
+        ).unwrap();
+        assert_eq!(sequence, expected);
+        assert!(swaps <= length.saturating_sub(1));
+    }
+    // Reversing a slice swaps each pair of opposite elements once
+    let mut sequence = (0..10).collect::<Vec<i32>>();
+    let (swaps, _) = selectionsort_counted_by(
+        &mut sequence[..], false, |a, b| a.cmp(b)
+    ).unwrap();
+    println!("selectionsort_counted_by swaps: {}", swaps);
+    assert_eq!(sequence, (0..10).rev().collect::<Vec<i32>>());
+    assert_eq!(swaps, 5);
+    let (swaps, _) = selectionsort_counted_by(
+        &mut sequence[..], false, |a, b| a.cmp(b)
+    ).unwrap();
+    assert_eq!(swaps, 0);
+}
+
+#[test]
+#[cfg(feature = "strict-debug")]
+fn test_strict_debug_consistent() {
+    use algocol::sort::{
+        bubblesort::bubblesort_by,
+        cocktailsort::cocktailsort_by,
+        combsort::combsort_by,
+        insertionsort::insertionsort_by,
+        network::sort_small_by,
+        selectionsort::selectionsort_by
+    };
+    let sequence = vec![5, 3, 9, 1, 1, 8, 2, 7];
+    for ascending in [true, false].iter().copied() {
+        let compare = |a: &i32, b: &i32| a.cmp(b);
+        bubblesort_by(&mut sequence.clone()[..], ascending, compare).unwrap();
+        cocktailsort_by(&mut sequence.clone()[..], ascending, compare)
+            .unwrap();
+        combsort_by(&mut sequence.clone()[..], ascending, compare).unwrap();
+        insertionsort_by(&mut sequence.clone()[..], ascending, compare)
+            .unwrap();
+        sort_small_by(&mut sequence.clone()[..], ascending, compare).unwrap();
+        selectionsort_by(&mut sequence.clone()[..], ascending, compare)
+            .unwrap();
+    }
+}
+
+#[test]
+#[cfg(all(feature = "strict-debug", debug_assertions))]
+#[should_panic(expected = "the compare function is inconsistent")]
+fn test_strict_debug_inconsistent() {
+    use algocol::sort::bubblesort::bubblesort_by;
+    use std::cmp::Ordering;
+    // Every element is "greater" than every other one, so swapping 2
+    // elements never puts them in order
+    let mut sequence = [1, 2, 3];
+    let _ = bubblesort_by(&mut sequence[..], true, |_, _| Ordering::Greater);
+}
+
+#[test]
+fn test_recursion_depth() {
+    use algocol::sort::{
+        is_sorted,
+        mergesort::mergesort_recursively_depth_by,
+        quicksort::{
+            quicksort_median3_recursively_depth_by,
+            quicksort_recursively_depth_by
+        }
+    };
+    let sorted = (0..1000).collect::<Vec<i32>>();
+    for ascending in [true, false].iter().copied() {
+        // The last element is always the largest (or smallest), so only the
+        // pivot is split off each time
+        let mut sequence = sorted.clone();
+        let (depth, result) = quicksort_recursively_depth_by(
+            &mut sequence[..], ascending, |a, b| a.cmp(b)
+        ).unwrap();
+        println!("quicksort_recursively depth: {}", depth);
+        assert!(is_sorted(result, ascending));
+        assert_eq!(depth, 999);
+        let mut sequence = sorted.clone();
+        let (depth, result) = quicksort_median3_recursively_depth_by(
+            &mut sequence[..], ascending, |a, b| a.cmp(b)
+        ).unwrap();
+        println!("quicksort_median3_recursively depth: {}", depth);
+        assert!(is_sorted(result, ascending));
+        // log2(1000) is about 10, and the segments are not always split
+        // exactly in half
+        assert!(depth <= 3 * 10);
+        let mut sequence = sorted.clone();
+        let (depth, result) = mergesort_recursively_depth_by(
+            &mut sequence[..], ascending, |a, b| a.cmp(b)
+        ).unwrap();
+        assert!(is_sorted(result, ascending));
+        assert_eq!(depth, 10);
+    }
+    let mut single = [1];
+    let (depth, _) = quicksort_recursively_depth_by(
+        &mut single[..], true, |a, b| a.cmp(b)
+    ).unwrap();
+    assert_eq!(depth, 0);
+}
+
+#[test]
+fn test_merge_by_with_tiebreak() {
+    use algocol::sort::mergesort::merge_by_with_tiebreak;
+    // (department, name, id)
+    let left = [(1, "cara", 0), (1, "eve", 1), (2, "bob", 2), (3, "al", 3)];
+    let right = [(1, "dan", 4), (2, "bob", 5), (2, "fay", 6)];
+    let by_department = |a: &(i32, &str, i32), b: &(i32, &str, i32)| {
+        a.0.cmp(&b.0)
+    };
+    let merged = merge_by_with_tiebreak(
+        &left, &right, by_department, |a, b| a.1.cmp(b.1)
+    );
+    println!("merge_by_with_tiebreak: {:?}", merged);
+    let ids = merged.iter().map(|record| record.2).collect::<Vec<i32>>();
+    // The 2 bobs are equal on both fields, so the one from `left` is first
+    assert_eq!(ids, [0, 4, 1, 2, 5, 6, 3]);
+    // Preferring `right` for every tie
+    let merged = merge_by_with_tiebreak(
+        &left, &right, by_department, |_, _| std::cmp::Ordering::Greater
+    );
+    let ids = merged.iter().map(|record| record.2).collect::<Vec<i32>>();
+    assert_eq!(ids, [4, 0, 1, 5, 6, 2, 3]);
+}
+
+#[test]
+fn test_riffle_merge() {
+    use algocol::sort::mergesort::riffle_merge;
+    let left = (0..26).collect::<Vec<i32>>();
+    let right = (26..52).collect::<Vec<i32>>();
+    let shuffled = riffle_merge(&left, &right, 84);
+    println!("riffle_merge: {:?}", shuffled);
+    assert_eq!(shuffled.len(), left.len() + right.len());
+    let mut sorted = shuffled.clone();
+    sorted.sort();
+    assert_eq!(sorted, (0..52).collect::<Vec<i32>>());
+    // Each half keeps its order, unlike a full shuffle
+    let from_left = shuffled.iter().filter(|x| **x < 26).copied();
+    assert_eq!(from_left.collect::<Vec<i32>>(), left);
+    let from_right = shuffled.iter().filter(|x| **x >= 26).copied();
+    assert_eq!(from_right.collect::<Vec<i32>>(), right);
+    assert_ne!(shuffled, sorted);
+    assert_eq!(riffle_merge(&left, &right, 84), shuffled);
+    assert_eq!(riffle_merge(&[], &right, 1), right);
+    assert_eq!(riffle_merge(&left, &[], 1), left);
 }
\ No newline at end of file
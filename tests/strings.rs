@@ -0,0 +1,19 @@
+extern crate algocol;
+
+#[test]
+fn test_longest_palindrome() {
+    use algocol::strings::longest_palindrome;
+    let word = "babad".as_bytes();
+    let (start, end) = longest_palindrome(word);
+    let palindrome = std::str::from_utf8(&word[start..end]).unwrap();
+    println!("longest_palindrome of babad: {}", palindrome);
+    assert!(palindrome == "bab" || palindrome == "aba");
+    let word = "cbbd".as_bytes();
+    assert_eq!(longest_palindrome(word), (1, 3));
+    let word = "forgeeksskeegfor".as_bytes();
+    assert_eq!(longest_palindrome(word), (3, 13));
+    assert_eq!(longest_palindrome("a".as_bytes()), (0, 1));
+    assert_eq!(longest_palindrome("".as_bytes()), (0, 0));
+    assert_eq!(longest_palindrome(&[1, 2, 3][..]), (0, 1));
+    assert_eq!(longest_palindrome(&[1, 2, 2, 1, 2, 2, 1][..]), (0, 7));
+}
\ No newline at end of file
@@ -0,0 +1,223 @@
+extern crate algocol;
+
+#[test]
+fn test_treap() {
+    use algocol::{sort::is_sorted, structures::Treap};
+    let mut treap = Treap::new(2021);
+    // Inserting sorted values would turn a plain binary search tree into a
+    // linked list, but the random priorities should keep the treap shallow.
+    for value in 0..1024 {
+        assert!(treap.insert(value));
+    }
+    assert!(!treap.insert(512));
+    assert_eq!(treap.len(), 1024);
+    let values = treap.iter().cloned().collect::<Vec<i32>>();
+    assert!(is_sorted(&values[..], true));
+    assert_eq!(values, (0..1024).collect::<Vec<i32>>());
+    println!("treap height: {}", treap.height());
+    assert!(treap.height() <= 3 * 10);
+    for value in (0..1024).step_by(2) {
+        assert!(treap.remove(&value));
+    }
+    assert!(!treap.remove(&0));
+    assert_eq!(treap.len(), 512);
+    assert!(treap.contains(&1) && !treap.contains(&2));
+    let values = treap.iter().cloned().collect::<Vec<i32>>();
+    assert_eq!(values, (1..1024).step_by(2).collect::<Vec<i32>>());
+}
+
+#[test]
+fn test_treap_lowest_common_ancestor() {
+    use algocol::structures::Treap;
+    let mut treap = Treap::new(99);
+    for value in [50, 20, 80, 10, 30, 70, 90, 25, 35].iter() {
+        treap.insert(*value);
+    }
+    let values = treap.iter().cloned().collect::<Vec<i32>>();
+    let mut ancestor_pairs = 0;
+    for a in values.iter() {
+        for b in values.iter() {
+            let ancestor = treap.lowest_common_ancestor(a, b).unwrap();
+            // In a binary search tree, the lowest common ancestor is the
+            // shallowest node whose value lies between `a` and `b`.
+            let expected = values.iter()
+                .filter(|value| a.min(b) <= *value && *value <= a.max(b))
+                .min_by_key(|value| treap.depth(value).unwrap())
+                .unwrap();
+            assert_eq!(ancestor, expected);
+            if ancestor == a && a != b {
+                // `a` is an ancestor of `b`
+                assert!(treap.depth(a) < treap.depth(b));
+                ancestor_pairs += 1;
+            }
+        }
+        // A node is its own ancestor
+        assert_eq!(treap.lowest_common_ancestor(a, a), Some(a));
+    }
+    println!("pairs where one node is an ancestor: {}", ancestor_pairs);
+    assert!(ancestor_pairs > 0);
+    assert_eq!(treap.lowest_common_ancestor(&10, &11), None);
+    assert_eq!(treap.lowest_common_ancestor(&11, &10), None);
+}
+
+#[test]
+fn test_treap_from_sorted_slice() {
+    use algocol::{AgcErrorKind, structures::Treap};
+    for length in 1..=16 {
+        let values = (0..length).collect::<Vec<i32>>();
+        let treap = Treap::from_sorted_slice(&values[..], length as u64)
+            .unwrap();
+        let expected = (length as f64 + 1.0).log2().ceil() as usize;
+        println!("length: {}, height: {}", length, treap.height());
+        assert_eq!(treap.height(), expected);
+        assert_eq!(treap.len(), length as usize);
+        assert_eq!(treap.iter().cloned().collect::<Vec<i32>>(), values);
+    }
+    // The heap property must still hold, so inserting and removing values
+    // afterwards keeps the treap valid.
+    let values = (0..100).map(|x| x * 2).collect::<Vec<i32>>();
+    let mut treap = Treap::from_sorted_slice(&values[..], 5).unwrap();
+    for value in 0..100 {
+        treap.insert(value * 2 + 1);
+    }
+    for value in 0..50 {
+        assert!(treap.remove(&(value * 4)));
+    }
+    assert_eq!(treap.len(), 150);
+    let remaining = treap.iter().cloned().collect::<Vec<i32>>();
+    assert!(remaining.windows(2).all(|pair| pair[0] < pair[1]));
+    let treap = Treap::from_sorted_slice(&[1, 1, 2, 3, 3][..], 0).unwrap();
+    assert_eq!(treap.iter().cloned().collect::<Vec<i32>>(), [1, 2, 3]);
+    let error = Treap::from_sorted_slice(&[3, 1, 2][..], 0).err().unwrap();
+    assert_eq!(error.kind(), AgcErrorKind::Unordered);
+}
+
+#[test]
+fn test_is_valid_heap_by() {
+    use algocol::structures::heap::is_valid_heap_by;
+    let compare = |a: &i32, b: &i32| a.cmp(b);
+    let mut heap = [100, 19, 36, 17, 3, 25, 1, 2, 7];
+    assert!(is_valid_heap_by(&heap[..], true, compare));
+    assert!(!is_valid_heap_by(&heap[..], false, compare));
+    // 25 is now bigger than its parent, 20
+    heap[2] = 20;
+    println!("corrupted heap: {:?}", heap);
+    assert!(!is_valid_heap_by(&heap[..], true, compare));
+    let heap = [1, 2, 3, 4, 5, 6, 7];
+    assert!(is_valid_heap_by(&heap[..], false, compare));
+    assert!(is_valid_heap_by(&[3, 3, 3][..], true, compare));
+    assert!(is_valid_heap_by(&[3, 3, 3][..], false, compare));
+    assert!(is_valid_heap_by(&[][..], true, compare));
+}
+
+#[test]
+fn test_kth_largest_streaming() {
+    use algocol::{
+        AgcErrorKind,
+        structures::heap::kth_largest_streaming,
+        utils::random::XorShift
+    };
+    let mut random = XorShift::new(3);
+    let stream = (0..1000)
+        .map(|_| random.next_below(500))
+        .collect::<Vec<usize>>();
+    let mut sorted = stream.clone();
+    sorted.sort_by(|a, b| b.cmp(a));
+    for k in [1, 2, 10, 500, 1000].iter() {
+        let kth = kth_largest_streaming(
+            stream.iter().copied(), *k, |a, b| a.cmp(b)
+        );
+        println!("kth_largest_streaming k = {}: {:?}", k, kth);
+        assert_eq!(kth, Ok(sorted[k - 1]));
+    }
+    // A reversed comparison finds the kth smallest instead
+    let kth = kth_largest_streaming(
+        stream.iter().copied(), 3, |a, b| b.cmp(a)
+    );
+    assert_eq!(kth, Ok(sorted[sorted.len() - 3]));
+    let error = kth_largest_streaming(0..5, 6, |a, b| a.cmp(b)).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::OutOfBounds);
+    let error = kth_largest_streaming(0..5, 0, |a, b| a.cmp(b)).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::OutOfBounds);
+}
+
+#[test]
+fn test_sorted_vec() {
+    use algocol::{
+        sort::{is_sorted, is_sorted_by},
+        structures::SortedVec,
+        utils::random::XorShift
+    };
+    let mut sorted = SortedVec::new();
+    let mut random = XorShift::new(66);
+    for _ in 0..200 {
+        sorted.insert(random.next_below(50));
+        assert!(is_sorted(sorted.as_slice(), true));
+    }
+    assert_eq!(sorted.len(), 200);
+    let mut expected = sorted.as_slice().to_vec();
+    // Every value from 10 up to but not including 20
+    let range = sorted.range(&10, &20);
+    println!("sorted_vec range: {:?}", range);
+    assert!(range.iter().all(|value| (10..20).contains(value)));
+    let inside = expected.iter().filter(|value| (10..20).contains(*value));
+    assert_eq!(range.len(), inside.count());
+    assert!(sorted.range(&20, &10).is_empty());
+    let missing = (0..50).find(|value| !expected.contains(value));
+    if let Some(missing) = missing {
+        assert!(!sorted.contains(&missing));
+        assert_eq!(sorted.remove_value(&missing), None);
+    }
+    let present = expected[100];
+    assert!(sorted.contains(&present));
+    assert_eq!(sorted.remove_value(&present), Some(present));
+    let index = expected.iter().position(|value| *value == present).unwrap();
+    expected.remove(index);
+    assert_eq!(sorted.as_slice(), &expected[..]);
+    // Sorting by a custom comparison function keeps equal values in the
+    // order they were inserted in
+    let mut by_key = SortedVec::new_by(|a: &(i32, char), b: &(i32, char)| {
+        b.0.cmp(&a.0)
+    });
+    by_key.insert_many(vec![(1, 'a'), (3, 'b'), (1, 'c'), (2, 'd'), (3, 'e')]);
+    assert!(is_sorted_by(by_key.as_slice(), false, |a, b| a.0.cmp(&b.0)));
+    assert_eq!(
+        by_key.into_vec(),
+        vec![(3, 'b'), (3, 'e'), (2, 'd'), (1, 'a'), (1, 'c')]
+    );
+}
+
+#[test]
+fn test_repair_heap_by() {
+    use algocol::{
+        structures::heap::{is_valid_heap_by, repair_heap_by},
+        utils::random::XorShift
+    };
+    let compare = |a: &i32, b: &i32| a.cmp(b);
+    let mut heap = [100, 19, 36, 17, 3, 25, 1, 2, 7];
+    let mut expected = heap.to_vec();
+    // Corrupt one element in the middle of the heap
+    heap[2] = 20;
+    expected[2] = 20;
+    assert!(!is_valid_heap_by(&heap[..], true, compare));
+    repair_heap_by(&mut heap[..], true, compare);
+    println!("repaired heap: {:?}", heap);
+    assert!(is_valid_heap_by(&heap[..], true, compare));
+    let mut sorted = heap.to_vec();
+    sorted.sort();
+    expected.sort();
+    assert_eq!(sorted, expected);
+    // Corrupt the root so that it must sink to the bottom
+    heap[0] = 0;
+    repair_heap_by(&mut heap[..], true, compare);
+    assert!(is_valid_heap_by(&heap[..], true, compare));
+    // Any order at all can be turned into a heap
+    let mut random = XorShift::new(82);
+    for length in 0..50 {
+        let mut slice = (0..length)
+            .map(|_| random.next_below(30) as i32)
+            .collect::<Vec<i32>>();
+        repair_heap_by(&mut slice[..], false, compare);
+        assert!(is_valid_heap_by(&slice[..], false, compare));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,200 @@
+extern crate algocol;
+
+#[test]
+fn test_group_by_key() {
+    use algocol::utils::slice::group_by_key;
+
+    #[derive(Debug, PartialEq)]
+    struct Item {
+        category: char,
+        value: i32
+    }
+
+    let items = [
+        Item {category: 'a', value: 4},
+        Item {category: 'a', value: 1},
+        Item {category: 'b', value: 9},
+        Item {category: 'c', value: 3},
+        Item {category: 'c', value: 2},
+        Item {category: 'c', value: 7}
+    ];
+    let groups = group_by_key(&items[..], |item| item.category)
+        .collect::<Vec<&[Item]>>();
+    println!("group_by_key: {:?}", groups);
+    let sizes = groups.iter().map(|group| group.len()).collect::<Vec<_>>();
+    assert_eq!(sizes, [2, 1, 3]);
+    assert_eq!(groups[0], &items[0..2]);
+    assert_eq!(groups[1], &items[2..3]);
+    assert_eq!(groups[2], &items[3..6]);
+    assert_eq!(group_by_key(&items[..0], |item| item.category).count(), 0);
+}
+
+#[test]
+fn test_counting_comparator() {
+    use algocol::{
+        binarysearch::binarysearch_by,
+        sort::is_sorted_by,
+        utils::cmp::CountingComparator
+    };
+    let array = (0..100).collect::<Vec<i32>>();
+    let counter = CountingComparator::new(|a: &i32, b: &i32| a.cmp(b));
+    assert!(is_sorted_by(&array[..], true, |a, b| counter.compare(a, b)));
+    println!("is_sorted_by comparisons: {}", counter.count());
+    // One comparison for each adjacent pair
+    assert_eq!(counter.count(), 99);
+    counter.reset();
+    assert_eq!(counter.count(), 0);
+    let result = binarysearch_by(&array[..], &42, true, |a, b| {
+        counter.compare(a, b)
+    });
+    assert!(result.is_ok());
+    println!("binarysearch_by comparisons: {}", counter.count());
+    // 99 to check that the array is sorted, at most 3 for the bounds and
+    // the final check, plus at most 7 for the search itself
+    assert!(counter.count() >= 100 && counter.count() <= 99 + 3 + 7);
+}
+
+#[test]
+fn test_apply_permutation() {
+    use algocol::{AgcErrorKind, utils::slice::apply_permutation};
+    let mut names = ["carol", "alice", "dave", "bob"];
+    let mut ages = [35, 30, 40, 25];
+    // Indices of `names` in alphabetical order
+    let mut permutation = [1, 3, 0, 2];
+    apply_permutation(&mut names[..], &mut permutation[..]).unwrap();
+    apply_permutation(&mut ages[..], &mut permutation[..]).unwrap();
+    println!("apply_permutation: {:?} {:?}", names, ages);
+    assert_eq!(names, ["alice", "bob", "carol", "dave"]);
+    assert_eq!(ages, [30, 25, 35, 40]);
+    assert_eq!(permutation, [1, 3, 0, 2]);
+    // Applying the inverse permutation restores the original order
+    let mut inverse = [0; 4];
+    for (index, target) in permutation.iter().enumerate() {
+        inverse[*target] = index;
+    }
+    apply_permutation(&mut names[..], &mut inverse[..]).unwrap();
+    assert_eq!(names, ["carol", "alice", "dave", "bob"]);
+
+    let mut invalid = [1, 1, 0, 2];
+    let result = apply_permutation(&mut names[..], &mut invalid[..]);
+    assert_eq!(result.unwrap_err().kind(), AgcErrorKind::InvalidArgument);
+    assert_eq!(invalid, [1, 1, 0, 2]);
+    assert_eq!(names, ["carol", "alice", "dave", "bob"]);
+    let result = apply_permutation(&mut names[..], &mut [0, 1, 2, 4][..]);
+    assert_eq!(result.unwrap_err().kind(), AgcErrorKind::InvalidArgument);
+    let result = apply_permutation(&mut names[..], &mut [0, 1, 2][..]);
+    assert_eq!(result.unwrap_err().kind(), AgcErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_invert_permutation() {
+    use algocol::{
+        AgcErrorKind,
+        utils::slice::{apply_permutation, invert_permutation}
+    };
+    let original = [10, 20, 30, 40, 50, 60];
+    let mut array = original;
+    let mut permutation = [4, 2, 5, 0, 1, 3];
+    let mut inverse = invert_permutation(&permutation[..]).unwrap();
+    println!("invert_permutation: {:?}", inverse);
+    apply_permutation(&mut array[..], &mut permutation[..]).unwrap();
+    assert_eq!(array, [50, 30, 60, 10, 20, 40]);
+    apply_permutation(&mut array[..], &mut inverse[..]).unwrap();
+    assert_eq!(array, original);
+    assert_eq!(invert_permutation(&inverse[..]), Ok(permutation.to_vec()));
+    assert_eq!(invert_permutation(&[][..]), Ok(vec![]));
+    let result = invert_permutation(&[0, 2, 2][..]);
+    assert_eq!(result.unwrap_err().kind(), AgcErrorKind::InvalidArgument);
+    let result = invert_permutation(&[0, 3, 1][..]);
+    assert_eq!(result.unwrap_err().kind(), AgcErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_rotate_sorted() {
+    use algocol::{
+        AgcErrorKind,
+        binarysearch::search_rotated_by,
+        utils::slice::rotate_sorted
+    };
+    let original = [0, 1, 2, 3, 4];
+    for pivot in 0..=original.len() {
+        let mut rotated = original;
+        rotate_sorted(&mut rotated[..], pivot).unwrap();
+        println!("rotate_sorted by {}: {:?}", pivot, rotated);
+        assert_eq!(rotated[0], original[pivot % original.len()]);
+        for item in original.iter() {
+            let location = search_rotated_by(&rotated[..], item, true, |a, b| {
+                a.cmp(b)
+            });
+            assert_eq!(location.map(|index| rotated[index]), Some(*item));
+        }
+        for missing in [-1, 5].iter() {
+            let location = search_rotated_by(
+                &rotated[..], missing, true, |a, b| a.cmp(b)
+            );
+            assert_eq!(location, None);
+        }
+    }
+    let mut descending = [9, 7, 5, 3, 1];
+    rotate_sorted(&mut descending[..], 2).unwrap();
+    assert_eq!(descending, [5, 3, 1, 9, 7]);
+    for item in [9, 7, 5, 3, 1].iter() {
+        let location = search_rotated_by(&descending[..], item, false, |a, b| {
+            a.cmp(b)
+        });
+        assert_eq!(location.map(|index| descending[index]), Some(*item));
+    }
+    let mut array = [0, 1, 2];
+    let error = rotate_sorted(&mut array[..], 4).unwrap_err();
+    assert_eq!(error.kind(), AgcErrorKind::OutOfBounds);
+    let empty: [i32; 0] = [];
+    assert_eq!(search_rotated_by(&empty[..], &0, true, |a, b| a.cmp(b)), None);
+}
+
+#[test]
+fn test_validate_comparator() {
+    use std::cmp::Ordering;
+    use algocol::{AgcErrorKind, utils::cmp::validate_comparator};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Hand {
+        Rock,
+        Paper,
+        Scissors
+    }
+
+    // The hand that beats another hand is treated as the greater one.
+    fn beats(a: &Hand, b: &Hand) -> Ordering {
+        use Hand::*;
+        match (a, b) {
+            (Rock, Scissors) | (Paper, Rock) | (Scissors, Paper) => {
+                Ordering::Greater
+            },
+            _ if a == b => Ordering::Equal,
+            _ => Ordering::Less
+        }
+    }
+
+    let samples = [5, 3, 9, 3, -1, 0];
+    assert!(validate_comparator(&samples, |a, b| a.cmp(b)).is_ok());
+    assert!(validate_comparator(&samples, |a, b| b.cmp(a)).is_ok());
+    let words = ["apple", "Banana", "cherry", "APPLE"];
+    let result = validate_comparator(&words, |a, b| {
+        a.to_lowercase().cmp(&b.to_lowercase())
+    });
+    assert!(result.is_ok());
+    let hands = [Hand::Rock, Hand::Paper, Hand::Scissors];
+    let error = validate_comparator(&hands, beats).unwrap_err();
+    println!("validate_comparator error: {}", error);
+    assert_eq!(error.kind(), AgcErrorKind::InconsistentOrder);
+    assert!(error.to_string().contains("(Rock, Paper, Scissors)"));
+    // Only comparing the first element is fine, but comparing the first
+    // element one way and the second element the other way is not.
+    let pairs = [(1, 2), (1, 3), (2, 1)];
+    assert!(validate_comparator(&pairs, |a, b| a.0.cmp(&b.0)).is_ok());
+    let error = validate_comparator(&pairs, |a, b| {
+        if a.0 != b.0 {a.0.cmp(&b.0)} else {Ordering::Less}
+    }).unwrap_err();
+    println!("validate_comparator error: {}", error);
+    assert_eq!(error.kind(), AgcErrorKind::InconsistentOrder);
+}
\ No newline at end of file